@@ -63,6 +63,33 @@ impl<T> VecAssumeInit for Vec<MaybeUninit<T>> {
     }
 }
 
+/// An replacement of unstable API for the initialized prefix of a slice
+/// https://doc.rust-lang.org/std/mem/union.MaybeUninit.html#method.slice_assume_init_ref
+///
+/// Safety
+/// ------
+/// - The first `len` elements of `s` must be initialized.
+///
+pub(crate) unsafe fn slice_prefix_assume_init_ref<T>(s: &[MaybeUninit<T>], len: usize) -> &[T] {
+    let s = &s[..len];
+    std::slice::from_raw_parts(s.as_ptr() as *const T, len)
+}
+
+/// An replacement of unstable API for the initialized prefix of a slice
+/// https://doc.rust-lang.org/std/mem/union.MaybeUninit.html#method.slice_assume_init_mut
+///
+/// Safety
+/// ------
+/// - The first `len` elements of `s` must be initialized.
+///
+pub(crate) unsafe fn slice_prefix_assume_init_mut<T>(
+    s: &mut [MaybeUninit<T>],
+    len: usize,
+) -> &mut [T] {
+    let s = &mut s[..len];
+    std::slice::from_raw_parts_mut(s.as_mut_ptr() as *mut T, len)
+}
+
 /// Create a vector without initialization
 ///
 /// Safety
@@ -0,0 +1,635 @@
+//! Implement linear solver using LU decomposition for general banded matrix,
+//! and Cholesky decomposition and eigenvalue problem for hermite banded matrix
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+use std::ops::{Index, IndexMut};
+
+/// Represents a square banded matrix with `kl` sub-diagonals and `ku` super-diagonals
+/// in the LAPACK band storage.
+///
+/// The `(kl + ku + 1) x n` column-major array `ab` stores the diagonals in its rows,
+/// i.e. the element `(i, j)` of the matrix is `ab[ku + i - j + j * (kl + ku + 1)]`
+/// for `max(0, j - ku) <= i <= min(n - 1, j + kl)`:
+///
+/// ```text
+/// // n = 5, kl = 2, ku = 1
+/// [  *, a01, a12, a23, a34,
+///  a00, a11, a22, a33, a44,
+///  a10, a21, a32, a43,   *,
+///  a20, a31, a42,   *,   *,]
+/// ```
+///
+/// where `*` is not referenced.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Banded<A: Scalar> {
+    /// layout of raw matrix
+    pub l: MatrixLayout,
+    /// Number of sub-diagonals
+    pub kl: i32,
+    /// Number of super-diagonals
+    pub ku: i32,
+    /// `(kl + ku + 1) x n` band storage in column-major order
+    pub ab: Vec<A>,
+}
+
+impl<A: Scalar> Banded<A> {
+    /// Zero matrix with `kl` sub-diagonals and `ku` super-diagonals
+    pub fn zeros(l: MatrixLayout, kl: i32, ku: i32) -> Self {
+        let (n, _) = l.size();
+        Banded {
+            l,
+            kl,
+            ku,
+            ab: vec![A::zero(); ((kl + ku + 1) * n) as usize],
+        }
+    }
+
+    /// Leading dimension of the band storage
+    pub fn ldab(&self) -> i32 {
+        self.kl + self.ku + 1
+    }
+
+    fn position(&self, row: i32, col: i32) -> Option<usize> {
+        let (n, _) = self.l.size();
+        assert!(
+            std::cmp::max(row, col) < n,
+            "ndarray: index {:?} is out of bounds for array of shape {}",
+            [row, col],
+            n
+        );
+        if row - col > self.kl || col - row > self.ku {
+            return None;
+        }
+        Some((self.ku + row - col + col * self.ldab()) as usize)
+    }
+
+    fn opnorm_one(&self) -> A::Real {
+        let (n, _) = self.l.size();
+        let ldab = self.ldab() as usize;
+        let mut max = A::Real::zero();
+        for j in 0..n {
+            let first = (self.ku - j).max(0) as usize;
+            let last = (self.ku + n - 1 - j).min(self.kl + self.ku) as usize;
+            let j = j as usize;
+            let mut sum = A::Real::zero();
+            for val in &self.ab[first + j * ldab..=last + j * ldab] {
+                sum += val.abs();
+            }
+            if max < sum {
+                max = sum;
+            }
+        }
+        max
+    }
+}
+
+/// Represents the LU factorization of a banded matrix `A` as `A = P*L*U`.
+#[derive(Clone, PartialEq)]
+pub struct LUFactorizedBanded<A: Scalar> {
+    /// Layout of raw matrix
+    pub l: MatrixLayout,
+    /// Number of sub-diagonals of the original matrix
+    pub kl: i32,
+    /// Number of super-diagonals of the original matrix
+    pub ku: i32,
+    /// `(2 * kl + ku + 1) x n` band storage in column-major order,
+    /// where `U` is stored as an upper banded matrix with `kl + ku` super-diagonals
+    /// and the multipliers of `L` are stored in the last `kl` rows.
+    pub ab: Vec<A>,
+    /// The pivot indices that define the permutation matrix `P`.
+    pub ipiv: Pivot,
+
+    a_opnorm_one: A::Real,
+}
+
+impl<A: Scalar> Index<(i32, i32)> for Banded<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, (row, col): (i32, i32)) -> &A {
+        match self.position(row, col) {
+            Some(pos) => &self.ab[pos],
+            None => panic!(
+                "ndarray-linalg::banded: index {:?} is out of band",
+                [row, col]
+            ),
+        }
+    }
+}
+
+impl<A: Scalar> Index<[i32; 2]> for Banded<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, [row, col]: [i32; 2]) -> &A {
+        &self[(row, col)]
+    }
+}
+
+impl<A: Scalar> IndexMut<(i32, i32)> for Banded<A> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (i32, i32)) -> &mut A {
+        match self.position(row, col) {
+            Some(pos) => &mut self.ab[pos],
+            None => panic!(
+                "ndarray-linalg::banded: index {:?} is out of band",
+                [row, col]
+            ),
+        }
+    }
+}
+
+impl<A: Scalar> IndexMut<[i32; 2]> for Banded<A> {
+    #[inline]
+    fn index_mut(&mut self, [row, col]: [i32; 2]) -> &mut A {
+        &mut self[(row, col)]
+    }
+}
+
+/// Wraps `*gbtrf`, `*gbcon` and `*gbtrs`
+pub trait Banded_: Scalar + Sized {
+    /// Computes the LU factorization of a banded `n x n` matrix `a` using
+    /// partial pivoting with row interchanges.
+    fn lu_banded(a: &Banded<Self>) -> Result<LUFactorizedBanded<Self>>;
+
+    fn rcond_banded(lu: &LUFactorizedBanded<Self>) -> Result<Self::Real>;
+
+    fn solve_banded(
+        lu: &LUFactorizedBanded<Self>,
+        bl: MatrixLayout,
+        t: Transpose,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_banded {
+    (@real, $scalar:ty, $gbtrf:path, $gbcon:path, $gbtrs:path) => {
+        impl_banded!(@body, $scalar, $gbtrf, $gbcon, $gbtrs, 3, iwork: i32);
+    };
+    (@complex, $scalar:ty, $gbtrf:path, $gbcon:path, $gbtrs:path) => {
+        impl_banded!(@body, $scalar, $gbtrf, $gbcon, $gbtrs, 2, rwork: Self::Real);
+    };
+    (@body, $scalar:ty, $gbtrf:path, $gbcon:path, $gbtrs:path, $lwork:expr, $iwork:ident: $iwork_ty:ty) => {
+        impl Banded_ for $scalar {
+            fn lu_banded(a: &Banded<Self>) -> Result<LUFactorizedBanded<Self>> {
+                let (n, _) = a.l.size();
+                let (kl, ku) = (a.kl, a.ku);
+                // *gbtrf requires additional kl rows for the fill-in of U
+                let ldab = 2 * kl + ku + 1;
+                let mut ab = vec![Self::zero(); (ldab * n) as usize];
+                for j in 0..n as usize {
+                    let src = j * a.ldab() as usize;
+                    let dst = kl as usize + j * ldab as usize;
+                    ab[dst..dst + a.ldab() as usize]
+                        .copy_from_slice(&a.ab[src..src + a.ldab() as usize]);
+                }
+                let mut ipiv = vec_uninit(n as usize);
+                // We have to calc one-norm before LU factorization
+                let a_opnorm_one = a.opnorm_one();
+                let mut info = 0;
+                unsafe {
+                    $gbtrf(
+                        &n,
+                        &n,
+                        &kl,
+                        &ku,
+                        AsPtr::as_mut_ptr(&mut ab),
+                        &ldab,
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let ipiv = unsafe { ipiv.assume_init() };
+                Ok(LUFactorizedBanded {
+                    l: a.l,
+                    kl,
+                    ku,
+                    ab,
+                    ipiv,
+                    a_opnorm_one,
+                })
+            }
+
+            fn rcond_banded(lu: &LUFactorizedBanded<Self>) -> Result<Self::Real> {
+                let (n, _) = lu.l.size();
+                let ldab = 2 * lu.kl + lu.ku + 1;
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit($lwork * n as usize);
+                let mut $iwork: Vec<MaybeUninit<$iwork_ty>> = vec_uninit(n as usize);
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $gbcon(
+                        NormType::One.as_ptr(),
+                        &n,
+                        &lu.kl,
+                        &lu.ku,
+                        AsPtr::as_ptr(&lu.ab),
+                        &ldab,
+                        lu.ipiv.as_ptr(),
+                        &lu.a_opnorm_one,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut $iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+
+            fn solve_banded(
+                lu: &LUFactorizedBanded<Self>,
+                b_layout: MatrixLayout,
+                t: Transpose,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = lu.l.size();
+                let ldab = 2 * lu.kl + lu.ku + 1;
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(b_layout, b);
+                        b_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+                let (ldb, nrhs) = b_layout.size();
+                let mut info = 0;
+                unsafe {
+                    $gbtrs(
+                        t.as_ptr(),
+                        &n,
+                        &lu.kl,
+                        &lu.ku,
+                        &nrhs,
+                        AsPtr::as_ptr(&lu.ab),
+                        &ldab,
+                        lu.ipiv.as_ptr(),
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &ldb,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+                Ok(())
+            }
+        }
+    };
+} // impl_banded!
+
+impl_banded!(@real, f64, lapack_sys::dgbtrf_, lapack_sys::dgbcon_, lapack_sys::dgbtrs_);
+impl_banded!(@real, f32, lapack_sys::sgbtrf_, lapack_sys::sgbcon_, lapack_sys::sgbtrs_);
+impl_banded!(@complex, c64, lapack_sys::zgbtrf_, lapack_sys::zgbcon_, lapack_sys::zgbtrs_);
+impl_banded!(@complex, c32, lapack_sys::cgbtrf_, lapack_sys::cgbcon_, lapack_sys::cgbtrs_);
+
+/// Represents a hermite (or real symmetric) banded matrix with `kd` super-diagonals
+/// in the LAPACK band storage.
+///
+/// Only the triangle specified by `uplo` is stored in the `(kd + 1) x n` column-major array `ab`:
+///
+/// - [UPLO::Upper]: the element `(i, j)` is `ab[kd + i - j + j * (kd + 1)]`
+///   for `max(0, j - kd) <= i <= j`
+/// - [UPLO::Lower]: the element `(i, j)` is `ab[i - j + j * (kd + 1)]`
+///   for `j <= i <= min(n - 1, j + kd)`
+///
+/// ```text
+/// // n = 5, kd = 2, UPLO::Upper
+/// [  *,   *, a02, a13, a24,
+///    *, a01, a12, a23, a34,
+///  a00, a11, a22, a33, a44,]
+///
+/// // n = 5, kd = 2, UPLO::Lower
+/// [a00, a11, a22, a33, a44,
+///  a10, a21, a32, a43,   *,
+///  a20, a31, a42,   *,   *,]
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct BandedHermite<A: Scalar> {
+    /// layout of raw matrix
+    pub l: MatrixLayout,
+    /// Which triangle is stored
+    pub uplo: UPLO,
+    /// Number of super-diagonals (equivalently sub-diagonals)
+    pub kd: i32,
+    /// `(kd + 1) x n` band storage in column-major order
+    pub ab: Vec<A>,
+}
+
+impl<A: Scalar> BandedHermite<A> {
+    /// Zero matrix with `kd` super-diagonals
+    pub fn zeros(l: MatrixLayout, uplo: UPLO, kd: i32) -> Self {
+        let (n, _) = l.size();
+        BandedHermite {
+            l,
+            uplo,
+            kd,
+            ab: vec![A::zero(); ((kd + 1) * n) as usize],
+        }
+    }
+
+    /// Leading dimension of the band storage
+    pub fn ldab(&self) -> i32 {
+        self.kd + 1
+    }
+
+    fn position(&self, row: i32, col: i32) -> Option<usize> {
+        let (n, _) = self.l.size();
+        assert!(
+            std::cmp::max(row, col) < n,
+            "ndarray: index {:?} is out of bounds for array of shape {}",
+            [row, col],
+            n
+        );
+        let offset = match self.uplo {
+            UPLO::Upper if row <= col && col - row <= self.kd => self.kd + row - col,
+            UPLO::Lower if row >= col && row - col <= self.kd => row - col,
+            _ => return None,
+        };
+        Some((offset + col * self.ldab()) as usize)
+    }
+
+    fn opnorm_one(&self) -> A::Real {
+        let (n, _) = self.l.size();
+        let mut col_sum = vec![A::Real::zero(); n as usize];
+        for j in 0..n {
+            let (first, last) = match self.uplo {
+                UPLO::Upper => ((j - self.kd).max(0), j),
+                UPLO::Lower => (j, (j + self.kd).min(n - 1)),
+            };
+            for i in first..=last {
+                let val = self[(i, j)].abs();
+                col_sum[j as usize] += val;
+                if i != j {
+                    col_sum[i as usize] += val;
+                }
+            }
+        }
+        let mut max = A::Real::zero();
+        for &val in &col_sum {
+            if max < val {
+                max = val;
+            }
+        }
+        max
+    }
+}
+
+/// Represents the Cholesky factorization of a hermite positive definite banded matrix `A`
+/// as `A = U^H * U` or `A = L * L^H`.
+#[derive(Clone, PartialEq)]
+pub struct CholeskyFactorizedBanded<A: Scalar> {
+    /// The upper triangular factor `U` or the lower triangular factor `L`
+    /// according to `uplo` in the same band storage as the original matrix
+    pub a: BandedHermite<A>,
+
+    a_opnorm_one: A::Real,
+}
+
+impl<A: Scalar> Index<(i32, i32)> for BandedHermite<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, (row, col): (i32, i32)) -> &A {
+        match self.position(row, col) {
+            Some(pos) => &self.ab[pos],
+            None => panic!(
+                "ndarray-linalg::banded: index {:?} is not stored element",
+                [row, col]
+            ),
+        }
+    }
+}
+
+impl<A: Scalar> Index<[i32; 2]> for BandedHermite<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, [row, col]: [i32; 2]) -> &A {
+        &self[(row, col)]
+    }
+}
+
+impl<A: Scalar> IndexMut<(i32, i32)> for BandedHermite<A> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (i32, i32)) -> &mut A {
+        match self.position(row, col) {
+            Some(pos) => &mut self.ab[pos],
+            None => panic!(
+                "ndarray-linalg::banded: index {:?} is not stored element",
+                [row, col]
+            ),
+        }
+    }
+}
+
+impl<A: Scalar> IndexMut<[i32; 2]> for BandedHermite<A> {
+    #[inline]
+    fn index_mut(&mut self, [row, col]: [i32; 2]) -> &mut A {
+        &mut self[(row, col)]
+    }
+}
+
+/// Eigenvalues and eigenvectors of hermite banded matrix
+pub struct EighBandedOutput<A: Scalar> {
+    /// Eigenvalues in ascending order
+    pub eigs: Vec<A::Real>,
+    /// Eigenvectors as the columns of column-major matrix
+    pub z: Option<Vec<A>>,
+}
+
+/// Wraps `*pbtrf`, `*pbcon`, `*pbtrs` and `*sbevd`/`*hbevd`
+pub trait BandedHermite_: Scalar + Sized {
+    /// Computes the Cholesky factorization of a hermite positive definite banded matrix `a`
+    fn cholesky_banded(a: &BandedHermite<Self>) -> Result<CholeskyFactorizedBanded<Self>>;
+
+    fn rcond_cholesky_banded(f: &CholeskyFactorizedBanded<Self>) -> Result<Self::Real>;
+
+    fn solve_cholesky_banded(
+        f: &CholeskyFactorizedBanded<Self>,
+        bl: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Computes all eigenvalues, and optionally eigenvectors, of a hermite banded matrix `a`
+    /// by divide and conquer method
+    fn eigh_banded(calc_v: bool, a: &BandedHermite<Self>) -> Result<EighBandedOutput<Self>>;
+}
+
+macro_rules! impl_banded_hermite {
+    (@real, $scalar:ty, $pbtrf:path, $pbcon:path, $pbtrs:path, $sbevd:path) => {
+        impl_banded_hermite!(@body, $scalar, $pbtrf, $pbcon, $pbtrs, $sbevd, 3, iwork: i32, );
+    };
+    (@complex, $scalar:ty, $pbtrf:path, $pbcon:path, $pbtrs:path, $hbevd:path) => {
+        impl_banded_hermite!(@body, $scalar, $pbtrf, $pbcon, $pbtrs, $hbevd, 2, rwork: Self::Real, rwork);
+    };
+    (@body, $scalar:ty, $pbtrf:path, $pbcon:path, $pbtrs:path, $hbevd:path,
+     $lwork:expr, $con_work:ident: $con_work_ty:ty, $($rwork:ident),*) => {
+        impl BandedHermite_ for $scalar {
+            fn cholesky_banded(a: &BandedHermite<Self>) -> Result<CholeskyFactorizedBanded<Self>> {
+                let (n, _) = a.l.size();
+                let mut a = a.clone();
+                // We have to calc one-norm before Cholesky factorization
+                let a_opnorm_one = a.opnorm_one();
+                let mut info = 0;
+                unsafe {
+                    $pbtrf(
+                        a.uplo.as_ptr(),
+                        &n,
+                        &a.kd,
+                        AsPtr::as_mut_ptr(&mut a.ab),
+                        &a.ldab(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(CholeskyFactorizedBanded { a, a_opnorm_one })
+            }
+
+            fn rcond_cholesky_banded(f: &CholeskyFactorizedBanded<Self>) -> Result<Self::Real> {
+                let (n, _) = f.a.l.size();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit($lwork * n as usize);
+                let mut $con_work: Vec<MaybeUninit<$con_work_ty>> = vec_uninit(n as usize);
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $pbcon(
+                        f.a.uplo.as_ptr(),
+                        &n,
+                        &f.a.kd,
+                        AsPtr::as_ptr(&f.a.ab),
+                        &f.a.ldab(),
+                        &f.a_opnorm_one,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut $con_work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+
+            fn solve_cholesky_banded(
+                f: &CholeskyFactorizedBanded<Self>,
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = f.a.l.size();
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(b_layout, b);
+                        b_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+                let (ldb, nrhs) = b_layout.size();
+                let mut info = 0;
+                unsafe {
+                    $pbtrs(
+                        f.a.uplo.as_ptr(),
+                        &n,
+                        &f.a.kd,
+                        &nrhs,
+                        AsPtr::as_ptr(&f.a.ab),
+                        &f.a.ldab(),
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &ldb,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+                Ok(())
+            }
+
+            fn eigh_banded(
+                calc_v: bool,
+                a: &BandedHermite<Self>,
+            ) -> Result<EighBandedOutput<Self>> {
+                let (n, _) = a.l.size();
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                // *sbevd and *hbevd destroy the band storage
+                let mut ab = a.ab.clone();
+                let mut eigs: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut z: Vec<MaybeUninit<Self>> =
+                    vec_uninit(if calc_v { (n * n) as usize } else { 0 });
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                $(
+                let mut $rwork = [Self::Real::zero()];
+                )*
+                let mut iwork_size = [0];
+                unsafe {
+                    $hbevd(
+                        jobz.as_ptr(),
+                        a.uplo.as_ptr(),
+                        &n,
+                        &a.kd,
+                        AsPtr::as_mut_ptr(&mut ab),
+                        &a.ldab(),
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        $(AsPtr::as_mut_ptr(&mut $rwork), &(-1),)*
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                $(
+                let lrwork = $rwork[0].to_usize().unwrap();
+                let mut $rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(lrwork);
+                )*
+                let liwork = iwork_size[0].to_usize().unwrap();
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork);
+                unsafe {
+                    $hbevd(
+                        jobz.as_ptr(),
+                        a.uplo.as_ptr(),
+                        &n,
+                        &a.kd,
+                        AsPtr::as_mut_ptr(&mut ab),
+                        &a.ldab(),
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        $(AsPtr::as_mut_ptr(&mut $rwork), &(lrwork as i32),)*
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &(liwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighBandedOutput {
+                    eigs: unsafe { eigs.assume_init() },
+                    z: jobz.then(|| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+} // impl_banded_hermite!
+
+impl_banded_hermite!(@real, f64, lapack_sys::dpbtrf_, lapack_sys::dpbcon_, lapack_sys::dpbtrs_, lapack_sys::dsbevd_);
+impl_banded_hermite!(@real, f32, lapack_sys::spbtrf_, lapack_sys::spbcon_, lapack_sys::spbtrs_, lapack_sys::ssbevd_);
+impl_banded_hermite!(@complex, c64, lapack_sys::zpbtrf_, lapack_sys::zpbcon_, lapack_sys::zpbtrs_, lapack_sys::zhbevd_);
+impl_banded_hermite!(@complex, c32, lapack_sys::cpbtrf_, lapack_sys::cpbcon_, lapack_sys::cpbtrs_, lapack_sys::chbevd_);
@@ -0,0 +1,205 @@
+//! Bidiagonal reduction
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Result of bidiagonal reduction
+pub struct BidiagonalOutput<A: Scalar> {
+    /// Diagonal elements of the bidiagonal matrix
+    pub d: Vec<A::Real>,
+    /// Off-diagonal elements of the bidiagonal matrix.
+    /// This is the superdiagonal if $m \ge n$, and the subdiagonal otherwise.
+    pub e: Vec<A::Real>,
+    /// Unitary matrix for destination space, $m \times \min(m, n)$
+    pub u: Option<Vec<A>>,
+    /// Unitary matrix for departure space, $\min(m, n) \times n$
+    pub vt: Option<Vec<A>>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Bidiagonal reduction
+pub trait Bidiagonal_: Scalar {
+    /// Reduce a general $m \times n$ matrix into bidiagonal form $A = U B V^H$
+    ///
+    /// $B$ is upper bidiagonal if $m \ge n$, and lower bidiagonal otherwise.
+    /// $U$ and $V^H$ are returned in the same layout as the input.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sgebrd | dgebrd | cgebrd | zgebrd |
+    /// | sorgbr | dorgbr | cungbr | zungbr |
+    ///
+    fn bidiagonal(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+    ) -> Result<BidiagonalOutput<Self>>;
+}
+
+macro_rules! impl_bidiagonal {
+    ($scalar:ty, $gebrd:path, $gbr:path) => {
+        impl Bidiagonal_ for $scalar {
+            fn bidiagonal(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<BidiagonalOutput<Self>> {
+                /// Generate Q or P^H in-place from the reflectors
+                fn generate(
+                    vect: Vect,
+                    m: i32,
+                    n: i32,
+                    k: i32,
+                    a: &mut [$scalar],
+                    lda: i32,
+                    tau: &[$scalar],
+                ) -> Result<()> {
+                    let mut info = 0;
+                    let mut work_size = [<$scalar>::zero()];
+                    unsafe {
+                        $gbr(
+                            vect.as_ptr(),
+                            &m,
+                            &n,
+                            &k,
+                            AsPtr::as_mut_ptr(a),
+                            &lda.max(1),
+                            AsPtr::as_ptr(tau),
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    let lwork = work_size[0].to_usize().unwrap();
+                    let mut work: Vec<MaybeUninit<$scalar>> = vec_uninit(lwork);
+                    unsafe {
+                        $gbr(
+                            vect.as_ptr(),
+                            &m,
+                            &n,
+                            &k,
+                            AsPtr::as_mut_ptr(a),
+                            &lda.max(1),
+                            AsPtr::as_ptr(tau),
+                            AsPtr::as_mut_ptr(&mut work),
+                            &(lwork as i32),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()
+                }
+
+                let (m, n) = l.size();
+                let k = m.min(n);
+
+                // Transpose if a is C-continuous
+                let mut a_t;
+                let a = match l {
+                    MatrixLayout::C { .. } => {
+                        a_t = transpose(l, a).1;
+                        &mut a_t[..]
+                    }
+                    MatrixLayout::F { .. } => a,
+                };
+
+                let mut d = vec_uninit(k as usize);
+                let mut e = vec_uninit((k - 1).max(0) as usize);
+                let mut tauq = vec_uninit(k as usize);
+                let mut taup = vec_uninit(k as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gebrd(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        AsPtr::as_mut_ptr(&mut tauq),
+                        AsPtr::as_mut_ptr(&mut taup),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gebrd(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        AsPtr::as_mut_ptr(&mut tauq),
+                        AsPtr::as_mut_ptr(&mut taup),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let d = unsafe { d.assume_init() };
+                let e = unsafe { e.assume_init() };
+                let tauq = unsafe { tauq.assume_init() };
+                let taup = unsafe { taup.assume_init() };
+
+                // Generate U from the reflectors stored in columns, m x k
+                let u = if calc_u {
+                    let mut u = a.to_vec();
+                    generate(Vect::Q, m, k, n, &mut u, m, &tauq)?;
+                    u.truncate((m * k) as usize);
+                    Some(u)
+                } else {
+                    None
+                };
+
+                // Generate V^H from the reflectors stored in rows, k x n with leading dimension m
+                let vt = if calc_vt {
+                    let mut p = a.to_vec();
+                    generate(Vect::P, k, n, m, &mut p, m, &taup)?;
+                    let (k, n, m) = (k as usize, n as usize, m as usize);
+                    let mut vt = Vec::with_capacity(k * n);
+                    for j in 0..n {
+                        vt.extend_from_slice(&p[j * m..j * m + k]);
+                    }
+                    Some(vt)
+                } else {
+                    None
+                };
+
+                // Re-transpose U and V^H into C-continuous
+                let (u, vt) = match l {
+                    MatrixLayout::C { .. } => (
+                        u.map(|u| transpose(MatrixLayout::F { col: k, lda: m }, &u).1),
+                        vt.map(|vt| transpose(MatrixLayout::F { col: n, lda: k }, &vt).1),
+                    ),
+                    MatrixLayout::F { .. } => (u, vt),
+                };
+
+                Ok(BidiagonalOutput { d, e, u, vt })
+            }
+        }
+    };
+} // endmacro
+
+impl_bidiagonal!(f64, lapack_sys::dgebrd_, lapack_sys::dorgbr_);
+impl_bidiagonal!(f32, lapack_sys::sgebrd_, lapack_sys::sorgbr_);
+impl_bidiagonal!(c64, lapack_sys::zgebrd_, lapack_sys::zungbr_);
+impl_bidiagonal!(c32, lapack_sys::cgebrd_, lapack_sys::cungbr_);
@@ -149,3 +149,80 @@ impl_cholesky!(
     lapack_sys::cpotri_,
     lapack_sys::cpotrs_
 );
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Cholesky decomposition with complete pivoting for symmetric/hermite positive semi-definite matrix
+pub trait CholeskyPivoted_: Scalar {
+    /// Compute $P^T A P = U^\dagger U$ or $P^T A P = L L^\dagger$ according to [UPLO],
+    /// where $P$ is a permutation matrix
+    ///
+    /// The factorization stops when the largest remaining diagonal element
+    /// is not greater than `tol`, and returns the pivot and the computed rank $r$.
+    /// The $k$-th column of $AP$ is the `piv[k]`-th column of $A$ (1-based).
+    /// Only the leading $r$ rows of $U$ (or columns of $L$) are the factor,
+    /// and the remaining part of the triangle is left unspecified.
+    /// If `tol` is negative, $n \epsilon \max_i A_{ii}$ is used.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | spstrf | dpstrf | cpstrf | zpstrf |
+    ///
+    fn cholesky_pivoted(
+        l: MatrixLayout,
+        uplo: UPLO,
+        tol: Self::Real,
+        a: &mut [Self],
+    ) -> Result<(Pivot, i32)>;
+}
+
+macro_rules! impl_cholesky_pivoted {
+    ($scalar:ty, $pstrf:path) => {
+        impl CholeskyPivoted_ for $scalar {
+            fn cholesky_pivoted(
+                l: MatrixLayout,
+                uplo: UPLO,
+                tol: Self::Real,
+                a: &mut [Self],
+            ) -> Result<(Pivot, i32)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut piv = vec_uninit(n as usize);
+                let mut rank = 0;
+                let mut work: Vec<MaybeUninit<Self::Real>> = vec_uninit(2 * n as usize);
+                let mut info = 0;
+                unsafe {
+                    $pstrf(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut piv),
+                        &mut rank,
+                        &tol,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                // Positive `info` only means that the matrix is rank deficient
+                if info < 0 {
+                    info.as_lapack_result()?;
+                }
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let piv = unsafe { piv.assume_init() };
+                Ok((piv, rank))
+            }
+        }
+    };
+} // impl_cholesky_pivoted!
+
+impl_cholesky_pivoted!(f64, lapack_sys::dpstrf_);
+impl_cholesky_pivoted!(f32, lapack_sys::spstrf_);
+impl_cholesky_pivoted!(c64, lapack_sys::zpstrf_);
+impl_cholesky_pivoted!(c32, lapack_sys::cpstrf_);
@@ -0,0 +1,230 @@
+//! Complete orthogonal decomposition
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{One, ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Result of complete orthogonal decomposition
+///
+/// See [CompleteOrthogonal_] for the meaning of each component.
+pub struct CompleteOrthogonalOutput<A: Scalar> {
+    /// Numerical rank $r$ of $A$
+    pub rank: i32,
+    /// The leading $r$ columns of $Q$ as $m \times r$ matrix
+    pub q: Vec<A>,
+    /// $r \times r$ upper triangular matrix $T$
+    pub t: Vec<A>,
+    /// The leading $r$ rows of $Z$ as $r \times n$ matrix
+    pub z: Vec<A>,
+    /// Column permutation in the same manner as [QRPivoted_]
+    pub jpvt: Pivot,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Complete orthogonal decomposition
+pub trait CompleteOrthogonal_: QRPivoted_ {
+    /// Compute complete orthogonal decomposition of $m \times n$ matrix $A$
+    ///
+    /// $$
+    /// A P = Q \begin{pmatrix} T & 0 \\ 0 & 0 \end{pmatrix} Z
+    /// $$
+    ///
+    /// where $P$ is a permutation matrix, $Q$ and $Z$ are unitary (orthogonal for real matrices),
+    /// and $T$ is $r \times r$ non-singular upper triangular matrix.
+    /// This is computed by [QRPivoted_::householder_pivoted] followed by
+    /// the reduction of the upper trapezoidal $r \times n$ block of $R$ into $(T, 0) Z$.
+    ///
+    /// The numerical rank $r$ is the number of diagonal elements of $R$
+    /// satisfying $|R_{ii}| > \mathrm{rcond} |R_{00}|$.
+    /// Only the blocks of $Q$ and $Z$ coupled to $T$ are returned,
+    /// i.e. $A P \simeq Q_{:, :r} T Z_{:r, :}$,
+    /// in the same layout as $A$.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sgeqp3 | dgeqp3 | cgeqp3 | zgeqp3 |
+    /// | stzrzf | dtzrzf | ctzrzf | ztzrzf |
+    /// | sormrz | dormrz | cunmrz | zunmrz |
+    ///
+    fn complete_orthogonal(
+        l: MatrixLayout,
+        rcond: Self::Real,
+        a: &mut [Self],
+    ) -> Result<CompleteOrthogonalOutput<Self>>;
+}
+
+macro_rules! impl_complete_orthogonal {
+    ($scalar:ty, $tzrzf:path, $mrz:path) => {
+        impl CompleteOrthogonal_ for $scalar {
+            fn complete_orthogonal(
+                l: MatrixLayout,
+                rcond: Self::Real,
+                a: &mut [Self],
+            ) -> Result<CompleteOrthogonalOutput<Self>> {
+                let (m, n) = l.size();
+
+                // Row-major input is transposed into column-major order
+                let mut a_t = None;
+                if let MatrixLayout::C { .. } = l {
+                    let (_, t) = transpose(l, a);
+                    a_t = Some(t);
+                }
+                let a = a_t.as_deref_mut().unwrap_or(a);
+                let (tau_q, jpvt) =
+                    Self::householder_pivoted(MatrixLayout::F { col: n, lda: m }, a)?;
+
+                // |R_ii| is non-increasing
+                let k = m.min(n) as usize;
+                let r00 = if k > 0 {
+                    a[0].abs()
+                } else {
+                    Self::Real::zero()
+                };
+                let r = (0..k)
+                    .take_while(|&i| a[i + i * m as usize].abs() > rcond * r00)
+                    .count();
+                if r == 0 {
+                    return Ok(CompleteOrthogonalOutput {
+                        rank: 0,
+                        q: Vec::new(),
+                        t: Vec::new(),
+                        z: Vec::new(),
+                        jpvt,
+                    });
+                }
+
+                // Form the leading r columns of Q from the reflectors below the diagonal,
+                // which are left untouched by *tzrzf
+                let mut q = a[..m as usize * r].to_vec();
+                Self::q(
+                    MatrixLayout::F {
+                        col: r as i32,
+                        lda: m,
+                    },
+                    &mut q,
+                    &tau_q[..r],
+                )?;
+
+                // Reduce (R_11, R_12) into (T, 0) Z
+                let r = r as i32;
+                let mut tau_z: Vec<MaybeUninit<Self>> = vec_uninit(r as usize);
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $tzrzf(
+                        &r,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut tau_z),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $tzrzf(
+                        &r,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut tau_z),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let tau_z = unsafe { tau_z.assume_init() };
+
+                let (mu, nu, ru) = (m as usize, n as usize, r as usize);
+                let mut t = vec![Self::zero(); ru * ru];
+                for j in 0..ru {
+                    for i in 0..=j {
+                        t[i + j * ru] = a[i + j * mu];
+                    }
+                }
+
+                // The leading r rows of Z are computed as (I_r, 0) Z
+                let mut z = vec![Self::zero(); ru * nu];
+                for i in 0..ru {
+                    z[i + i * ru] = Self::one();
+                }
+                unsafe {
+                    $mrz(
+                        &(b'R' as i8),
+                        Transpose::No.as_ptr(),
+                        &r,
+                        &n,
+                        &r,
+                        &(n - r),
+                        AsPtr::as_ptr(a),
+                        &m,
+                        AsPtr::as_ptr(&tau_z),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &r,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $mrz(
+                        &(b'R' as i8),
+                        Transpose::No.as_ptr(),
+                        &r,
+                        &n,
+                        &r,
+                        &(n - r),
+                        AsPtr::as_ptr(a),
+                        &m,
+                        AsPtr::as_ptr(&tau_z),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &r,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // Return in the same layout as A
+                let to_layout = |rows: i32, cols: i32, x: Vec<Self>| match l {
+                    MatrixLayout::F { .. } => x,
+                    MatrixLayout::C { .. } => {
+                        transpose(
+                            MatrixLayout::F {
+                                col: cols,
+                                lda: rows,
+                            },
+                            &x,
+                        )
+                        .1
+                    }
+                };
+                Ok(CompleteOrthogonalOutput {
+                    rank: r,
+                    q: to_layout(m, r, q),
+                    t: to_layout(r, r, t),
+                    z: to_layout(r, n, z),
+                    jpvt,
+                })
+            }
+        }
+    };
+} // impl_complete_orthogonal!
+
+impl_complete_orthogonal!(f64, lapack_sys::dtzrzf_, lapack_sys::dormrz_);
+impl_complete_orthogonal!(f32, lapack_sys::stzrzf_, lapack_sys::sormrz_);
+impl_complete_orthogonal!(c64, lapack_sys::ztzrzf_, lapack_sys::zunmrz_);
+impl_complete_orthogonal!(c32, lapack_sys::ctzrzf_, lapack_sys::cunmrz_);
@@ -3,12 +3,14 @@
 //! LAPACK correspondance
 //! ----------------------
 //!
-//! | f32   | f64   | c32   | c64   |
-//! |:------|:------|:------|:------|
-//! | ssyev | dsyev | cheev | zheev |
+//! | driver                          | f32    | f64    | c32    | c64    |
+//! |:--------------------------------|:-------|:-------|:-------|:-------|
+//! | [EighDriver::Qr]                | ssyev  | dsyev  | cheev  | zheev  |
+//! | [EighDriver::DivideAndConquer]  | ssyevd | dsyevd | cheevd | zheevd |
+//! | [EighDriver::Mrrr]              | ssyevr | dsyevr | cheevr | zheevr |
 
 use super::*;
-use crate::{error::*, layout::MatrixLayout};
+use crate::{eigh_range::*, error::*, layout::MatrixLayout};
 use cauchy::*;
 use num_traits::{ToPrimitive, Zero};
 
@@ -188,3 +190,374 @@ macro_rules! impl_eigh_work_r {
 }
 impl_eigh_work_r!(f64, lapack_sys::dsyev_);
 impl_eigh_work_r!(f32, lapack_sys::ssyev_);
+
+/// Working memory for Hermitian eigenvalue problem using the specified [EighDriver]
+///
+/// This is separated from [EighWork] since the drivers need different working memories.
+#[non_exhaustive]
+pub struct EighDriverWork<T: Scalar> {
+    pub n: i32,
+    pub jobz: JobEv,
+    pub driver: EighDriver,
+    pub eigs: Vec<MaybeUninit<T::Real>>,
+    pub work: Vec<MaybeUninit<T>>,
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    pub iwork: Option<Vec<MaybeUninit<i32>>>,
+    /// Working memory for [EighDriver::Mrrr]
+    pub mrrr: Option<EighRangeWork<T>>,
+}
+
+pub trait EighDriverWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_eigenvectors: bool, layout: MatrixLayout, driver: EighDriver) -> Result<Self>;
+    fn calc(&mut self, uplo: UPLO, a: &mut [Self::Elem])
+        -> Result<&[<Self::Elem as Scalar>::Real]>;
+    fn eval(self, uplo: UPLO, a: &mut [Self::Elem]) -> Result<Vec<<Self::Elem as Scalar>::Real>>;
+}
+
+/// Compute all eigenvalues by [EighRangeWork], and overwrite `a` by the eigenvectors
+fn calc_mrrr<T>(
+    work: &mut EighRangeWork<T>,
+    eigs: &mut [MaybeUninit<T::Real>],
+    uplo: UPLO,
+    a: &mut [T],
+) -> Result<()>
+where
+    T: Scalar,
+    EighRangeWork<T>: EighRangeWorkImpl<Elem = T>,
+{
+    let res = work.calc(uplo, EigRange::All, a)?;
+    for (e, w) in eigs.iter_mut().zip(res.eigs) {
+        e.write(*w);
+    }
+    if let Some(z) = res.z {
+        a.copy_from_slice(z);
+    }
+    Ok(())
+}
+
+macro_rules! impl_eigh_driver_work_c {
+    ($c:ty, $ev:path, $evd:path) => {
+        impl EighDriverWorkImpl for EighDriverWork<$c> {
+            type Elem = $c;
+
+            fn new(
+                calc_eigenvectors: bool,
+                layout: MatrixLayout,
+                driver: EighDriver,
+            ) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs = vec_uninit(n as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                match driver {
+                    EighDriver::Qr => {
+                        let mut rwork = vec_uninit(3 * n as usize - 2 as usize);
+                        unsafe {
+                            $ev(
+                                jobz.as_ptr(),
+                                UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                                &n,
+                                std::ptr::null_mut(),
+                                &n,
+                                AsPtr::as_mut_ptr(&mut eigs),
+                                AsPtr::as_mut_ptr(&mut work_size),
+                                &(-1),
+                                AsPtr::as_mut_ptr(&mut rwork),
+                                &mut info,
+                            );
+                        }
+                        info.as_lapack_result()?;
+                        let lwork = work_size[0].to_usize().unwrap();
+                        let work = vec_uninit(lwork);
+                        Ok(EighDriverWork {
+                            n,
+                            eigs,
+                            jobz,
+                            driver,
+                            work,
+                            rwork: Some(rwork),
+                            iwork: None,
+                            mrrr: None,
+                        })
+                    }
+                    EighDriver::DivideAndConquer => {
+                        let mut rwork_size = [<Self::Elem as Scalar>::Real::zero()];
+                        let mut iwork_size = [0];
+                        unsafe {
+                            $evd(
+                                jobz.as_ptr(),
+                                UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                                &n,
+                                std::ptr::null_mut(),
+                                &n,
+                                AsPtr::as_mut_ptr(&mut eigs),
+                                AsPtr::as_mut_ptr(&mut work_size),
+                                &(-1),
+                                AsPtr::as_mut_ptr(&mut rwork_size),
+                                &(-1),
+                                AsPtr::as_mut_ptr(&mut iwork_size),
+                                &(-1),
+                                &mut info,
+                            );
+                        }
+                        info.as_lapack_result()?;
+                        let lwork = work_size[0].to_usize().unwrap();
+                        let lrwork = rwork_size[0].to_usize().unwrap();
+                        let liwork = iwork_size[0].to_usize().unwrap();
+                        Ok(EighDriverWork {
+                            n,
+                            eigs,
+                            jobz,
+                            driver,
+                            work: vec_uninit(lwork),
+                            rwork: Some(vec_uninit(lrwork)),
+                            iwork: Some(vec_uninit(liwork)),
+                            mrrr: None,
+                        })
+                    }
+                    EighDriver::Mrrr => Ok(EighDriverWork {
+                        n,
+                        eigs,
+                        jobz,
+                        driver,
+                        work: Vec::new(),
+                        rwork: None,
+                        iwork: None,
+                        // `a` is regarded as column-major as in the other drivers
+                        mrrr: Some(EighRangeWork::new(
+                            calc_eigenvectors,
+                            MatrixLayout::F { col: n, lda: n },
+                        )?),
+                    }),
+                }
+            }
+
+            fn calc(
+                &mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<&[<Self::Elem as Scalar>::Real]> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                match self.driver {
+                    EighDriver::Qr => unsafe {
+                        $ev(
+                            self.jobz.as_ptr(),
+                            uplo.as_ptr(),
+                            &self.n,
+                            AsPtr::as_mut_ptr(a),
+                            &self.n,
+                            AsPtr::as_mut_ptr(&mut self.eigs),
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                            &mut info,
+                        );
+                    },
+                    EighDriver::DivideAndConquer => {
+                        let rwork = self.rwork.as_mut().unwrap();
+                        let iwork = self.iwork.as_mut().unwrap();
+                        unsafe {
+                            $evd(
+                                self.jobz.as_ptr(),
+                                uplo.as_ptr(),
+                                &self.n,
+                                AsPtr::as_mut_ptr(a),
+                                &self.n,
+                                AsPtr::as_mut_ptr(&mut self.eigs),
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                AsPtr::as_mut_ptr(rwork),
+                                &rwork.len().to_i32().unwrap(),
+                                AsPtr::as_mut_ptr(iwork),
+                                &iwork.len().to_i32().unwrap(),
+                                &mut info,
+                            );
+                        }
+                    }
+                    EighDriver::Mrrr => {
+                        calc_mrrr(self.mrrr.as_mut().unwrap(), &mut self.eigs, uplo, a)?
+                    }
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { self.eigs.slice_assume_init_ref() })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<Vec<<Self::Elem as Scalar>::Real>> {
+                let _eig = self.calc(uplo, a)?;
+                Ok(unsafe { self.eigs.assume_init() })
+            }
+        }
+    };
+}
+impl_eigh_driver_work_c!(c64, lapack_sys::zheev_, lapack_sys::zheevd_);
+impl_eigh_driver_work_c!(c32, lapack_sys::cheev_, lapack_sys::cheevd_);
+
+macro_rules! impl_eigh_driver_work_r {
+    ($f:ty, $ev:path, $evd:path) => {
+        impl EighDriverWorkImpl for EighDriverWork<$f> {
+            type Elem = $f;
+
+            fn new(
+                calc_eigenvectors: bool,
+                layout: MatrixLayout,
+                driver: EighDriver,
+            ) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobz = if calc_eigenvectors {
+                    JobEv::All
+                } else {
+                    JobEv::None
+                };
+                let mut eigs = vec_uninit(n as usize);
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                match driver {
+                    EighDriver::Qr => {
+                        unsafe {
+                            $ev(
+                                jobz.as_ptr(),
+                                UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                                &n,
+                                std::ptr::null_mut(),
+                                &n,
+                                AsPtr::as_mut_ptr(&mut eigs),
+                                AsPtr::as_mut_ptr(&mut work_size),
+                                &(-1),
+                                &mut info,
+                            );
+                        }
+                        info.as_lapack_result()?;
+                        let lwork = work_size[0].to_usize().unwrap();
+                        let work = vec_uninit(lwork);
+                        Ok(EighDriverWork {
+                            n,
+                            eigs,
+                            jobz,
+                            driver,
+                            work,
+                            rwork: None,
+                            iwork: None,
+                            mrrr: None,
+                        })
+                    }
+                    EighDriver::DivideAndConquer => {
+                        let mut iwork_size = [0];
+                        unsafe {
+                            $evd(
+                                jobz.as_ptr(),
+                                UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                                &n,
+                                std::ptr::null_mut(),
+                                &n,
+                                AsPtr::as_mut_ptr(&mut eigs),
+                                AsPtr::as_mut_ptr(&mut work_size),
+                                &(-1),
+                                AsPtr::as_mut_ptr(&mut iwork_size),
+                                &(-1),
+                                &mut info,
+                            );
+                        }
+                        info.as_lapack_result()?;
+                        let lwork = work_size[0].to_usize().unwrap();
+                        let liwork = iwork_size[0].to_usize().unwrap();
+                        Ok(EighDriverWork {
+                            n,
+                            eigs,
+                            jobz,
+                            driver,
+                            work: vec_uninit(lwork),
+                            rwork: None,
+                            iwork: Some(vec_uninit(liwork)),
+                            mrrr: None,
+                        })
+                    }
+                    EighDriver::Mrrr => Ok(EighDriverWork {
+                        n,
+                        eigs,
+                        jobz,
+                        driver,
+                        work: Vec::new(),
+                        rwork: None,
+                        iwork: None,
+                        // `a` is regarded as column-major as in the other drivers
+                        mrrr: Some(EighRangeWork::new(
+                            calc_eigenvectors,
+                            MatrixLayout::F { col: n, lda: n },
+                        )?),
+                    }),
+                }
+            }
+
+            fn calc(
+                &mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<&[<Self::Elem as Scalar>::Real]> {
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                match self.driver {
+                    EighDriver::Qr => unsafe {
+                        $ev(
+                            self.jobz.as_ptr(),
+                            uplo.as_ptr(),
+                            &self.n,
+                            AsPtr::as_mut_ptr(a),
+                            &self.n,
+                            AsPtr::as_mut_ptr(&mut self.eigs),
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            &mut info,
+                        );
+                    },
+                    EighDriver::DivideAndConquer => {
+                        let iwork = self.iwork.as_mut().unwrap();
+                        unsafe {
+                            $evd(
+                                self.jobz.as_ptr(),
+                                uplo.as_ptr(),
+                                &self.n,
+                                AsPtr::as_mut_ptr(a),
+                                &self.n,
+                                AsPtr::as_mut_ptr(&mut self.eigs),
+                                AsPtr::as_mut_ptr(&mut self.work),
+                                &lwork,
+                                AsPtr::as_mut_ptr(iwork),
+                                &iwork.len().to_i32().unwrap(),
+                                &mut info,
+                            );
+                        }
+                    }
+                    EighDriver::Mrrr => {
+                        calc_mrrr(self.mrrr.as_mut().unwrap(), &mut self.eigs, uplo, a)?
+                    }
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { self.eigs.slice_assume_init_ref() })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                a: &mut [Self::Elem],
+            ) -> Result<Vec<<Self::Elem as Scalar>::Real>> {
+                let _eig = self.calc(uplo, a)?;
+                Ok(unsafe { self.eigs.assume_init() })
+            }
+        }
+    };
+}
+impl_eigh_driver_work_r!(f64, lapack_sys::dsyev_, lapack_sys::dsyevd_);
+impl_eigh_driver_work_r!(f32, lapack_sys::ssyev_, lapack_sys::ssyevd_);
@@ -0,0 +1,433 @@
+//! Eigenvalue problem for symmetric/Hermitian matricies with selected eigenvalues
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | ssyevr | dsyevr | cheevr | zheevr |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Eigenvalue problem for symmetric/hermite matrix with selected eigenvalues
+///
+/// This computes the eigenvalues $\lambda_i$ and eigenvectors $v_i$ of
+/// $$
+/// A v_i = \lambda_i v_i
+/// $$
+/// only for the eigenvalues selected by [EigRange],
+/// using Multiple Relatively Robust Representations (MRRR).
+/// Eigenvalues are returned in ascending order.
+///
+/// The number of eigenvalues $m$ is determined in [EighRangeWork::calc]
+/// since it is unknown before computation for [EigRange::Value].
+/// The eigenvectors are returned as the columns of $n \times m$ matrix
+/// in the same layout as the input matrix.
+///
+#[non_exhaustive]
+pub struct EighRangeWork<T: Scalar> {
+    /// Problem size
+    pub n: i32,
+    /// Layout of the input matrix
+    pub layout: MatrixLayout,
+    /// Compute eigenvectors or not
+    pub jobz: JobEv,
+    /// Number of eigenvalues found in the last computation
+    pub m: i32,
+
+    /// Eigenvalues
+    pub eigs: Vec<MaybeUninit<T::Real>>,
+    /// Eigenvectors
+    pub z: Option<Vec<MaybeUninit<T>>>,
+    /// Support of eigenvectors
+    pub isuppz: Vec<MaybeUninit<i32>>,
+
+    /// Working memory
+    pub work: Vec<MaybeUninit<T>>,
+    /// Working memory with `T::Real`
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Working memory with `i32`
+    pub iwork: Vec<MaybeUninit<i32>>,
+}
+
+impl<T> EighRangeWork<T>
+where
+    T: Scalar,
+    EighRangeWork<T>: EighRangeWorkImpl<Elem = T>,
+{
+    /// Create new working memory for eigenvalues compution.
+    pub fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+        EighRangeWorkImpl::new(calc_v, l)
+    }
+
+    /// Compute selected eigenvalues and vectors on this working memory.
+    pub fn calc(
+        &mut self,
+        uplo: UPLO,
+        range: EigRange<T::Real>,
+        a: &mut [T],
+    ) -> Result<EighRangeRef<'_, T>> {
+        EighRangeWorkImpl::calc(self, uplo, range, a)
+    }
+
+    /// Compute selected eigenvalues and vectors by consuming this working memory.
+    pub fn eval(
+        self,
+        uplo: UPLO,
+        range: EigRange<T::Real>,
+        a: &mut [T],
+    ) -> Result<EighRangeOwned<T>> {
+        EighRangeWorkImpl::eval(self, uplo, range, a)
+    }
+}
+
+/// Owned result of eigenvalue problem by [EighRangeWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighRangeOwned<T: Scalar> {
+    /// Eigenvalues
+    pub eigs: Vec<T::Real>,
+    /// Eigenvectors
+    pub z: Option<Vec<T>>,
+}
+
+/// Reference result of eigenvalue problem by [EighRangeWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EighRangeRef<'work, T: Scalar> {
+    /// Eigenvalues
+    pub eigs: &'work [T::Real],
+    /// Eigenvectors
+    pub z: Option<&'work [T]>,
+}
+
+/// Helper trait for implementing [EighRangeWork] methods
+pub trait EighRangeWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_v: bool, l: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        uplo: UPLO,
+        range: EigRange<<Self::Elem as Scalar>::Real>,
+        a: &mut [Self::Elem],
+    ) -> Result<EighRangeRef<'work, Self::Elem>>;
+    fn eval(
+        self,
+        uplo: UPLO,
+        range: EigRange<<Self::Elem as Scalar>::Real>,
+        a: &mut [Self::Elem],
+    ) -> Result<EighRangeOwned<Self::Elem>>;
+}
+
+/// Truncate eigenvectors into `n x m`, and re-transpose them if the input is C-continuous
+///
+/// Safety
+/// ------
+/// - The first `n * m` elements of `z` must be initialized.
+///
+unsafe fn finalize_eigenvectors<T: Copy>(
+    l: MatrixLayout,
+    n: i32,
+    m: i32,
+    z: &mut [MaybeUninit<T>],
+) -> &[T] {
+    let z = slice_prefix_assume_init_mut(z, (n * m) as usize);
+    if matches!(l, MatrixLayout::C { .. }) {
+        let (_, zt) = transpose(MatrixLayout::F { col: m, lda: n }, z);
+        z.copy_from_slice(&zt);
+    }
+    z
+}
+
+macro_rules! impl_eigh_range_work_c {
+    ($c:ty, $evr:path) => {
+        impl EighRangeWorkImpl for EighRangeWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                assert_eq!(l.len(), l.lda());
+                let n = l.len();
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                let mut eigs = vec_uninit(n as usize);
+                let mut isuppz = vec_uninit(2 * n.max(1) as usize);
+                let mut m = 0;
+                let range = EigRange::All;
+                let (vl, vu, il, iu) = range.bounds();
+                let abstol = <Self::Elem as Scalar>::Real::zero();
+
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut rwork_size = [<Self::Elem as Scalar>::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evr(
+                        jobz.as_ptr(),
+                        range.as_ptr(), // dummy, working memory is not affected by RANGE
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        std::ptr::null_mut(),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let lrwork = rwork_size[0].to_usize().unwrap();
+                let liwork = iwork_size[0].to_usize().unwrap();
+                Ok(EighRangeWork {
+                    n,
+                    layout: l,
+                    jobz,
+                    m,
+                    eigs,
+                    // for the upper bound of the number of eigenvalues
+                    z: jobz.then(|| vec_uninit((n * n) as usize)),
+                    isuppz,
+                    work: vec_uninit(lwork),
+                    rwork: Some(vec_uninit(lrwork)),
+                    iwork: vec_uninit(liwork),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                uplo: UPLO,
+                range: EigRange<<Self::Elem as Scalar>::Real>,
+                a: &mut [Self::Elem],
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                let n = self.n;
+                // Transpose if a is C-continuous
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                }
+                let (vl, vu, il, iu) = range.bounds();
+                // use default tolerance
+                let abstol = <Self::Elem as Scalar>::Real::zero();
+                let lwork = self.work.len().to_i32().unwrap();
+                let lrwork = self.rwork.as_ref().unwrap().len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $evr(
+                        self.jobz.as_ptr(),
+                        range.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut self.m,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        self.z
+                            .as_mut()
+                            .map(|z| AsPtr::as_mut_ptr(z))
+                            .unwrap_or(std::ptr::null_mut()),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut self.isuppz),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        &lrwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let (l, m) = (self.layout, self.m);
+                // Only the first `m` eigenvalues and eigenvectors are written
+                let eigs = unsafe { slice_prefix_assume_init_ref(&self.eigs, m as usize) };
+                let z = self
+                    .z
+                    .as_mut()
+                    .map(|z| unsafe { finalize_eigenvectors(l, n, m, z) });
+                Ok(EighRangeRef { eigs, z })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                range: EigRange<<Self::Elem as Scalar>::Real>,
+                a: &mut [Self::Elem],
+            ) -> Result<EighRangeOwned<Self::Elem>> {
+                let _ = self.calc(uplo, range, a)?;
+                let (n, m) = (self.n as usize, self.m as usize);
+                self.eigs.truncate(m);
+                let eigs = unsafe { self.eigs.assume_init() };
+                let z = self.z.map(|mut z| {
+                    z.truncate(n * m);
+                    unsafe { z.assume_init() }
+                });
+                Ok(EighRangeOwned { eigs, z })
+            }
+        }
+    };
+}
+
+impl_eigh_range_work_c!(c64, lapack_sys::zheevr_);
+impl_eigh_range_work_c!(c32, lapack_sys::cheevr_);
+
+macro_rules! impl_eigh_range_work_r {
+    ($f:ty, $evr:path) => {
+        impl EighRangeWorkImpl for EighRangeWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+                assert_eq!(l.len(), l.lda());
+                let n = l.len();
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                let mut eigs = vec_uninit(n as usize);
+                let mut isuppz = vec_uninit(2 * n.max(1) as usize);
+                let mut m = 0;
+                let range = EigRange::All;
+                let (vl, vu, il, iu) = range.bounds();
+                let abstol = <Self::Elem as Scalar>::Real::zero();
+
+                let mut info = 0;
+                let mut work_size = [Self::Elem::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $evr(
+                        jobz.as_ptr(),
+                        range.as_ptr(), // dummy, working memory is not affected by RANGE
+                        UPLO::Upper.as_ptr(), // dummy, working memory is not affected by UPLO
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        std::ptr::null_mut(),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let lwork = work_size[0].to_usize().unwrap();
+                let liwork = iwork_size[0].to_usize().unwrap();
+                Ok(EighRangeWork {
+                    n,
+                    layout: l,
+                    jobz,
+                    m,
+                    eigs,
+                    // for the upper bound of the number of eigenvalues
+                    z: jobz.then(|| vec_uninit((n * n) as usize)),
+                    isuppz,
+                    work: vec_uninit(lwork),
+                    rwork: None,
+                    iwork: vec_uninit(liwork),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                uplo: UPLO,
+                range: EigRange<<Self::Elem as Scalar>::Real>,
+                a: &mut [Self::Elem],
+            ) -> Result<EighRangeRef<'work, Self::Elem>> {
+                let n = self.n;
+                // Transpose if a is C-continuous
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                }
+                let (vl, vu, il, iu) = range.bounds();
+                // use default tolerance
+                let abstol = <Self::Elem as Scalar>::Real::zero();
+                let lwork = self.work.len().to_i32().unwrap();
+                let liwork = self.iwork.len().to_i32().unwrap();
+                let mut info = 0;
+                unsafe {
+                    $evr(
+                        self.jobz.as_ptr(),
+                        range.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut self.m,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        self.z
+                            .as_mut()
+                            .map(|z| AsPtr::as_mut_ptr(z))
+                            .unwrap_or(std::ptr::null_mut()),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut self.isuppz),
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(&mut self.iwork),
+                        &liwork,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let (l, m) = (self.layout, self.m);
+                // Only the first `m` eigenvalues and eigenvectors are written
+                let eigs = unsafe { slice_prefix_assume_init_ref(&self.eigs, m as usize) };
+                let z = self
+                    .z
+                    .as_mut()
+                    .map(|z| unsafe { finalize_eigenvectors(l, n, m, z) });
+                Ok(EighRangeRef { eigs, z })
+            }
+
+            fn eval(
+                mut self,
+                uplo: UPLO,
+                range: EigRange<<Self::Elem as Scalar>::Real>,
+                a: &mut [Self::Elem],
+            ) -> Result<EighRangeOwned<Self::Elem>> {
+                let _ = self.calc(uplo, range, a)?;
+                let (n, m) = (self.n as usize, self.m as usize);
+                self.eigs.truncate(m);
+                let eigs = unsafe { self.eigs.assume_init() };
+                let z = self.z.map(|mut z| {
+                    z.truncate(n * m);
+                    unsafe { z.assume_init() }
+                });
+                Ok(EighRangeOwned { eigs, z })
+            }
+        }
+    };
+}
+
+impl_eigh_range_work_r!(f64, lapack_sys::dsyevr_);
+impl_eigh_range_work_r!(f32, lapack_sys::ssyevr_);
@@ -0,0 +1,412 @@
+use super::*;
+use crate::error::*;
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+/// Selected eigenvalues and eigenvectors of real symmetric tridiagonal matrix
+pub struct EighTridiagonalOutput<A: Scalar> {
+    /// Eigenvalues in ascending order
+    pub eigs: Vec<A::Real>,
+    /// Eigenvectors as the columns of column-major matrix
+    pub z: Option<Vec<A>>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Eigenvalue problem for real symmetric tridiagonal matrix
+///
+/// The tridiagonal matrix $T$ is given by its diagonal elements `d` of length $n$
+/// and its off-diagonal elements `e` of length $n - 1$.
+/// Eigenvectors are returned as the columns of $n \times m$ column-major matrix,
+/// where $m$ is the number of computed eigenvalues.
+///
+/// These routines only handle real matrices.
+/// Complex types are supported to return eigenvectors in the same type
+/// as hermite matrices reduced by [Tridiagonalize_].
+pub trait EighTridiagonal_: Scalar {
+    /// Compute all eigenvalues, and optionally eigenvectors, by implicit QL/QR method
+    ///
+    /// Eigenvalues are stored in `d` in ascending order, and `e` is destroyed.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sstev  | dstev  | sstev  | dstev  |
+    ///
+    fn eigh_tridiagonal(
+        calc_v: bool,
+        d: &mut [Self::Real],
+        e: &mut [Self::Real],
+    ) -> Result<Option<Vec<Self>>>;
+
+    /// Compute all eigenvalues, and optionally eigenvectors, by divide and conquer method
+    ///
+    /// Eigenvalues are stored in `d` in ascending order, and `e` is destroyed.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sstevd | dstevd | sstevd | dstevd |
+    ///
+    fn eigh_tridiagonal_dc(
+        calc_v: bool,
+        d: &mut [Self::Real],
+        e: &mut [Self::Real],
+    ) -> Result<Option<Vec<Self>>>;
+
+    /// Compute selected eigenvalues, and optionally eigenvectors,
+    /// by Multiple Relatively Robust Representations (MRRR)
+    ///
+    /// Eigenvalues are returned in ascending order, and `d` and `e` are destroyed.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sstevr | dstevr | sstevr | dstevr |
+    ///
+    fn eigh_tridiagonal_range(
+        calc_v: bool,
+        range: EigRange<Self::Real>,
+        d: &mut [Self::Real],
+        e: &mut [Self::Real],
+    ) -> Result<EighTridiagonalOutput<Self>>;
+
+    /// Compute selected eigenvalues by bisection,
+    /// and optionally eigenvectors by inverse iteration
+    ///
+    /// Eigenvalues are returned in ascending order.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sstebz | dstebz | sstebz | dstebz |
+    /// | sstein | dstein | cstein | zstein |
+    ///
+    fn eigh_tridiagonal_bisect(
+        calc_v: bool,
+        range: EigRange<Self::Real>,
+        d: &[Self::Real],
+        e: &[Self::Real],
+    ) -> Result<EighTridiagonalOutput<Self>>;
+}
+
+macro_rules! impl_eigh_tridiagonal {
+    ($scalar:ty, $stev:path, $stevd:path, $stevr:path, $stebz:path, $stein:path) => {
+        impl EighTridiagonal_ for $scalar {
+            fn eigh_tridiagonal(
+                calc_v: bool,
+                d: &mut [Self::Real],
+                e: &mut [Self::Real],
+            ) -> Result<Option<Vec<Self>>> {
+                let n = d.len() as i32;
+                assert_eq!(e.len(), (n - 1).max(0) as usize);
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                let mut z: Vec<MaybeUninit<Self::Real>> =
+                    vec_uninit(if calc_v { (n * n) as usize } else { 0 });
+                let mut work: Vec<MaybeUninit<Self::Real>> =
+                    vec_uninit((2 * n - 2).max(1) as usize);
+                let mut info = 0;
+                unsafe {
+                    $stev(
+                        jobz.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(e),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(jobz.then(|| {
+                    let z = unsafe { z.assume_init() };
+                    z.into_iter().map(Self::from_real).collect()
+                }))
+            }
+
+            fn eigh_tridiagonal_dc(
+                calc_v: bool,
+                d: &mut [Self::Real],
+                e: &mut [Self::Real],
+            ) -> Result<Option<Vec<Self>>> {
+                let n = d.len() as i32;
+                assert_eq!(e.len(), (n - 1).max(0) as usize);
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                let mut z: Vec<MaybeUninit<Self::Real>> =
+                    vec_uninit(if calc_v { (n * n) as usize } else { 0 });
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $stevd(
+                        jobz.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(e),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self::Real>> = vec_uninit(lwork);
+                let liwork = iwork_size[0].to_usize().unwrap();
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork);
+                unsafe {
+                    $stevd(
+                        jobz.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(e),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &(liwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(jobz.then(|| {
+                    let z = unsafe { z.assume_init() };
+                    z.into_iter().map(Self::from_real).collect()
+                }))
+            }
+
+            fn eigh_tridiagonal_range(
+                calc_v: bool,
+                range: EigRange<Self::Real>,
+                d: &mut [Self::Real],
+                e: &mut [Self::Real],
+            ) -> Result<EighTridiagonalOutput<Self>> {
+                let n = d.len() as i32;
+                assert_eq!(e.len(), (n - 1).max(0) as usize);
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                let (vl, vu, il, iu) = range.bounds();
+                let abstol = Self::Real::zero();
+                let mut m = 0;
+                let mut w: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                // The number of eigenvalues in a value range is unknown before computation
+                let mut z: Vec<MaybeUninit<Self::Real>> =
+                    vec_uninit(if calc_v { (n * n) as usize } else { 0 });
+                let mut isuppz: Vec<MaybeUninit<i32>> = vec_uninit(2 * n.max(1) as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::Real::zero()];
+                let mut iwork_size = [0];
+                unsafe {
+                    $stevr(
+                        jobz.as_ptr(),
+                        range.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(e),
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut iwork_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self::Real>> = vec_uninit(lwork);
+                let liwork = iwork_size[0].to_usize().unwrap();
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork);
+                unsafe {
+                    $stevr(
+                        jobz.as_ptr(),
+                        range.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(e),
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        &mut m,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut isuppz),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &(liwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // Only the first `m` eigenvalues and eigenvectors are written
+                w.truncate(m as usize);
+                let w = unsafe { w.assume_init() };
+                let z = jobz.then(|| {
+                    z.truncate((n * m) as usize);
+                    let z = unsafe { z.assume_init() };
+                    z.into_iter().map(Self::from_real).collect()
+                });
+                Ok(EighTridiagonalOutput { eigs: w, z })
+            }
+
+            fn eigh_tridiagonal_bisect(
+                calc_v: bool,
+                range: EigRange<Self::Real>,
+                d: &[Self::Real],
+                e: &[Self::Real],
+            ) -> Result<EighTridiagonalOutput<Self>> {
+                let n = d.len() as i32;
+                assert_eq!(e.len(), (n - 1).max(0) as usize);
+                let (vl, vu, il, iu) = range.bounds();
+                let abstol = Self::Real::zero();
+                // *stein requires eigenvalues grouped by split-off blocks (ORDER = 'B')
+                let order = if calc_v {
+                    EigOrder::Block
+                } else {
+                    EigOrder::Entire
+                };
+                let mut m = 0;
+                let mut nsplit = 0;
+                let mut w: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut iblock: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                let mut isplit: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                let mut work: Vec<MaybeUninit<Self::Real>> = vec_uninit(4 * n as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(3 * n as usize);
+                let mut info = 0;
+                unsafe {
+                    $stebz(
+                        range.as_ptr(),
+                        order.as_ptr(),
+                        &n,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &abstol,
+                        AsPtr::as_ptr(d),
+                        AsPtr::as_ptr(e),
+                        &mut m,
+                        &mut nsplit,
+                        AsPtr::as_mut_ptr(&mut w),
+                        AsPtr::as_mut_ptr(&mut iblock),
+                        AsPtr::as_mut_ptr(&mut isplit),
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                // Only the first `m` eigenvalues are written
+                w.truncate(m as usize);
+                let w = unsafe { w.assume_init() };
+                if !calc_v {
+                    return Ok(EighTridiagonalOutput { eigs: w, z: None });
+                }
+
+                // `iblock` and `isplit` are partially written by *stebz, and passed to *stein as is
+                let mut z: Vec<MaybeUninit<Self>> = vec_uninit((n * m) as usize);
+                let mut work: Vec<MaybeUninit<Self::Real>> = vec_uninit(5 * n as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                let mut ifail: Vec<MaybeUninit<i32>> = vec_uninit(m as usize);
+                unsafe {
+                    $stein(
+                        &n,
+                        AsPtr::as_ptr(d),
+                        AsPtr::as_ptr(e),
+                        &m,
+                        AsPtr::as_ptr(&w),
+                        AsPtr::as_ptr(&iblock),
+                        AsPtr::as_ptr(&isplit),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        AsPtr::as_mut_ptr(&mut ifail),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let z = unsafe { z.assume_init() };
+
+                // Sort eigenvalues and eigenvectors in ascending order across the blocks
+                let n = n as usize;
+                let mut index: Vec<usize> = (0..w.len()).collect();
+                index.sort_by(|&i, &j| w[i].partial_cmp(&w[j]).unwrap());
+                let w_sorted = index.iter().map(|&i| w[i]).collect();
+                let z_sorted = index
+                    .iter()
+                    .flat_map(|&i| z[i * n..(i + 1) * n].iter().cloned())
+                    .collect();
+                Ok(EighTridiagonalOutput {
+                    eigs: w_sorted,
+                    z: Some(z_sorted),
+                })
+            }
+        }
+    };
+} // endmacro
+
+impl_eigh_tridiagonal!(
+    f64,
+    lapack_sys::dstev_,
+    lapack_sys::dstevd_,
+    lapack_sys::dstevr_,
+    lapack_sys::dstebz_,
+    lapack_sys::dstein_
+);
+impl_eigh_tridiagonal!(
+    f32,
+    lapack_sys::sstev_,
+    lapack_sys::sstevd_,
+    lapack_sys::sstevr_,
+    lapack_sys::sstebz_,
+    lapack_sys::sstein_
+);
+impl_eigh_tridiagonal!(
+    c64,
+    lapack_sys::dstev_,
+    lapack_sys::dstevd_,
+    lapack_sys::dstevr_,
+    lapack_sys::dstebz_,
+    lapack_sys::zstein_
+);
+impl_eigh_tridiagonal!(
+    c32,
+    lapack_sys::sstev_,
+    lapack_sys::sstevd_,
+    lapack_sys::sstevr_,
+    lapack_sys::sstebz_,
+    lapack_sys::cstein_
+);
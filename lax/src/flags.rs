@@ -92,6 +92,46 @@ impl JobEv {
     }
 }
 
+/// Specify whether eigenvalues are sorted on the diagonal of the Schur form
+/// by `*gees` or `*gges`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Sort {
+    /// Eigenvalues selected by the `SELECT` function are moved to the top-left
+    Sorted = b'S',
+    /// Eigenvalues are not ordered
+    NotSorted = b'N',
+}
+
+impl Sort {
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const Sort as *const i8
+    }
+}
+
+/// Specify which reciprocal condition numbers are computed on reordering
+/// the Schur form by `*trsen`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Sense {
+    /// None
+    None = b'N',
+    /// For the average of the selected eigenvalues only
+    Eigenvalues = b'E',
+    /// For the invariant subspace only
+    Subspace = b'V',
+    /// For both the eigenvalues and the invariant subspace
+    Both = b'B',
+}
+
+impl Sense {
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const Sense as *const i8
+    }
+}
+
 /// Specifies how many singular vectors are computed
 ///
 /// For an input matrix $A$ of shape $m \times n$,
@@ -137,3 +177,115 @@ impl Diag {
         self as *const Diag as *const i8
     }
 }
+
+/// Specify which unitary matrix is generated from the Householder reflectors of bidiagonal reduction
+#[cfg_attr(doc, katexit::katexit)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Vect {
+    /// $Q$ multiplied from the left, i.e. the left singular vectors side
+    Q = b'Q',
+    /// $P^H$ multiplied from the right, i.e. the right singular vectors side
+    P = b'P',
+}
+
+impl Vect {
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const Vect as *const i8
+    }
+}
+
+/// Specify which unitary matrices are computed in generalized singular value decomposition
+///
+/// `*ggsvd3` takes a distinct character for each of $U$, $V$ and $Q$.
+#[cfg_attr(doc, katexit::katexit)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum JobGsvd {
+    /// Compute $U$ for the first matrix $A$
+    U = b'U',
+    /// Compute $V$ for the second matrix $B$
+    V = b'V',
+    /// Compute $Q$ for the departure space
+    Q = b'Q',
+    /// Do not compute the unitary matrix
+    None = b'N',
+}
+
+impl JobGsvd {
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const JobGsvd as *const i8
+    }
+}
+
+/// Specify how eigenvalues are ordered by `*stebz`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum EigOrder {
+    /// Grouped by split-off block, and ordered from smallest to largest within each block
+    Block = b'B',
+    /// Ordered from smallest to largest over the entire matrix
+    Entire = b'E',
+}
+
+impl EigOrder {
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const i8 {
+        self as *const EigOrder as *const i8
+    }
+}
+
+/// Range of eigenvalues to be computed for symmetric/hermite eigenvalue problem
+#[derive(Debug, Clone, PartialEq)]
+pub enum EigRange<R> {
+    /// All eigenvalues
+    All,
+    /// Eigenvalues in the half-open interval `(vl, vu]`
+    Value(R, R),
+    /// Eigenvalues with the indices in the range when sorted in ascending order,
+    /// e.g. `Index(0..3)` for the three smallest eigenvalues.
+    /// Singular values are sorted in descending order instead, see [crate::SVDRange_].
+    Index(std::ops::Range<usize>),
+}
+
+impl<R: Copy + num_traits::Zero> EigRange<R> {
+    /// Lower and upper bounds `(vl, vu, il, iu)` passed to LAPACK
+    ///
+    /// `il` and `iu` are 1-based and inclusive as LAPACK expects.
+    /// Unused bounds are filled by zero.
+    pub fn bounds(&self) -> (R, R, i32, i32) {
+        match self {
+            EigRange::All => (R::zero(), R::zero(), 0, 0),
+            EigRange::Value(vl, vu) => (*vl, *vu, 0, 0),
+            EigRange::Index(range) => (
+                R::zero(),
+                R::zero(),
+                range.start as i32 + 1,
+                range.end as i32,
+            ),
+        }
+    }
+
+    /// To use Fortran LAPACK API in lapack-sys crate
+    pub fn as_ptr(&self) -> *const i8 {
+        match self {
+            EigRange::All => &(b'A' as i8),
+            EigRange::Value(..) => &(b'V' as i8),
+            EigRange::Index(..) => &(b'I' as i8),
+        }
+    }
+}
+
+/// Driver routine for symmetric/hermite eigenvalue problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum EighDriver {
+    /// Implicit QL/QR method, `*syev/*heev`
+    #[default]
+    Qr,
+    /// Divide and conquer method, `*syevd/*heevd`.
+    /// This is much faster than [EighDriver::Qr] for large matrices
+    /// at the cost of larger working memory.
+    DivideAndConquer,
+    /// Multiple Relatively Robust Representations (MRRR), `*syevr/*heevr`
+    Mrrr,
+}
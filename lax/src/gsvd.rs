@@ -0,0 +1,242 @@
+//! Generalized singular-value decomposition
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Result of generalized SVD
+///
+/// See [GSVD_] for the meaning of each component.
+pub struct GSVDOutput<A: Scalar> {
+    /// Number of rows of $R$ coupled only to $A$
+    pub k: i32,
+    /// Number of rows of $R$ coupled to both $A$ and $B$,
+    /// i.e. $k + l$ is the effective numerical rank of $(A^T, B^T)^T$
+    pub l: i32,
+    /// Diagonal values of $C$ with the leading $k$ ones
+    pub alpha: Vec<A::Real>,
+    /// Diagonal values of $S$ with the leading $k$ zeros
+    pub beta: Vec<A::Real>,
+    /// Unitary matrix $U$ for $A$
+    pub u: Option<Vec<A>>,
+    /// Unitary matrix $V$ for $B$
+    pub v: Option<Vec<A>>,
+    /// Unitary matrix $Q$ for departure space
+    pub q: Option<Vec<A>>,
+    /// Upper triangular $(k + l) \times (k + l)$ matrix $R$
+    pub r: Vec<A>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized singular value decomposition
+pub trait GSVD_: Scalar {
+    /// Compute generalized singular value decomposition of $m \times n$ matrix $A$
+    /// and $p \times n$ matrix $B$
+    ///
+    /// $$
+    /// U^\dagger A Q = D_1 \begin{pmatrix} 0 & R \end{pmatrix}, \quad
+    /// V^\dagger B Q = D_2 \begin{pmatrix} 0 & R \end{pmatrix}
+    /// $$
+    ///
+    /// where $U$, $V$ and $Q$ are unitary (orthogonal for real matrices),
+    /// $R$ is $(k + l) \times (k + l)$ non-singular upper triangular matrix,
+    /// and $D_1$ and $D_2$ are $m \times (k + l)$ and $p \times (k + l)$ "diagonal" matrices
+    /// consisting of `alpha` and `beta`:
+    ///
+    /// - $(D_1)_{ii}$ = `alpha[i]` for $i < \min(m, k + l)$
+    /// - $(D_2)_{i - k, i}$ = `beta[i]` for $k \le i < k + l$
+    /// - the other elements are zero
+    ///
+    /// The generalized singular values of $(A, B)$ are `alpha[i] / beta[i]` for $i < k + l$.
+    /// Note that they are not sorted.
+    ///
+    /// $U$, $V$, $Q$ and $R$ are returned in the same layout as $A$.
+    /// $A$ and $B$ are destroyed.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32     | f64     | c32     | c64     |
+    /// |:--------|:--------|:--------|:--------|
+    /// | sggsvd3 | dggsvd3 | cggsvd3 | zggsvd3 |
+    ///
+    fn gsvd(
+        la: MatrixLayout,
+        lb: MatrixLayout,
+        calc_u: bool,
+        calc_v: bool,
+        calc_q: bool,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<GSVDOutput<Self>>;
+}
+
+/// Extract $R$ from $A$ and $B$ overwritten by `*ggsvd3` into column-major order
+fn extract_r<T: Scalar>(m: i32, n: i32, p: i32, k: i32, l: i32, a: &[T], b: &[T]) -> Vec<T> {
+    let (m, n, p) = (m as usize, n as usize, p as usize);
+    let r = (k + l) as usize;
+    let mut out = vec![T::zero(); r * r];
+    for j in 0..r {
+        for i in 0..=j {
+            out[i + j * r] = if i < m {
+                // A(0..min(m, k+l), n-k-l..n)
+                a[i + (n - r + j) * m]
+            } else {
+                // B(m-k..l, n+m-k-l..n) for the rows of R below m
+                b[(i - k as usize) + (n - r + j) * p]
+            };
+        }
+    }
+    out
+}
+
+macro_rules! impl_gsvd {
+    (@real, $scalar:ty, $ggsvd3:path) => {
+        impl_gsvd!(@body, $scalar, $ggsvd3, );
+    };
+    (@complex, $scalar:ty, $ggsvd3:path) => {
+        impl_gsvd!(@body, $scalar, $ggsvd3, rwork);
+    };
+    (@body, $scalar:ty, $ggsvd3:path, $($rwork_ident:ident),*) => {
+        impl GSVD_ for $scalar {
+            fn gsvd(
+                la: MatrixLayout,
+                lb: MatrixLayout,
+                calc_u: bool,
+                calc_v: bool,
+                calc_q: bool,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<GSVDOutput<Self>> {
+                let (m, n) = la.size();
+                let (p, nb) = lb.size();
+                assert_eq!(n, nb, "The numbers of columns of A and B must be identical");
+
+                // Row-major inputs are transposed into column-major order
+                let mut a_t = None;
+                if let MatrixLayout::C { .. } = la {
+                    let (_, t) = transpose(la, a);
+                    a_t = Some(t);
+                }
+                let a = a_t.as_deref_mut().unwrap_or(a);
+                let mut b_t = None;
+                if let MatrixLayout::C { .. } = lb {
+                    let (_, t) = transpose(lb, b);
+                    b_t = Some(t);
+                }
+                let b = b_t.as_deref_mut().unwrap_or(b);
+
+                let jobu = if calc_u { JobGsvd::U } else { JobGsvd::None };
+                let jobv = if calc_v { JobGsvd::V } else { JobGsvd::None };
+                let jobq = if calc_q { JobGsvd::Q } else { JobGsvd::None };
+                let mut u: Option<Vec<MaybeUninit<Self>>> = calc_u.then(|| vec_uninit((m * m) as usize));
+                let mut v: Option<Vec<MaybeUninit<Self>>> = calc_v.then(|| vec_uninit((p * p) as usize));
+                let mut q: Option<Vec<MaybeUninit<Self>>> = calc_q.then(|| vec_uninit((n * n) as usize));
+
+                let mut k = 0;
+                let mut l = 0;
+                let mut alpha: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut beta: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(n as usize);
+                $(
+                let mut $rwork_ident: Vec<MaybeUninit<Self::Real>> = vec_uninit(2 * n as usize);
+                )*
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $ggsvd3(
+                        jobu.as_ptr(),
+                        jobv.as_ptr(),
+                        jobq.as_ptr(),
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(v.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(q.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        $(AsPtr::as_mut_ptr(&mut $rwork_ident),)*
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $ggsvd3(
+                        jobu.as_ptr(),
+                        jobv.as_ptr(),
+                        jobq.as_ptr(),
+                        &m,
+                        &n,
+                        &p,
+                        &mut k,
+                        &mut l,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(v.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(q.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        $(AsPtr::as_mut_ptr(&mut $rwork_ident),)*
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let r = extract_r(m, n, p, k, l, a, b);
+                let u = u.map(|u| unsafe { u.assume_init() });
+                let v = v.map(|v| unsafe { v.assume_init() });
+                let q = q.map(|q| unsafe { q.assume_init() });
+
+                // Return in the same layout as A
+                let to_layout = |rows: i32, cols: i32, x: Vec<Self>| match la {
+                    MatrixLayout::F { .. } => x,
+                    MatrixLayout::C { .. } => transpose(MatrixLayout::F { col: cols, lda: rows }, &x).1,
+                };
+                Ok(GSVDOutput {
+                    k,
+                    l,
+                    alpha: unsafe { alpha.assume_init() },
+                    beta: unsafe { beta.assume_init() },
+                    u: u.map(|u| to_layout(m, m, u)),
+                    v: v.map(|v| to_layout(p, p, v)),
+                    q: q.map(|q| to_layout(n, n, q)),
+                    r: to_layout(k + l, k + l, r),
+                })
+            }
+        }
+    };
+} // impl_gsvd!
+
+impl_gsvd!(@real, f64, lapack_sys::dggsvd3_);
+impl_gsvd!(@real, f32, lapack_sys::sggsvd3_);
+impl_gsvd!(@complex, c64, lapack_sys::zggsvd3_);
+impl_gsvd!(@complex, c32, lapack_sys::cggsvd3_);
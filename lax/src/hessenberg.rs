@@ -0,0 +1,156 @@
+use super::*;
+use crate::{error::*, layout::*};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reduce general matrix into upper Hessenberg form
+///
+/// For a given square matrix $A$, this computes
+/// $$
+/// A = Q H Q^\dagger
+/// $$
+/// where $Q$ is unitary (orthogonal for real matrices) and
+/// $H$ is upper Hessenberg, i.e. $H_{ij} = 0$ for $i > j + 1$.
+///
+/// This is designed as two step computation according to LAPACK API
+///
+/// 1. Reduce $A$ into $H$ by Householder reflections
+/// 2. Reconstruct $Q$ from the Householder reflectors
+pub trait Hessenberg_: Sized {
+    /// Reduce $A$ into upper Hessenberg form $H$
+    ///
+    /// $H$ is stored in the upper Hessenberg part of `a`,
+    /// and the Householder reflectors are stored below the first subdiagonal
+    /// with the scalar factors returned as `tau`.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sgehrd | dgehrd | cgehrd | zgehrd |
+    ///
+    fn hessenberg(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+
+    /// Reconstruct $Q$ from the Householder reflectors computed by [Hessenberg_::hessenberg]
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sorghr | dorghr | cunghr | zunghr |
+    ///
+    fn hessenberg_q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
+}
+
+macro_rules! impl_hessenberg {
+    ($scalar:ty, $hrd:path, $ghr:path) => {
+        impl Hessenberg_ for $scalar {
+            fn hessenberg(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut tau = vec_uninit((n - 1).max(0) as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $hrd(
+                        &n,
+                        &1,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $hrd(
+                        &n,
+                        &1,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let tau = unsafe { tau.assume_init() };
+                Ok(tau)
+            }
+
+            fn hessenberg_q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()> {
+                let (n, _) = l.size();
+                assert_eq!(tau.len(), (n - 1).max(0) as usize);
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $ghr(
+                        &n,
+                        &1,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $ghr(
+                        &n,
+                        &1,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                Ok(())
+            }
+        }
+    };
+} // endmacro
+
+impl_hessenberg!(f64, lapack_sys::dgehrd_, lapack_sys::dorghr_);
+impl_hessenberg!(f32, lapack_sys::sgehrd_, lapack_sys::sorghr_);
+impl_hessenberg!(c64, lapack_sys::zgehrd_, lapack_sys::zunghr_);
+impl_hessenberg!(c32, lapack_sys::cgehrd_, lapack_sys::cunghr_);
@@ -173,3 +173,332 @@ impl_least_squares!(@real, f64, lapack_sys::dgelsd_);
 impl_least_squares!(@real, f32, lapack_sys::sgelsd_);
 impl_least_squares!(@complex, c64, lapack_sys::zgelsd_);
 impl_least_squares!(@complex, c32, lapack_sys::cgelsd_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Solve least square problem by complete orthogonal decomposition
+///
+/// This is faster than [LeastSquaresSvdDivideConquer_] in most cases,
+/// and also returns the minimum norm solution for rank-deficient $A$.
+pub trait LeastSquaresQrPivoted_: Scalar {
+    /// Compute a vector $x$ which minimizes Euclidian norm $\| Ax - b\|$
+    /// for a given matrix $A$ and a vector $b$.
+    ///
+    /// See [LeastSquaresQrPivoted_::least_squares_qr_pivoted_nrhs] for `rcond` and the returned value.
+    fn least_squares_qr_pivoted(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+        rcond: Self::Real,
+    ) -> Result<i32>;
+
+    /// Solve least square problems $\argmin_X \| AX - B\|$
+    ///
+    /// The effective rank of $A$ is determined as the order of the largest leading triangular block $R_{11}$
+    /// of the column-pivoted QR-decomposition of $A$ whose estimated condition number is less than `1 / rcond`,
+    /// and is returned.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sgelsy | dgelsy | cgelsy | zgelsy |
+    ///
+    fn least_squares_qr_pivoted_nrhs(
+        a_layout: MatrixLayout,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+        rcond: Self::Real,
+    ) -> Result<i32>;
+}
+
+macro_rules! impl_least_squares_qr_pivoted {
+    (@real, $scalar:ty, $gelsy:path) => {
+        impl_least_squares_qr_pivoted!(@body, $scalar, $gelsy, );
+    };
+    (@complex, $scalar:ty, $gelsy:path) => {
+        impl_least_squares_qr_pivoted!(@body, $scalar, $gelsy, rwork);
+    };
+
+    (@body, $scalar:ty, $gelsy:path, $($rwork:ident),*) => {
+        impl LeastSquaresQrPivoted_ for $scalar {
+            fn least_squares_qr_pivoted(
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+                rcond: Self::Real,
+            ) -> Result<i32> {
+                let b_layout = l.resized(b.len() as i32, 1);
+                Self::least_squares_qr_pivoted_nrhs(l, a, b_layout, b, rcond)
+            }
+
+            fn least_squares_qr_pivoted_nrhs(
+                a_layout: MatrixLayout,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+                rcond: Self::Real,
+            ) -> Result<i32> {
+                // Minimize |b - Ax|_2 as LeastSquaresSvdDivideConquer_::least_squares_nrhs
+                let (m, n) = a_layout.size();
+                let (m_, nrhs) = b_layout.size();
+                assert!(m_ >= m);
+
+                // Transpose if a is C-continuous
+                let mut a_t = None;
+                let a_layout = match a_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(a_layout, a);
+                        a_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => a_layout,
+                };
+
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(b_layout, b);
+                        b_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+
+                // All columns are free to be pivoted
+                let mut jpvt = vec![0; n as usize];
+                let mut rank: i32 = 0;
+                $(
+                let mut $rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(2 * n as usize);
+                )*
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gelsy(
+                        &m,
+                        &n,
+                        &nrhs,
+                        AsPtr::as_mut_ptr(a_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(a)),
+                        &a_layout.lda(),
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &b_layout.lda(),
+                        jpvt.as_mut_ptr(),
+                        &rcond,
+                        &mut rank,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        $(AsPtr::as_mut_ptr(&mut $rwork),)*
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gelsy(
+                        &m,
+                        &n,
+                        &nrhs,
+                        AsPtr::as_mut_ptr(a_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(a)),
+                        &a_layout.lda(),
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &b_layout.lda(),
+                        jpvt.as_mut_ptr(),
+                        &rcond,
+                        &mut rank,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        $(AsPtr::as_mut_ptr(&mut $rwork),)*
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // Skip a_t -> a transpose because A has been destroyed
+                // Re-transpose b
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+
+                Ok(rank)
+            }
+        }
+    };
+}
+
+impl_least_squares_qr_pivoted!(@real, f64, lapack_sys::dgelsy_);
+impl_least_squares_qr_pivoted!(@real, f32, lapack_sys::sgelsy_);
+impl_least_squares_qr_pivoted!(@complex, c64, lapack_sys::zgelsy_);
+impl_least_squares_qr_pivoted!(@complex, c32, lapack_sys::cgelsy_);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Solve least square problem by QR or LQ decomposition
+///
+/// $A$ is assumed to have full rank, but this is not checked.
+/// An error is returned only if a diagonal element of the triangular factor
+/// is exactly zero, and the result is not reliable for numerically rank-deficient $A$.
+pub trait LeastSquaresQr_: Scalar {
+    /// Compute a vector $x$ which minimizes Euclidian norm $\| \mathrm{op}(A)x - b\|$
+    /// for a given matrix $A$ and a vector $b$.
+    ///
+    /// See [LeastSquaresQr_::least_squares_qr_nrhs] for detail.
+    fn least_squares_qr(
+        a_layout: MatrixLayout,
+        t: Transpose,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Solve least square problems $\argmin_X \| \mathrm{op}(A)X - B\|$
+    /// where $\mathrm{op}(A)$ is $A$, $A^T$ or $A^H$ specified by `t`
+    ///
+    /// For $p \times q$ matrix $\mathrm{op}(A)$, `b` must have $\max(p, q)$ rows.
+    /// On exit, the leading $q$ rows of `b` are overwritten by the solution.
+    /// If $p \ge q$, this is the least squares solution,
+    /// and the remaining rows contain the components of the residual
+    /// whose sum of squares is the residual sum of squares.
+    /// Otherwise, this is the minimum norm solution of the underdetermined system.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32   | f64   | c32   | c64   |
+    /// |:------|:------|:------|:------|
+    /// | sgels | dgels | cgels | zgels |
+    ///
+    fn least_squares_qr_nrhs(
+        a_layout: MatrixLayout,
+        t: Transpose,
+        a: &mut [Self],
+        b_layout: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_least_squares_qr {
+    ($scalar:ty, $gels:path, $trans:expr $(, $strlen:expr)?) => {
+        impl LeastSquaresQr_ for $scalar {
+            fn least_squares_qr(
+                l: MatrixLayout,
+                t: Transpose,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<()> {
+                let b_layout = l.resized(b.len() as i32, 1);
+                Self::least_squares_qr_nrhs(l, t, a, b_layout, b)
+            }
+
+            fn least_squares_qr_nrhs(
+                a_layout: MatrixLayout,
+                t: Transpose,
+                a: &mut [Self],
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (m, n) = a_layout.size();
+                let (m_, nrhs) = b_layout.size();
+                assert!(m_ >= m.max(n));
+
+                // C-continuous A is not transposed, but seen as A^T by LAPACK.
+                // As in Solve_::solve, A^T is replaced by A^H with conjugating B
+                // for complex matrices, since A^T x = b <=> A^H conj(x) = conj(b).
+                // `$trans` is `T` for real and `C` for complex matrices.
+                let (trans, conj) = match a_layout {
+                    MatrixLayout::F { .. } => match t {
+                        Transpose::No => (Transpose::No, false),
+                        Transpose::Transpose => ($trans, true),
+                        Transpose::Hermite => ($trans, false),
+                    },
+                    MatrixLayout::C { .. } => match t {
+                        Transpose::No => ($trans, true),
+                        Transpose::Transpose => (Transpose::No, false),
+                        Transpose::Hermite => (Transpose::No, true),
+                    },
+                };
+
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(b_layout, b);
+                        b_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+                let b_ = b_t.as_deref_mut().unwrap_or(&mut *b);
+                if conj {
+                    for b_elem in &mut *b_ {
+                        *b_elem = b_elem.conj();
+                    }
+                }
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gels(
+                        trans.as_ptr(),
+                        &a_layout.lda(),
+                        &a_layout.len(),
+                        &nrhs,
+                        AsPtr::as_mut_ptr(a),
+                        &a_layout.lda(),
+                        AsPtr::as_mut_ptr(b_),
+                        &b_layout.lda(),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                        $($strlen)?
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gels(
+                        trans.as_ptr(),
+                        &a_layout.lda(),
+                        &a_layout.len(),
+                        &nrhs,
+                        AsPtr::as_mut_ptr(a),
+                        &a_layout.lda(),
+                        AsPtr::as_mut_ptr(b_),
+                        &b_layout.lda(),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                        $($strlen)?
+                    );
+                }
+                info.as_lapack_result()?;
+
+                if conj {
+                    for b_elem in &mut *b_ {
+                        *b_elem = b_elem.conj();
+                    }
+                }
+
+                // Re-transpose b
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+// lapack-sys exposes the hidden length argument of TRANS only for dgels
+impl_least_squares_qr!(f64, lapack_sys::dgels_, Transpose::Transpose, 1);
+impl_least_squares_qr!(f32, lapack_sys::sgels_, Transpose::Transpose);
+impl_least_squares_qr!(c64, lapack_sys::zgels_, Transpose::Hermite);
+impl_least_squares_qr!(c32, lapack_sys::cgels_, Transpose::Hermite);
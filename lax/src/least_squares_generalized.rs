@@ -0,0 +1,233 @@
+//! Generalized linear least squares problems
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Linear equality-constrained least squares problem
+pub trait LeastSquaresEqualityConstrained_: Scalar {
+    /// Compute a vector $x$ which minimizes Euclidian norm $\| Ax - c\|$
+    /// subject to the equality constraint $Bx = d$
+    /// for $m \times n$ matrix $A$ and $p \times n$ matrix $B$
+    ///
+    /// It is assumed that $p \le n \le m + p$, $B$ has full row rank $p$,
+    /// and $(A^T, B^T)^T$ has full column rank $n$.
+    /// Then the solution is unique.
+    ///
+    /// On exit, the residual sum of squares is the sum of squares of
+    /// `c[n-p..m]` if $m + p > n$. $A$, $B$ and $d$ are destroyed.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sgglse | dgglse | cgglse | zgglse |
+    ///
+    fn least_squares_equality_constrained(
+        la: MatrixLayout,
+        a: &mut [Self],
+        lb: MatrixLayout,
+        b: &mut [Self],
+        c: &mut [Self],
+        d: &mut [Self],
+    ) -> Result<Vec<Self>>;
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// General Gauss-Markov linear model problem
+pub trait GeneralLinearModel_: Scalar {
+    /// Compute vectors $x$ and $y$ which minimize Euclidian norm $\| y \|$
+    /// subject to $d = Ax + By$
+    /// for $n \times m$ matrix $A$ and $n \times p$ matrix $B$
+    ///
+    /// It is assumed that $m \le n \le m + p$, $A$ has full column rank $m$,
+    /// and $(A, B)$ has full row rank $n$.
+    /// Then the solution is unique.
+    /// Returns $(x, y)$, and $A$, $B$ and $d$ are destroyed.
+    ///
+    /// This is equivalent to the weighted linear regression $\min_x \| B^{-1} (d - Ax) \|$
+    /// if $B$ is square and non-singular.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sggglm | dggglm | cggglm | zggglm |
+    ///
+    fn general_linear_model(
+        la: MatrixLayout,
+        a: &mut [Self],
+        lb: MatrixLayout,
+        b: &mut [Self],
+        d: &mut [Self],
+    ) -> Result<(Vec<Self>, Vec<Self>)>;
+}
+
+macro_rules! impl_least_squares_generalized {
+    ($scalar:ty, $gglse:path, $ggglm:path) => {
+        impl LeastSquaresEqualityConstrained_ for $scalar {
+            fn least_squares_equality_constrained(
+                la: MatrixLayout,
+                a: &mut [Self],
+                lb: MatrixLayout,
+                b: &mut [Self],
+                c: &mut [Self],
+                d: &mut [Self],
+            ) -> Result<Vec<Self>> {
+                let (m, n) = la.size();
+                let (p, nb) = lb.size();
+                assert_eq!(n, nb, "The numbers of columns of A and B must be identical");
+                assert_eq!(c.len(), m as usize);
+                assert_eq!(d.len(), p as usize);
+
+                // Row-major inputs are transposed into column-major order
+                let mut a_t = None;
+                if let MatrixLayout::C { .. } = la {
+                    let (_, t) = transpose(la, a);
+                    a_t = Some(t);
+                }
+                let a = a_t.as_deref_mut().unwrap_or(a);
+                let mut b_t = None;
+                if let MatrixLayout::C { .. } = lb {
+                    let (_, t) = transpose(lb, b);
+                    b_t = Some(t);
+                }
+                let b = b_t.as_deref_mut().unwrap_or(b);
+
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(n as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gglse(
+                        &m,
+                        &n,
+                        &p,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(c),
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(&mut x),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gglse(
+                        &m,
+                        &n,
+                        &p,
+                        AsPtr::as_mut_ptr(a),
+                        &m.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &p.max(1),
+                        AsPtr::as_mut_ptr(c),
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(&mut x),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                Ok(unsafe { x.assume_init() })
+            }
+        }
+
+        impl GeneralLinearModel_ for $scalar {
+            fn general_linear_model(
+                la: MatrixLayout,
+                a: &mut [Self],
+                lb: MatrixLayout,
+                b: &mut [Self],
+                d: &mut [Self],
+            ) -> Result<(Vec<Self>, Vec<Self>)> {
+                let (n, m) = la.size();
+                let (nb, p) = lb.size();
+                assert_eq!(n, nb, "The numbers of rows of A and B must be identical");
+                assert_eq!(d.len(), n as usize);
+
+                // Row-major inputs are transposed into column-major order
+                let mut a_t = None;
+                if let MatrixLayout::C { .. } = la {
+                    let (_, t) = transpose(la, a);
+                    a_t = Some(t);
+                }
+                let a = a_t.as_deref_mut().unwrap_or(a);
+                let mut b_t = None;
+                if let MatrixLayout::C { .. } = lb {
+                    let (_, t) = transpose(lb, b);
+                    b_t = Some(t);
+                }
+                let b = b_t.as_deref_mut().unwrap_or(b);
+
+                let mut x: Vec<MaybeUninit<Self>> = vec_uninit(m as usize);
+                let mut y: Vec<MaybeUninit<Self>> = vec_uninit(p as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $ggglm(
+                        &n,
+                        &m,
+                        &p,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(&mut x),
+                        AsPtr::as_mut_ptr(&mut y),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap().max(1);
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $ggglm(
+                        &n,
+                        &m,
+                        &p,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(d),
+                        AsPtr::as_mut_ptr(&mut x),
+                        AsPtr::as_mut_ptr(&mut y),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                Ok(unsafe { (x.assume_init(), y.assume_init()) })
+            }
+        }
+    };
+} // impl_least_squares_generalized!
+
+impl_least_squares_generalized!(f64, lapack_sys::dgglse_, lapack_sys::dggglm_);
+impl_least_squares_generalized!(f32, lapack_sys::sgglse_, lapack_sys::sggglm_);
+impl_least_squares_generalized!(c64, lapack_sys::zgglse_, lapack_sys::zggglm_);
+impl_least_squares_generalized!(c32, lapack_sys::cgglse_, lapack_sys::cggglm_);
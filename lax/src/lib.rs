@@ -51,6 +51,12 @@
 //! - [Solve_] trait provides methods for LU-decomposition for general matrix.
 //! - [Solveh_] triat provides methods for Bunch-Kaufman diagonal pivoting method for symmetric/hermite indefinite matrix.
 //! - [Cholesky_] triat provides methods for Cholesky decomposition for symmetric/hermite positive dinite matrix.
+//! - [CholeskyPivoted_] trait provides methods for pivoted Cholesky decomposition for symmetric/hermite positive semi-definite matrix.
+//! - [BandedHermite_] trait provides methods for Cholesky decomposition and eigenvalue problem
+//!   for symmetric/hermite banded matrix.
+//! - [Packed_] trait provides methods for symmetric/hermite matrix in packed or RFP storage.
+//! - [Tridiagonal_] and [Banded_] traits provide methods for LU-decomposition for tridiagonal and banded matrix.
+//! - [LDLTridiagonal_] trait provides methods for LDL decomposition for symmetric/hermite positive definite tridiagonal matrix.
 //!
 //! Eigenvalue Problem
 //! -------------------
@@ -59,8 +65,17 @@
 //! there are several types of eigenvalue problem API
 //!
 //! - [eig] module for eigenvalue problem for general matrix.
+//! - [eig_generalized] module for generalized eigenvalue problem for general matrix.
 //! - [eigh] module for eigenvalue problem for symmetric/hermite matrix.
+//! - [eigh_range] module for eigenvalue problem for symmetric/hermite matrix
+//!   with eigenvalues selected by index or value range.
 //! - [eigh_generalized] module for generalized eigenvalue problem for symmetric/hermite matrix.
+//! - [schur] module for Schur decomposition for general matrix.
+//! - [qz] module for generalized Schur (QZ) decomposition for general matrix pair.
+//! - [Hessenberg_] trait provides methods for reducing general matrix into upper Hessenberg form.
+//! - [Tridiagonalize_] trait provides methods for reducing symmetric/hermite matrix
+//!   into real symmetric tridiagonal form.
+//! - [EighTridiagonal_] trait provides methods for eigenvalue problem for real symmetric tridiagonal matrix.
 //!
 //! Singular Value Decomposition
 //! -----------------------------
@@ -68,8 +83,22 @@
 //! - [SVD_] trait provides methods for singular value decomposition for general matrix
 //! - [SVDDC_] trait provides methods for singular value decomposition for general matrix
 //!   with divided-and-conquer algorithm
+//! - [SVDJacobi_] trait provides methods for singular value decomposition for general matrix
+//!   with one-sided Jacobi method, which computes small singular values to high relative accuracy
+//! - [SVDRange_] trait provides methods for singular value decomposition for general matrix
+//!   with singular values selected by index or value range
+//! - [GSVD_] trait provides methods for generalized singular value decomposition for a pair of general matrices
+//! - [Bidiagonal_] trait provides methods for reducing general matrix into bidiagonal form,
+//!   which is the first step of singular value decomposition
 //! - [LeastSquaresSvdDivideConquer_] trait provides methods
 //!   for solving least square problem by SVD
+//! - [LeastSquaresQrPivoted_] trait provides methods
+//!   for solving least square problem by complete orthogonal decomposition,
+//!   see also [CompleteOrthogonal_]
+//! - [LeastSquaresQr_] trait provides methods
+//!   for solving least square problem of full rank matrix by QR or LQ decomposition
+//! - [LeastSquaresEqualityConstrained_] and [GeneralLinearModel_] traits provide methods
+//!   for solving generalized linear least square problems
 //!
 
 #![deny(rustdoc::broken_intra_doc_links, rustdoc::private_intra_doc_links)]
@@ -88,34 +117,60 @@ pub mod flags;
 pub mod layout;
 
 pub mod eig;
+pub mod eig_generalized;
 pub mod eigh;
 pub mod eigh_generalized;
+pub mod eigh_range;
+pub mod qz;
+pub mod schur;
 
 mod alloc;
+mod banded;
+mod bidiagonal;
 mod cholesky;
+mod complete_orthogonal;
+mod eigh_tridiagonal;
+mod gsvd;
+mod hessenberg;
 mod least_squares;
+mod least_squares_generalized;
 mod opnorm;
+mod packed;
 mod qr;
 mod rcond;
 mod solve;
 mod solveh;
 mod svd;
+mod svd_jacobi;
+mod svd_range;
 mod svddc;
 mod triangular;
 mod tridiagonal;
+mod tridiagonalize;
 
+pub use self::banded::*;
+pub use self::bidiagonal::*;
 pub use self::cholesky::*;
+pub use self::complete_orthogonal::*;
+pub use self::eigh_tridiagonal::*;
 pub use self::flags::*;
+pub use self::gsvd::*;
+pub use self::hessenberg::*;
 pub use self::least_squares::*;
+pub use self::least_squares_generalized::*;
 pub use self::opnorm::*;
+pub use self::packed::*;
 pub use self::qr::*;
 pub use self::rcond::*;
 pub use self::solve::*;
 pub use self::solveh::*;
 pub use self::svd::*;
+pub use self::svd_jacobi::*;
+pub use self::svd_range::*;
 pub use self::svddc::*;
 pub use self::triangular::*;
 pub use self::tridiagonal::*;
+pub use self::tridiagonalize::*;
 
 use self::{alloc::*, error::*, layout::*};
 use cauchy::*;
@@ -127,15 +182,37 @@ pub type Pivot = Vec<i32>;
 pub trait Lapack:
     OperatorNorm_
     + QR_
+    + QRPivoted_
+    + ApplyQ_
+    + CompleteOrthogonal_
+    + LQ_
+    + QL_
+    + RQ_
     + SVD_
     + SVDDC_
+    + SVDJacobi_
+    + SVDRange_
+    + GSVD_
+    + Bidiagonal_
     + Solve_
     + Solveh_
     + Cholesky_
+    + CholeskyPivoted_
+    + Hessenberg_
     + Triangular_
     + Tridiagonal_
+    + LDLTridiagonal_
+    + Banded_
+    + BandedHermite_
+    + Packed_
+    + Tridiagonalize_
+    + EighTridiagonal_
     + Rcond_
     + LeastSquaresSvdDivideConquer_
+    + LeastSquaresQrPivoted_
+    + LeastSquaresQr_
+    + LeastSquaresEqualityConstrained_
+    + GeneralLinearModel_
 {
     /// Compute right eigenvalue and eigenvectors for a general matrix
     fn eig(
@@ -144,6 +221,15 @@ pub trait Lapack:
         a: &mut [Self],
     ) -> Result<(Vec<Self::Complex>, Vec<Self::Complex>)>;
 
+    /// Compute generalized eigenvalues as `(alpha, beta)` pairs and eigenvectors
+    /// for a pair of general matrices
+    fn eig_generalized(
+        calc_v: bool,
+        l: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+    ) -> Result<eig_generalized::GeneralizedEigOwned<Self>>;
+
     /// Compute right eigenvalue and eigenvectors for a symmetric or hermite matrix
     fn eigh(
         calc_eigenvec: bool,
@@ -152,6 +238,25 @@ pub trait Lapack:
         a: &mut [Self],
     ) -> Result<Vec<Self::Real>>;
 
+    /// Compute right eigenvalue and eigenvectors for a symmetric or hermite matrix
+    /// using the specified driver
+    fn eigh_with_driver(
+        calc_eigenvec: bool,
+        layout: MatrixLayout,
+        uplo: UPLO,
+        driver: EighDriver,
+        a: &mut [Self],
+    ) -> Result<Vec<Self::Real>>;
+
+    /// Compute selected eigenvalues and eigenvectors for a symmetric or hermite matrix
+    fn eigh_range(
+        calc_eigenvec: bool,
+        layout: MatrixLayout,
+        uplo: UPLO,
+        range: EigRange<Self::Real>,
+        a: &mut [Self],
+    ) -> Result<eigh_range::EighRangeOwned<Self>>;
+
     /// Compute right eigenvalue and eigenvectors for a symmetric or hermite matrix
     fn eigh_generalized(
         calc_eigenvec: bool,
@@ -160,6 +265,29 @@ pub trait Lapack:
         a: &mut [Self],
         b: &mut [Self],
     ) -> Result<Vec<Self::Real>>;
+
+    /// Compute Schur decomposition for a general matrix
+    ///
+    /// If `select` is given, the eigenvalues for which it returns `true`
+    /// are moved to the top-left block of the Schur form.
+    fn schur(
+        calc_v: bool,
+        l: MatrixLayout,
+        a: &mut [Self],
+        select: Option<&mut dyn FnMut(Self::Complex) -> bool>,
+    ) -> Result<schur::SchurOwned<Self>>;
+
+    /// Compute generalized Schur (QZ) decomposition for a pair of general matrices
+    ///
+    /// If `select` is given, the eigenvalues `(alpha, beta)` for which it returns `true`
+    /// are moved to the top-left blocks of the generalized Schur form.
+    fn qz(
+        calc_v: bool,
+        l: MatrixLayout,
+        a: &mut [Self],
+        b: &mut [Self],
+        select: Option<&mut dyn FnMut(Self::Complex, Self) -> bool>,
+    ) -> Result<qz::QZOwned<Self>>;
 }
 
 macro_rules! impl_lapack {
@@ -176,6 +304,17 @@ macro_rules! impl_lapack {
                 Ok((eigs, vr.or(vl).unwrap_or_default()))
             }
 
+            fn eig_generalized(
+                calc_v: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+            ) -> Result<eig_generalized::GeneralizedEigOwned<Self>> {
+                use eig_generalized::*;
+                let work = GeneralizedEigWork::<$s>::new(calc_v, l)?;
+                work.eval(a, b)
+            }
+
             fn eigh(
                 calc_eigenvec: bool,
                 layout: MatrixLayout,
@@ -187,6 +326,30 @@ macro_rules! impl_lapack {
                 work.eval(uplo, a)
             }
 
+            fn eigh_with_driver(
+                calc_eigenvec: bool,
+                layout: MatrixLayout,
+                uplo: UPLO,
+                driver: EighDriver,
+                a: &mut [Self],
+            ) -> Result<Vec<Self::Real>> {
+                use eigh::*;
+                let work = EighDriverWork::<$s>::new(calc_eigenvec, layout, driver)?;
+                work.eval(uplo, a)
+            }
+
+            fn eigh_range(
+                calc_eigenvec: bool,
+                layout: MatrixLayout,
+                uplo: UPLO,
+                range: EigRange<Self::Real>,
+                a: &mut [Self],
+            ) -> Result<eigh_range::EighRangeOwned<Self>> {
+                use eigh_range::*;
+                let work = EighRangeWork::<$s>::new(calc_eigenvec, layout)?;
+                work.eval(uplo, range, a)
+            }
+
             fn eigh_generalized(
                 calc_eigenvec: bool,
                 layout: MatrixLayout,
@@ -198,6 +361,29 @@ macro_rules! impl_lapack {
                 let work = EighGeneralizedWork::<$s>::new(calc_eigenvec, layout)?;
                 work.eval(uplo, a, b)
             }
+
+            fn schur(
+                calc_v: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+                select: Option<&mut dyn FnMut(Self::Complex) -> bool>,
+            ) -> Result<schur::SchurOwned<Self>> {
+                use schur::*;
+                let work = SchurWork::<$s>::new(calc_v, l)?;
+                work.eval(a, select)
+            }
+
+            fn qz(
+                calc_v: bool,
+                l: MatrixLayout,
+                a: &mut [Self],
+                b: &mut [Self],
+                select: Option<&mut dyn FnMut(Self::Complex, Self) -> bool>,
+            ) -> Result<qz::QZOwned<Self>> {
+                use qz::*;
+                let work = QZWork::<$s>::new(calc_v, l)?;
+                work.eval(a, b, select)
+            }
         }
     };
 }
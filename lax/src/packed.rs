@@ -0,0 +1,598 @@
+//! Packed and rectangular full packed (RFP) storage for symmetric/hermite matrix
+
+use crate::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::Zero;
+use std::ops::{Index, IndexMut};
+
+/// Represents a hermite (or real symmetric) matrix by its triangle packed column-wise
+///
+/// Only the triangle specified by `uplo` is stored in `ap` of length `n * (n + 1) / 2`:
+///
+/// - [UPLO::Upper]: the element `(i, j)` is `ap[i + j * (j + 1) / 2]` for `i <= j`
+/// - [UPLO::Lower]: the element `(i, j)` is `ap[i + j * (2 * n - j - 1) / 2]` for `j <= i`
+///
+/// ```text
+/// // n = 3, UPLO::Upper
+/// [a00, a01, a11, a02, a12, a22]
+///
+/// // n = 3, UPLO::Lower
+/// [a00, a10, a20, a11, a21, a22]
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Packed<A: Scalar> {
+    /// layout of raw matrix
+    pub l: MatrixLayout,
+    /// Which triangle is stored
+    pub uplo: UPLO,
+    /// `n * (n + 1) / 2` elements of the packed triangle
+    pub ap: Vec<A>,
+}
+
+impl<A: Scalar> Packed<A> {
+    /// Zero matrix
+    pub fn zeros(l: MatrixLayout, uplo: UPLO) -> Self {
+        let (n, _) = l.size();
+        Packed {
+            l,
+            uplo,
+            ap: vec![A::zero(); packed_len(n)],
+        }
+    }
+
+    fn position(&self, row: i32, col: i32) -> Option<usize> {
+        let (n, _) = self.l.size();
+        assert!(
+            std::cmp::max(row, col) < n,
+            "ndarray: index {:?} is out of bounds for array of shape {}",
+            [row, col],
+            n
+        );
+        let (row, col, n) = (row as usize, col as usize, n as usize);
+        match self.uplo {
+            UPLO::Upper if row <= col => Some(row + col * (col + 1) / 2),
+            UPLO::Lower if row >= col => Some(row + col * (2 * n - col - 1) / 2),
+            _ => None,
+        }
+    }
+}
+
+impl<A: Scalar> Index<(i32, i32)> for Packed<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, (row, col): (i32, i32)) -> &A {
+        match self.position(row, col) {
+            Some(pos) => &self.ap[pos],
+            None => panic!(
+                "ndarray-linalg::packed: index {:?} is not stored element",
+                [row, col]
+            ),
+        }
+    }
+}
+
+impl<A: Scalar> Index<[i32; 2]> for Packed<A> {
+    type Output = A;
+    #[inline]
+    fn index(&self, [row, col]: [i32; 2]) -> &A {
+        &self[(row, col)]
+    }
+}
+
+impl<A: Scalar> IndexMut<(i32, i32)> for Packed<A> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (i32, i32)) -> &mut A {
+        match self.position(row, col) {
+            Some(pos) => &mut self.ap[pos],
+            None => panic!(
+                "ndarray-linalg::packed: index {:?} is not stored element",
+                [row, col]
+            ),
+        }
+    }
+}
+
+impl<A: Scalar> IndexMut<[i32; 2]> for Packed<A> {
+    #[inline]
+    fn index_mut(&mut self, [row, col]: [i32; 2]) -> &mut A {
+        &mut self[(row, col)]
+    }
+}
+
+/// Represents a hermite (or real symmetric) matrix in the rectangular full packed (RFP) format
+///
+/// The triangle specified by `uplo` is rearranged into `n * (n + 1) / 2` elements `arf`,
+/// which is a column-major rectangular matrix in the normal (`TRANSR = 'N'`) RFP format of LAPACK.
+/// Unlike [Packed], the Level 3 BLAS can be used for this format.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RectangularFullPacked<A: Scalar> {
+    /// layout of raw matrix
+    pub l: MatrixLayout,
+    /// Which triangle is stored
+    pub uplo: UPLO,
+    /// `n * (n + 1) / 2` elements in the RFP format
+    pub arf: Vec<A>,
+}
+
+/// Eigenvalues and eigenvectors of hermite matrix in packed storage
+pub struct EighPackedOutput<A: Scalar> {
+    /// Eigenvalues in ascending order
+    pub eigs: Vec<A::Real>,
+    /// Eigenvectors as the columns of column-major matrix
+    pub z: Option<Vec<A>>,
+}
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Linear solvers and eigenvalue problem for hermite (or real symmetric) matrix
+/// in [Packed] or [RectangularFullPacked] storage
+pub trait Packed_: Scalar + Sized {
+    /// Pack the triangle specified by `uplo` of the $n \times n$ matrix `a`
+    ///
+    /// Row-major `a` is not transposed. Since its column-major view is the conjugate of `a`,
+    /// the opposite triangle of the view is packed with the values conjugated,
+    /// i.e. `uplo` of the result is swapped.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | strttp | dtrttp | ctrttp | ztrttp |
+    ///
+    fn to_packed(l: MatrixLayout, uplo: UPLO, a: &[Self]) -> Result<Packed<Self>>;
+
+    /// Unpack into $n \times n$ column-major matrix whose elements
+    /// in the other triangle are zero
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | stpttr | dtpttr | ctpttr | ztpttr |
+    ///
+    fn from_packed(a: &Packed<Self>) -> Result<Vec<Self>>;
+
+    /// Rearrange the triangle specified by `uplo` of the $n \times n$ matrix `a`
+    /// into the RFP format
+    ///
+    /// `uplo` of the result is swapped for row-major `a` as [Packed_::to_packed].
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | strttf | dtrttf | ctrttf | ztrttf |
+    ///
+    fn to_rfp(l: MatrixLayout, uplo: UPLO, a: &[Self]) -> Result<RectangularFullPacked<Self>>;
+
+    /// Unpack into $n \times n$ column-major matrix whose elements
+    /// in the other triangle are zero
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | stfttr | dtfttr | ctfttr | ztfttr |
+    ///
+    fn from_rfp(a: &RectangularFullPacked<Self>) -> Result<Vec<Self>>;
+
+    /// Cholesky decomposition $A = U^\dagger U$ or $A = L L^\dagger$ according to `uplo`,
+    /// where `a` is overwritten by the factor
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | spptrf | dpptrf | cpptrf | zpptrf |
+    ///
+    fn cholesky_packed(a: &mut Packed<Self>) -> Result<()>;
+
+    /// Solve $Ax = b$ using the factor computed by [Packed_::cholesky_packed]
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | spptrs | dpptrs | cpptrs | zpptrs |
+    ///
+    fn solve_cholesky_packed(a: &Packed<Self>, bl: MatrixLayout, b: &mut [Self]) -> Result<()>;
+
+    /// Cholesky decomposition $A = U^\dagger U$ or $A = L L^\dagger$ according to `uplo`,
+    /// where `a` is overwritten by the factor
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | spftrf | dpftrf | cpftrf | zpftrf |
+    ///
+    fn cholesky_rfp(a: &mut RectangularFullPacked<Self>) -> Result<()>;
+
+    /// Solve $Ax = b$ using the factor computed by [Packed_::cholesky_rfp]
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | spftrs | dpftrs | cpftrs | zpftrs |
+    ///
+    fn solve_cholesky_rfp(
+        a: &RectangularFullPacked<Self>,
+        bl: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Bunch-Kaufman factorization $A = P U D U^\dagger P^T$ or $A = P L D L^\dagger P^T$
+    /// according to `uplo`, where `a` is overwritten by $D$ and the multipliers
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | ssptrf | dsptrf | chptrf | zhptrf |
+    ///
+    fn bk_packed(a: &mut Packed<Self>) -> Result<Pivot>;
+
+    /// Solve $Ax = b$ using the factorization computed by [Packed_::bk_packed]
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | ssptrs | dsptrs | chptrs | zhptrs |
+    ///
+    fn solve_bk_packed(
+        a: &Packed<Self>,
+        ipiv: &Pivot,
+        bl: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+
+    /// Compute all eigenvalues, and optionally eigenvectors
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sspev  | dspev  | chpev  | zhpev  |
+    ///
+    fn eigh_packed(calc_v: bool, a: &Packed<Self>) -> Result<EighPackedOutput<Self>>;
+}
+
+/// Number of elements in a triangle of $n \times n$ matrix
+///
+/// This is computed in `usize` since `n * (n + 1)` overflows `i32` for `n >= 46341`.
+fn packed_len(n: i32) -> usize {
+    let n = n as usize;
+    n * (n + 1) / 2
+}
+
+/// Layout and triangle of hermite matrix to be read by LAPACK without transposing it
+///
+/// Row-major matrix is regarded as its column-major transpose, which is its conjugate,
+/// thus the opposite triangle is read and the values must be conjugated afterward.
+fn col_major_triangle(l: MatrixLayout, uplo: UPLO) -> (UPLO, bool) {
+    match l {
+        MatrixLayout::C { .. } => (uplo.t(), true),
+        MatrixLayout::F { .. } => (uplo, false),
+    }
+}
+
+/// Row-major matrix is transposed into column-major order
+fn to_col_major<T: Scalar>(l: MatrixLayout, a: &[T]) -> (MatrixLayout, Option<Vec<T>>) {
+    match l {
+        MatrixLayout::C { .. } => {
+            let (l, t) = transpose(l, a);
+            (l, Some(t))
+        }
+        MatrixLayout::F { .. } => (l, None),
+    }
+}
+
+/// Call `solve` with column-major right hand side `b` and its leading dimension and columns
+fn solve_col_major<T: Scalar>(
+    bl: MatrixLayout,
+    b: &mut [T],
+    solve: impl FnOnce(i32, i32, &mut [T]) -> Result<()>,
+) -> Result<()> {
+    let (bl_f, b_t) = to_col_major(bl, b);
+    let (ldb, nrhs) = bl_f.size();
+    match b_t {
+        Some(mut b_t) => {
+            solve(ldb, nrhs, &mut b_t)?;
+            transpose_over(bl_f, &b_t, b);
+        }
+        None => solve(ldb, nrhs, b)?,
+    }
+    Ok(())
+}
+
+macro_rules! impl_packed {
+    (@real, $scalar:ty, $trttp:path, $tpttr:path, $trttf:path, $tfttr:path,
+     $pptrf:path, $pptrs:path, $pftrf:path, $pftrs:path, $sptrf:path, $sptrs:path, $spev:path) => {
+        impl_packed!(@body, $scalar, $trttp, $tpttr, $trttf, $tfttr,
+                     $pptrf, $pptrs, $pftrf, $pftrs, $sptrf, $sptrs, $spev, 3, 0, );
+    };
+    (@complex, $scalar:ty, $trttp:path, $tpttr:path, $trttf:path, $tfttr:path,
+     $pptrf:path, $pptrs:path, $pftrf:path, $pftrs:path, $hptrf:path, $hptrs:path, $hpev:path) => {
+        impl_packed!(@body, $scalar, $trttp, $tpttr, $trttf, $tfttr,
+                     $pptrf, $pptrs, $pftrf, $pftrs, $hptrf, $hptrs, $hpev, 2, 1, rwork);
+    };
+    (@body, $scalar:ty, $trttp:path, $tpttr:path, $trttf:path, $tfttr:path,
+     $pptrf:path, $pptrs:path, $pftrf:path, $pftrs:path, $sptrf:path, $sptrs:path, $spev:path,
+     $lwork_n:expr, $lwork_c:expr, $($rwork:ident),*) => {
+        impl Packed_ for $scalar {
+            fn to_packed(l: MatrixLayout, uplo: UPLO, a: &[Self]) -> Result<Packed<Self>> {
+                let n = l.len();
+                let (uplo, conj) = col_major_triangle(l, uplo);
+                let mut ap: Vec<MaybeUninit<Self>> = vec_uninit(packed_len(n));
+                let mut info = 0;
+                unsafe {
+                    $trttp(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut ap),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let mut ap = unsafe { ap.assume_init() };
+                if conj {
+                    ap.iter_mut().for_each(|x| *x = x.conj());
+                }
+                let l = MatrixLayout::F { col: n, lda: n };
+                Ok(Packed { l, uplo, ap })
+            }
+
+            fn from_packed(a: &Packed<Self>) -> Result<Vec<Self>> {
+                let (n, _) = a.l.size();
+                let mut out = vec![Self::zero(); n as usize * n as usize];
+                let mut info = 0;
+                unsafe {
+                    $tpttr(
+                        a.uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(&a.ap),
+                        AsPtr::as_mut_ptr(&mut out),
+                        &n.max(1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(out)
+            }
+
+            fn to_rfp(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+            ) -> Result<RectangularFullPacked<Self>> {
+                let n = l.len();
+                let (uplo, conj) = col_major_triangle(l, uplo);
+                let mut arf: Vec<MaybeUninit<Self>> = vec_uninit(packed_len(n));
+                let mut info = 0;
+                unsafe {
+                    $trttf(
+                        Transpose::No.as_ptr(),
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut arf),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                let mut arf = unsafe { arf.assume_init() };
+                if conj {
+                    arf.iter_mut().for_each(|x| *x = x.conj());
+                }
+                let l = MatrixLayout::F { col: n, lda: n };
+                Ok(RectangularFullPacked { l, uplo, arf })
+            }
+
+            fn from_rfp(a: &RectangularFullPacked<Self>) -> Result<Vec<Self>> {
+                let (n, _) = a.l.size();
+                let mut out = vec![Self::zero(); n as usize * n as usize];
+                let mut info = 0;
+                unsafe {
+                    $tfttr(
+                        Transpose::No.as_ptr(),
+                        a.uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_ptr(&a.arf),
+                        AsPtr::as_mut_ptr(&mut out),
+                        &n.max(1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(out)
+            }
+
+            fn cholesky_packed(a: &mut Packed<Self>) -> Result<()> {
+                let (n, _) = a.l.size();
+                let mut info = 0;
+                unsafe {
+                    $pptrf(a.uplo.as_ptr(), &n, AsPtr::as_mut_ptr(&mut a.ap), &mut info);
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+
+            fn solve_cholesky_packed(
+                a: &Packed<Self>,
+                bl: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = a.l.size();
+                solve_col_major(bl, b, |ldb, nrhs, b| {
+                    let mut info = 0;
+                    unsafe {
+                        $pptrs(
+                            a.uplo.as_ptr(),
+                            &n,
+                            &nrhs,
+                            AsPtr::as_ptr(&a.ap),
+                            AsPtr::as_mut_ptr(b),
+                            &ldb,
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()
+                })
+            }
+
+            fn cholesky_rfp(a: &mut RectangularFullPacked<Self>) -> Result<()> {
+                let (n, _) = a.l.size();
+                let mut info = 0;
+                unsafe {
+                    $pftrf(
+                        Transpose::No.as_ptr(),
+                        a.uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut a.arf),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+
+            fn solve_cholesky_rfp(
+                a: &RectangularFullPacked<Self>,
+                bl: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = a.l.size();
+                solve_col_major(bl, b, |ldb, nrhs, b| {
+                    let mut info = 0;
+                    unsafe {
+                        $pftrs(
+                            Transpose::No.as_ptr(),
+                            a.uplo.as_ptr(),
+                            &n,
+                            &nrhs,
+                            AsPtr::as_ptr(&a.arf),
+                            AsPtr::as_mut_ptr(b),
+                            &ldb,
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()
+                })
+            }
+
+            fn bk_packed(a: &mut Packed<Self>) -> Result<Pivot> {
+                let (n, _) = a.l.size();
+                let mut ipiv = vec_uninit(n as usize);
+                let mut info = 0;
+                unsafe {
+                    $sptrf(
+                        a.uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut a.ap),
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(unsafe { ipiv.assume_init() })
+            }
+
+            fn solve_bk_packed(
+                a: &Packed<Self>,
+                ipiv: &Pivot,
+                bl: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = a.l.size();
+                solve_col_major(bl, b, |ldb, nrhs, b| {
+                    let mut info = 0;
+                    unsafe {
+                        $sptrs(
+                            a.uplo.as_ptr(),
+                            &n,
+                            &nrhs,
+                            AsPtr::as_ptr(&a.ap),
+                            ipiv.as_ptr(),
+                            AsPtr::as_mut_ptr(b),
+                            &ldb,
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()
+                })
+            }
+
+            fn eigh_packed(calc_v: bool, a: &Packed<Self>) -> Result<EighPackedOutput<Self>> {
+                let (n, _) = a.l.size();
+                let jobz = if calc_v { JobEv::All } else { JobEv::None };
+                // *spev and *hpev destroy the packed matrix
+                let mut ap = a.ap.clone();
+                let mut eigs: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut z: Vec<MaybeUninit<Self>> =
+                    vec_uninit(if calc_v { n as usize * n as usize } else { 0 });
+                let mut work: Vec<MaybeUninit<Self>> =
+                    vec_uninit(($lwork_n * n - $lwork_c).max(1) as usize);
+                $(
+                let mut $rwork: Vec<MaybeUninit<Self::Real>> =
+                    vec_uninit((3 * n - 2).max(1) as usize);
+                )*
+                let mut info = 0;
+                unsafe {
+                    $spev(
+                        jobz.as_ptr(),
+                        a.uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut ap),
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(&mut z),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut work),
+                        $(AsPtr::as_mut_ptr(&mut $rwork),)*
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(EighPackedOutput {
+                    eigs: unsafe { eigs.assume_init() },
+                    z: jobz.then(|| unsafe { z.assume_init() }),
+                })
+            }
+        }
+    };
+} // impl_packed!
+
+impl_packed!(
+    @real, f64, lapack_sys::dtrttp_, lapack_sys::dtpttr_, lapack_sys::dtrttf_, lapack_sys::dtfttr_,
+    lapack_sys::dpptrf_, lapack_sys::dpptrs_, lapack_sys::dpftrf_, lapack_sys::dpftrs_,
+    lapack_sys::dsptrf_, lapack_sys::dsptrs_, lapack_sys::dspev_
+);
+impl_packed!(
+    @real, f32, lapack_sys::strttp_, lapack_sys::stpttr_, lapack_sys::strttf_, lapack_sys::stfttr_,
+    lapack_sys::spptrf_, lapack_sys::spptrs_, lapack_sys::spftrf_, lapack_sys::spftrs_,
+    lapack_sys::ssptrf_, lapack_sys::ssptrs_, lapack_sys::sspev_
+);
+impl_packed!(
+    @complex, c64, lapack_sys::ztrttp_, lapack_sys::ztpttr_, lapack_sys::ztrttf_, lapack_sys::ztfttr_,
+    lapack_sys::zpptrf_, lapack_sys::zpptrs_, lapack_sys::zpftrf_, lapack_sys::zpftrs_,
+    lapack_sys::zhptrf_, lapack_sys::zhptrs_, lapack_sys::zhpev_
+);
+impl_packed!(
+    @complex, c32, lapack_sys::ctrttp_, lapack_sys::ctpttr_, lapack_sys::ctrttf_, lapack_sys::ctfttr_,
+    lapack_sys::cpptrf_, lapack_sys::cpptrs_, lapack_sys::cpftrf_, lapack_sys::cpftrs_,
+    lapack_sys::chptrf_, lapack_sys::chptrs_, lapack_sys::chpev_
+);
@@ -205,3 +205,568 @@ impl_qr!(
     lapack_sys::cungqr_,
     lapack_sys::cunglq_
 );
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Multiply Q-matrix of QR-decomposition without constructing it
+pub trait ApplyQ_: Scalar {
+    /// Overwrite $m \times n$ matrix $B$ by $\mathrm{op}(Q) B$
+    /// where $\mathrm{op}(Q)$ is $Q$, $Q^T$ or $Q^H$ specified by `t`
+    ///
+    /// $Q$ is the $m \times m$ unitary matrix represented by
+    /// the Householder reflectors `a` and `tau` computed by [QR_::householder] with layout `l`.
+    /// Since $Q$ is never formed explicitly,
+    /// this only needs $O(m)$ additional memory for tall-skinny matrix $A$.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sormqr | dormqr | cunmqr | zunmqr |
+    /// | sormlq | dormlq | cunmlq | zunmlq |
+    ///
+    /// `*ormlq/*unmlq` are used for C-continuous `a`.
+    fn apply_q(
+        l: MatrixLayout,
+        a: &[Self],
+        tau: &[Self],
+        t: Transpose,
+        lb: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_apply_q {
+    ($scalar:ty, $mqr:path, $mlq:path, $trans:expr) => {
+        impl ApplyQ_ for $scalar {
+            fn apply_q(
+                l: MatrixLayout,
+                a: &[Self],
+                tau: &[Self],
+                t: Transpose,
+                lb: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (m, _) = l.size();
+                let (mb, _) = lb.size();
+                assert_eq!(m, mb, "The numbers of rows of A and B must be identical");
+                let k = tau.len() as i32;
+
+                // LAPACK sees B^T for C-continuous B, and then computes
+                // B^T op(Q)^T from the right. As in Solve_::solve,
+                // Q^H is replaced by Q^T with conjugating B,
+                // since B^T conj(Q) = conj(conj(B^T) Q).
+                let (side, t, conj_b) = match lb {
+                    MatrixLayout::F { .. } => (b'L', t, false),
+                    MatrixLayout::C { .. } => match t {
+                        Transpose::No => (b'R', Transpose::Transpose, false),
+                        Transpose::Transpose => (b'R', Transpose::No, false),
+                        Transpose::Hermite => (b'R', Transpose::No, true),
+                    },
+                };
+                // Householder reflectors are stored as LQ-decomposition of A^T
+                // for C-continuous A, i.e. they represent Q^T instead of Q.
+                // `$trans` is `T` for real and `C` for complex matrices.
+                let (trans, conj_q) = match l {
+                    MatrixLayout::F { .. } => match t {
+                        Transpose::No => (Transpose::No, false),
+                        Transpose::Transpose => ($trans, true),
+                        Transpose::Hermite => ($trans, false),
+                    },
+                    MatrixLayout::C { .. } => match t {
+                        Transpose::No => ($trans, true),
+                        Transpose::Transpose => (Transpose::No, false),
+                        Transpose::Hermite => (Transpose::No, true),
+                    },
+                };
+                let conj = conj_b ^ conj_q;
+
+                let m = lb.lda();
+                let n = lb.len();
+                if m == 0 || n == 0 {
+                    return Ok(());
+                }
+                if conj {
+                    for b_elem in &mut *b {
+                        *b_elem = b_elem.conj();
+                    }
+                }
+
+                let mqr: unsafe extern "C" fn(_, _, _, _, _, _, _, _, _, _, _, _, _) = match l {
+                    MatrixLayout::F { .. } => $mqr,
+                    MatrixLayout::C { .. } => $mlq,
+                };
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    mqr(
+                        &(side as i8),
+                        trans.as_ptr(),
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(b),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    mqr(
+                        &(side as i8),
+                        trans.as_ptr(),
+                        &m,
+                        &n,
+                        &k,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(b),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                if conj {
+                    for b_elem in &mut *b {
+                        *b_elem = b_elem.conj();
+                    }
+                }
+                Ok(())
+            }
+        }
+    };
+} // endmacro
+
+impl_apply_q!(
+    f64,
+    lapack_sys::dormqr_,
+    lapack_sys::dormlq_,
+    Transpose::Transpose
+);
+impl_apply_q!(
+    f32,
+    lapack_sys::sormqr_,
+    lapack_sys::sormlq_,
+    Transpose::Transpose
+);
+impl_apply_q!(
+    c64,
+    lapack_sys::zunmqr_,
+    lapack_sys::zunmlq_,
+    Transpose::Hermite
+);
+impl_apply_q!(
+    c32,
+    lapack_sys::cunmqr_,
+    lapack_sys::cunmlq_,
+    Transpose::Hermite
+);
+
+#[cfg_attr(doc, katexit::katexit)]
+/// QR decomposition with column pivoting
+///
+/// For a $m \times n$ matrix $A$, this computes $A P = Q R$
+/// where $P$ is a permutation matrix chosen so that
+/// the absolute values of the diagonal elements of $R$ are non-increasing.
+/// This is called rank-revealing QR decomposition
+/// since the numerical rank of $A$ can be estimated from the diagonal of $R$.
+///
+/// The permutation is returned as `jpvt` using 1-based index following LAPACK,
+/// i.e. the $j$-th column of $A P$ is the `jpvt[j] - 1`-th column of $A$.
+///
+/// Row-major input is transposed into column-major order before the LAPACK call,
+/// and the result is returned in the same layout as the input.
+pub trait QRPivoted_: QR_ + Scalar {
+    /// Execute Householder reflection with column pivoting
+    ///
+    /// `a` is overwritten by $R$ in its upper triangle
+    /// and Householder reflectors below the diagonal.
+    /// Returns the scalar factors of the reflectors and `jpvt`.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sgeqp3 | dgeqp3 | cgeqp3 | zgeqp3 |
+    ///
+    fn householder_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)>;
+
+    /// Execute QR-decomposition with column pivoting at once
+    ///
+    /// `a` is overwritten by $Q$, and $R$ is returned with `jpvt`
+    /// in the same manner as [QR_::qr].
+    fn qr_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)>;
+}
+
+macro_rules! impl_qr_pivoted {
+    (@real, $scalar:ty, $qp3:path) => {
+        impl_qr_pivoted!(@body, $scalar, $qp3, );
+    };
+    (@complex, $scalar:ty, $qp3:path) => {
+        impl_qr_pivoted!(@body, $scalar, $qp3, rwork);
+    };
+    (@body, $scalar:ty, $qp3:path, $($rwork_ident:ident),*) => {
+        impl QRPivoted_ for $scalar {
+            fn householder_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)> {
+                let (m, n) = l.size();
+                let k = m.min(n);
+                let mut a_t = None;
+                if let MatrixLayout::C { .. } = l {
+                    let (_, t) = transpose(l, a);
+                    a_t = Some(t);
+                }
+                let af = a_t.as_deref_mut().unwrap_or(&mut *a);
+
+                let mut tau = vec_uninit(k as usize);
+                // All columns are free columns
+                let mut jpvt = vec![0; n as usize];
+                $(
+                let mut $rwork_ident: Vec<MaybeUninit<Self::Real>> = vec_uninit(2 * n as usize);
+                )*
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(af),
+                        &m.max(1),
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        $(AsPtr::as_mut_ptr(&mut $rwork_ident),)*
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $qp3(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(af),
+                        &m.max(1),
+                        jpvt.as_mut_ptr(),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        $(AsPtr::as_mut_ptr(&mut $rwork_ident),)*
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                if let Some(t) = a_t {
+                    transpose_over(MatrixLayout::F { col: n, lda: m }, &t, a);
+                }
+                let tau = unsafe { tau.assume_init() };
+                Ok((tau, jpvt))
+            }
+
+            fn qr_pivoted(l: MatrixLayout, a: &mut [Self]) -> Result<(Vec<Self>, Pivot)> {
+                let (tau, jpvt) = Self::householder_pivoted(l, a)?;
+                let r = Vec::from(&*a);
+                let (m, n) = l.size();
+                if m == 0 || n == 0 {
+                    // Q is empty
+                    return Ok((r, jpvt));
+                }
+                match l {
+                    MatrixLayout::F { .. } => Self::q(l, a, &tau)?,
+                    MatrixLayout::C { .. } => {
+                        // Reflectors are stored in the same manner as column-major case,
+                        // which differs from the LQ-decomposition assumed in [QR_::q]
+                        let (lf, mut af) = transpose(l, a);
+                        Self::q(lf, &mut af, &tau)?;
+                        transpose_over(lf, &af, a);
+                    }
+                }
+                Ok((r, jpvt))
+            }
+        }
+    };
+} // endmacro
+
+impl_qr_pivoted!(@real, f64, lapack_sys::dgeqp3_);
+impl_qr_pivoted!(@real, f32, lapack_sys::sgeqp3_);
+impl_qr_pivoted!(@complex, c64, lapack_sys::zgeqp3_);
+impl_qr_pivoted!(@complex, c32, lapack_sys::cgeqp3_);
+
+/// Kind of orthogonal factorization computed by Householder reflections
+#[derive(Debug, Clone, Copy)]
+enum Householder {
+    QR,
+    LQ,
+    QL,
+    RQ,
+}
+
+impl Householder {
+    /// Size and offset of $Q$ generated in-place
+    /// from the reflectors stored in column-major `m x n` matrix
+    fn q_block(self, m: i32, n: i32) -> (i32, i32, usize) {
+        let k = m.min(n);
+        match self {
+            Householder::QR => (m, k, 0),
+            Householder::LQ => (k, n, 0),
+            // reflectors are stored in the last `k` columns
+            Householder::QL => (m, k, ((n - k) * m) as usize),
+            // reflectors are stored in the last `k` rows
+            Householder::RQ => (k, n, (m - k) as usize),
+        }
+    }
+}
+
+/// LQ decomposition $A = LQ$
+pub trait LQ_: Sized {
+    /// Execute Householder reflection as the first step of LQ-decomposition
+    ///
+    /// For C-continuous array,
+    /// this will call QR-decomposition of the transposed matrix $ A^T = Q^T L^T $
+    fn householder_lq(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+
+    /// Reconstruct Q-matrix from Householder-reflectors
+    ///
+    /// Q-matrix is stored in the first $k = \min(m, n)$ rows of `a`
+    fn q_lq(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
+
+    /// Execute LQ-decomposition at once
+    fn lq(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+}
+
+/// QL decomposition $A = QL$
+pub trait QL_: Sized {
+    /// Execute Householder reflection as the first step of QL-decomposition
+    ///
+    /// For C-continuous array,
+    /// this will call RQ-decomposition of the transposed matrix $ A^T = L^T Q^T $
+    fn householder_ql(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+
+    /// Reconstruct Q-matrix from Householder-reflectors
+    ///
+    /// Q-matrix is stored in the last $k = \min(m, n)$ columns of `a`
+    fn q_ql(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
+
+    /// Execute QL-decomposition at once
+    fn ql(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+}
+
+/// RQ decomposition $A = RQ$
+pub trait RQ_: Sized {
+    /// Execute Householder reflection as the first step of RQ-decomposition
+    ///
+    /// For C-continuous array,
+    /// this will call QL-decomposition of the transposed matrix $ A^T = Q^T R^T $
+    fn householder_rq(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+
+    /// Reconstruct Q-matrix from Householder-reflectors
+    ///
+    /// Q-matrix is stored in the last $k = \min(m, n)$ rows of `a`
+    fn q_rq(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()>;
+
+    /// Execute RQ-decomposition at once
+    fn rq(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>>;
+}
+
+macro_rules! impl_householder {
+    (
+        $trait:ident, $householder:ident, $q:ident, $qr:ident, $scalar:ty,
+        F => ($f_kind:ident, $f_fact:path, $f_gen:path),
+        C => ($c_kind:ident, $c_fact:path, $c_gen:path)
+    ) => {
+        impl $trait for $scalar {
+            fn $householder(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
+                let m = l.lda();
+                let n = l.len();
+                let k = m.min(n);
+                let mut tau = vec_uninit(k as usize);
+                let fact: unsafe extern "C" fn(_, _, _, _, _, _, _, _) = match l {
+                    MatrixLayout::F { .. } => $f_fact,
+                    MatrixLayout::C { .. } => $c_fact,
+                };
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    fact(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    fact(
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                let tau = unsafe { tau.assume_init() };
+
+                Ok(tau)
+            }
+
+            fn $q(l: MatrixLayout, a: &mut [Self], tau: &[Self]) -> Result<()> {
+                let m = l.lda();
+                let n = l.len();
+                let k = m.min(n);
+                assert_eq!(tau.len(), k as usize);
+                let (gen, (qm, qn, offset)): (unsafe extern "C" fn(_, _, _, _, _, _, _, _, _), _) =
+                    match l {
+                        MatrixLayout::F { .. } => ($f_gen, Householder::$f_kind.q_block(m, n)),
+                        MatrixLayout::C { .. } => ($c_gen, Householder::$c_kind.q_block(m, n)),
+                    };
+                let a = &mut a[offset..];
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    gen(
+                        &qm,
+                        &qn,
+                        &k,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    gen(
+                        &qm,
+                        &qn,
+                        &k,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(())
+            }
+
+            fn $qr(l: MatrixLayout, a: &mut [Self]) -> Result<Vec<Self>> {
+                let tau = Self::$householder(l, a)?;
+                let r = Vec::from(&*a);
+                Self::$q(l, a, &tau)?;
+                Ok(r)
+            }
+        }
+    };
+} // endmacro
+
+macro_rules! impl_lq_ql_rq {
+    ($scalar:ty, $qrf:path, $lqf:path, $qlf:path, $rqf:path, $gqr:path, $glq:path, $gql:path, $grq:path) => {
+        impl_householder!(
+            LQ_, householder_lq, q_lq, lq, $scalar,
+            F => (LQ, $lqf, $glq),
+            C => (QR, $qrf, $gqr)
+        );
+        impl_householder!(
+            QL_, householder_ql, q_ql, ql, $scalar,
+            F => (QL, $qlf, $gql),
+            C => (RQ, $rqf, $grq)
+        );
+        impl_householder!(
+            RQ_, householder_rq, q_rq, rq, $scalar,
+            F => (RQ, $rqf, $grq),
+            C => (QL, $qlf, $gql)
+        );
+    };
+}
+
+impl_lq_ql_rq!(
+    f64,
+    lapack_sys::dgeqrf_,
+    lapack_sys::dgelqf_,
+    lapack_sys::dgeqlf_,
+    lapack_sys::dgerqf_,
+    lapack_sys::dorgqr_,
+    lapack_sys::dorglq_,
+    lapack_sys::dorgql_,
+    lapack_sys::dorgrq_
+);
+impl_lq_ql_rq!(
+    f32,
+    lapack_sys::sgeqrf_,
+    lapack_sys::sgelqf_,
+    lapack_sys::sgeqlf_,
+    lapack_sys::sgerqf_,
+    lapack_sys::sorgqr_,
+    lapack_sys::sorglq_,
+    lapack_sys::sorgql_,
+    lapack_sys::sorgrq_
+);
+impl_lq_ql_rq!(
+    c64,
+    lapack_sys::zgeqrf_,
+    lapack_sys::zgelqf_,
+    lapack_sys::zgeqlf_,
+    lapack_sys::zgerqf_,
+    lapack_sys::zungqr_,
+    lapack_sys::zunglq_,
+    lapack_sys::zungql_,
+    lapack_sys::zungrq_
+);
+impl_lq_ql_rq!(
+    c32,
+    lapack_sys::cgeqrf_,
+    lapack_sys::cgelqf_,
+    lapack_sys::cgeqlf_,
+    lapack_sys::cgerqf_,
+    lapack_sys::cungqr_,
+    lapack_sys::cunglq_,
+    lapack_sys::cungql_,
+    lapack_sys::cungrq_
+);
@@ -0,0 +1,561 @@
+//! Generalized Schur (QZ) decomposition for general matrix pairs
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgges  | dgges  | cgges  | zgges  |
+//! | stgsen | dtgsen | ctgsen | ztgsen |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Generalized Schur (QZ) decomposition for a pair of general matrices
+///
+/// For a pair of square matrices $(A, B)$, this computes
+/// $$
+/// A = Q S Z^\dagger, \quad B = Q T Z^\dagger
+/// $$
+/// where $Q$ and $Z$ are unitary (orthogonal for real matrices),
+/// $T$ is upper triangular,
+/// and $S$ is upper triangular for complex matrices,
+/// or upper quasi-triangular with 1-by-1 and 2-by-2 diagonal blocks for real matrices.
+///
+/// The generalized eigenvalues are returned as pairs $(\alpha_i, \beta_i)$
+/// where $\lambda_i = \alpha_i / \beta_i$, as in [crate::eig_generalized::GeneralizedEigWork].
+///
+/// Eigenvalue reordering
+/// ----------------------
+/// When a predicate on $(\alpha_i, \beta_i)$ is passed to [QZWork::calc],
+/// the selected eigenvalues are moved to the leading diagonal blocks of $(S, T)$ by `*tgsen`,
+/// and the first `sdim` columns of $Q$ and $Z$ span the corresponding
+/// left and right deflating subspaces.
+/// For real matrices, a complex conjugate pair is selected if either of them is selected.
+///
+/// Row-major input is transposed into column-major order before the LAPACK call,
+/// and $S$, $T$, $Q$ and $Z$ are returned in the same layout as the input.
+///
+#[non_exhaustive]
+pub struct QZWork<T: Scalar> {
+    /// Problem size
+    pub n: i32,
+    /// Layout of input matrices
+    pub layout: MatrixLayout,
+    /// Compute Schur vectors or not
+    pub jobv: JobEv,
+
+    /// Numerator of eigenvalues
+    pub alpha: Vec<MaybeUninit<T::Complex>>,
+    /// Real part of numerator used in real routines
+    pub alpha_re: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Imaginary part of numerator used in real routines
+    pub alpha_im: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Denominator of eigenvalues
+    pub beta: Vec<MaybeUninit<T>>,
+
+    /// Left Schur vectors
+    pub vsl: Option<Vec<MaybeUninit<T>>>,
+    /// Right Schur vectors
+    pub vsr: Option<Vec<MaybeUninit<T>>>,
+
+    /// Working memory
+    pub work: Vec<MaybeUninit<T>>,
+    /// Working memory with `T::Real`
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+impl<T> QZWork<T>
+where
+    T: Scalar,
+    QZWork<T>: QZWorkImpl<Elem = T>,
+{
+    /// Create new working memory for QZ decomposition.
+    pub fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+        QZWorkImpl::new(calc_v, l)
+    }
+
+    /// Compute QZ decomposition on this working memory.
+    ///
+    /// `a` and `b` are overwritten by $S$ and $T$ respectively.
+    pub fn calc(
+        &mut self,
+        a: &mut [T],
+        b: &mut [T],
+        select: Option<&mut QZSelect<T>>,
+    ) -> Result<QZRef<'_, T>> {
+        QZWorkImpl::calc(self, a, b, select)
+    }
+
+    /// Compute QZ decomposition by consuming this working memory.
+    ///
+    /// `a` and `b` are overwritten by $S$ and $T$ respectively.
+    pub fn eval(
+        self,
+        a: &mut [T],
+        b: &mut [T],
+        select: Option<&mut QZSelect<T>>,
+    ) -> Result<QZOwned<T>> {
+        QZWorkImpl::eval(self, a, b, select)
+    }
+}
+
+/// Predicate on a generalized eigenvalue `(alpha, beta)` used for reordering
+pub type QZSelect<'a, T> = dyn FnMut(<T as Scalar>::Complex, T) -> bool + 'a;
+
+/// Owned result of QZ decomposition by [QZWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct QZOwned<T: Scalar> {
+    /// Numerator of eigenvalues
+    pub alpha: Vec<T::Complex>,
+    /// Denominator of eigenvalues
+    pub beta: Vec<T>,
+    /// Left Schur vectors $Q$
+    pub vsl: Option<Vec<T>>,
+    /// Right Schur vectors $Z$
+    pub vsr: Option<Vec<T>>,
+    /// Number of eigenvalues selected by the predicate.
+    /// This is zero if no predicate is given.
+    pub sdim: i32,
+}
+
+/// Reference result of QZ decomposition by [QZWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct QZRef<'work, T: Scalar> {
+    /// Numerator of eigenvalues
+    pub alpha: &'work [T::Complex],
+    /// Denominator of eigenvalues
+    pub beta: &'work [T],
+    /// Left Schur vectors $Q$
+    pub vsl: Option<&'work [T]>,
+    /// Right Schur vectors $Z$
+    pub vsr: Option<&'work [T]>,
+    /// Number of eigenvalues selected by the predicate.
+    /// This is zero if no predicate is given.
+    pub sdim: i32,
+}
+
+/// Helper trait for implementing [QZWork] methods
+pub trait QZWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_v: bool, l: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+        select: Option<&mut QZSelect<Self::Elem>>,
+    ) -> Result<QZRef<'work, Self::Elem>>;
+    fn eval(
+        self,
+        a: &mut [Self::Elem],
+        b: &mut [Self::Elem],
+        select: Option<&mut QZSelect<Self::Elem>>,
+    ) -> Result<QZOwned<Self::Elem>>;
+}
+
+macro_rules! impl_qz_work_c {
+    ($c:ty, $gges:path, $tgsen:path) => {
+        impl QZWorkImpl for QZWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_v: bool, layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobv = if calc_v { JobEv::All } else { JobEv::None };
+                let mut alpha = vec_uninit(n as usize);
+                let mut beta = vec_uninit(n as usize);
+                let mut rwork = vec_uninit(8 * n as usize);
+                let mut vsl = jobv.then(|| vec_uninit((n * n) as usize));
+                let mut vsr = jobv.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut sdim = 0;
+                let mut work_size = [<$c>::zero()];
+                unsafe {
+                    $gges(
+                        jobv.as_ptr(),
+                        jobv.as_ptr(),
+                        Sort::NotSorted.as_ptr(), // reordering is done by *tgsen
+                        None,
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vsl.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(vsr.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    layout,
+                    jobv,
+                    alpha,
+                    alpha_re: None,
+                    alpha_im: None,
+                    beta,
+                    vsl,
+                    vsr,
+                    work,
+                    rwork: Some(rwork),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+                select: Option<&mut QZSelect<Self::Elem>>,
+            ) -> Result<QZRef<'work, Self::Elem>> {
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                    square_transpose(self.layout, b);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut sdim = 0;
+                unsafe {
+                    $gges(
+                        self.jobv.as_ptr(),
+                        self.jobv.as_ptr(),
+                        Sort::NotSorted.as_ptr(), // reordering is done by *tgsen
+                        None,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut self.alpha),
+                        AsPtr::as_mut_ptr(&mut self.beta),
+                        AsPtr::as_mut_ptr(self.vsl.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.vsr.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                if let Some(select) = select {
+                    let bselect: Vec<i32> = {
+                        let alpha = unsafe { self.alpha.slice_assume_init_ref() };
+                        let beta = unsafe { self.beta.slice_assume_init_ref() };
+                        alpha
+                            .iter()
+                            .zip(beta)
+                            .map(|(&a, &b)| select(a, b) as i32)
+                            .collect()
+                    };
+                    let wantv = self.jobv.is_calc() as i32;
+                    let mut pl = <$c as Scalar>::Real::zero();
+                    let mut pr = <$c as Scalar>::Real::zero();
+                    let mut dif = [<$c as Scalar>::Real::zero(); 2];
+                    let mut iwork = [0];
+                    unsafe {
+                        $tgsen(
+                            &0, // IJOB = 0, only reorder
+                            &wantv,
+                            &wantv,
+                            bselect.as_ptr(),
+                            &self.n,
+                            AsPtr::as_mut_ptr(a),
+                            &self.n,
+                            AsPtr::as_mut_ptr(b),
+                            &self.n,
+                            AsPtr::as_mut_ptr(&mut self.alpha),
+                            AsPtr::as_mut_ptr(&mut self.beta),
+                            AsPtr::as_mut_ptr(self.vsl.as_deref_mut().unwrap_or(&mut [])),
+                            &self.n,
+                            AsPtr::as_mut_ptr(self.vsr.as_deref_mut().unwrap_or(&mut [])),
+                            &self.n,
+                            &mut sdim,
+                            &mut pl,
+                            &mut pr,
+                            dif.as_mut_ptr(),
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            iwork.as_mut_ptr(),
+                            &1,
+                            &mut info,
+                        )
+                    };
+                    info.as_lapack_result()?;
+                }
+
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                    square_transpose(self.layout, b);
+                    if let Some(vsl) = self.vsl.as_mut() {
+                        square_transpose(self.layout, unsafe { vsl.slice_assume_init_mut() });
+                    }
+                    if let Some(vsr) = self.vsr.as_mut() {
+                        square_transpose(self.layout, unsafe { vsr.slice_assume_init_mut() });
+                    }
+                }
+
+                Ok(QZRef {
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    vsl: self
+                        .vsl
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    vsr: self
+                        .vsr
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    sdim,
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+                select: Option<&mut QZSelect<Self::Elem>>,
+            ) -> Result<QZOwned<Self::Elem>> {
+                let QZRef { sdim, .. } = self.calc(a, b, select)?;
+                Ok(QZOwned {
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    vsl: self.vsl.map(|v| unsafe { v.assume_init() }),
+                    vsr: self.vsr.map(|v| unsafe { v.assume_init() }),
+                    sdim,
+                })
+            }
+        }
+    };
+}
+
+impl_qz_work_c!(c32, lapack_sys::cgges_, lapack_sys::ctgsen_);
+impl_qz_work_c!(c64, lapack_sys::zgges_, lapack_sys::ztgsen_);
+
+macro_rules! impl_qz_work_r {
+    ($f:ty, $gges:path, $tgsen:path) => {
+        impl QZWorkImpl for QZWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_v: bool, layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobv = if calc_v { JobEv::All } else { JobEv::None };
+                let mut alpha_re = vec_uninit(n as usize);
+                let mut alpha_im = vec_uninit(n as usize);
+                let mut beta = vec_uninit(n as usize);
+                let mut vsl = jobv.then(|| vec_uninit((n * n) as usize));
+                let mut vsr = jobv.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut sdim = 0;
+                let mut work_size: [$f; 1] = [0.0];
+                unsafe {
+                    $gges(
+                        jobv.as_ptr(),
+                        jobv.as_ptr(),
+                        Sort::NotSorted.as_ptr(), // reordering is done by *tgsen
+                        None,
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut alpha_re),
+                        AsPtr::as_mut_ptr(&mut alpha_im),
+                        AsPtr::as_mut_ptr(&mut beta),
+                        AsPtr::as_mut_ptr(vsl.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(vsr.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // *tgsen requires 4n+16 for IJOB = 0
+                let lwork = work_size[0].to_usize().unwrap().max(4 * n as usize + 16);
+                let work = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    layout,
+                    jobv,
+                    alpha: vec_uninit(n as usize),
+                    alpha_re: Some(alpha_re),
+                    alpha_im: Some(alpha_im),
+                    beta,
+                    vsl,
+                    vsr,
+                    work,
+                    rwork: None,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+                select: Option<&mut QZSelect<Self::Elem>>,
+            ) -> Result<QZRef<'work, Self::Elem>> {
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                    square_transpose(self.layout, b);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut sdim = 0;
+                unsafe {
+                    $gges(
+                        self.jobv.as_ptr(),
+                        self.jobv.as_ptr(),
+                        Sort::NotSorted.as_ptr(), // reordering is done by *tgsen
+                        None,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        AsPtr::as_mut_ptr(b),
+                        &self.n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(self.alpha_re.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.alpha_im.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(&mut self.beta),
+                        AsPtr::as_mut_ptr(self.vsl.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(self.vsr.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                if let Some(select) = select {
+                    let bselect: Vec<i32> = {
+                        let alpha_re =
+                            unsafe { self.alpha_re.as_ref().unwrap().slice_assume_init_ref() };
+                        let alpha_im =
+                            unsafe { self.alpha_im.as_ref().unwrap().slice_assume_init_ref() };
+                        let beta = unsafe { self.beta.slice_assume_init_ref() };
+                        alpha_re
+                            .iter()
+                            .zip(alpha_im)
+                            .zip(beta)
+                            .map(|((&re, &im), &b)| select(<$f>::complex(re, im), b) as i32)
+                            .collect()
+                    };
+                    let wantv = self.jobv.is_calc() as i32;
+                    let mut pl = 0.0;
+                    let mut pr = 0.0;
+                    let mut dif = [0.0; 2];
+                    let mut iwork = [0];
+                    unsafe {
+                        $tgsen(
+                            &0, // IJOB = 0, only reorder
+                            &wantv,
+                            &wantv,
+                            bselect.as_ptr(),
+                            &self.n,
+                            AsPtr::as_mut_ptr(a),
+                            &self.n,
+                            AsPtr::as_mut_ptr(b),
+                            &self.n,
+                            AsPtr::as_mut_ptr(self.alpha_re.as_mut().unwrap()),
+                            AsPtr::as_mut_ptr(self.alpha_im.as_mut().unwrap()),
+                            AsPtr::as_mut_ptr(&mut self.beta),
+                            AsPtr::as_mut_ptr(self.vsl.as_deref_mut().unwrap_or(&mut [])),
+                            &self.n,
+                            AsPtr::as_mut_ptr(self.vsr.as_deref_mut().unwrap_or(&mut [])),
+                            &self.n,
+                            &mut sdim,
+                            &mut pl,
+                            &mut pr,
+                            dif.as_mut_ptr(),
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            iwork.as_mut_ptr(),
+                            &1,
+                            &mut info,
+                        )
+                    };
+                    info.as_lapack_result()?;
+                }
+
+                let alpha_re = unsafe { self.alpha_re.as_ref().unwrap().slice_assume_init_ref() };
+                let alpha_im = unsafe { self.alpha_im.as_ref().unwrap().slice_assume_init_ref() };
+                eig::reconstruct_eigs(alpha_re, alpha_im, &mut self.alpha);
+
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                    square_transpose(self.layout, b);
+                    if let Some(vsl) = self.vsl.as_mut() {
+                        square_transpose(self.layout, unsafe { vsl.slice_assume_init_mut() });
+                    }
+                    if let Some(vsr) = self.vsr.as_mut() {
+                        square_transpose(self.layout, unsafe { vsr.slice_assume_init_mut() });
+                    }
+                }
+
+                Ok(QZRef {
+                    alpha: unsafe { self.alpha.slice_assume_init_ref() },
+                    beta: unsafe { self.beta.slice_assume_init_ref() },
+                    vsl: self
+                        .vsl
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    vsr: self
+                        .vsr
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    sdim,
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                b: &mut [Self::Elem],
+                select: Option<&mut QZSelect<Self::Elem>>,
+            ) -> Result<QZOwned<Self::Elem>> {
+                let QZRef { sdim, .. } = self.calc(a, b, select)?;
+                Ok(QZOwned {
+                    alpha: unsafe { self.alpha.assume_init() },
+                    beta: unsafe { self.beta.assume_init() },
+                    vsl: self.vsl.map(|v| unsafe { v.assume_init() }),
+                    vsr: self.vsr.map(|v| unsafe { v.assume_init() }),
+                    sdim,
+                })
+            }
+        }
+    };
+}
+
+impl_qz_work_r!(f32, lapack_sys::sgges_, lapack_sys::stgsen_);
+impl_qz_work_r!(f64, lapack_sys::dgges_, lapack_sys::dtgsen_);
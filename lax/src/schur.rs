@@ -0,0 +1,454 @@
+//! Schur decomposition for general matrices
+//!
+//! LAPACK correspondance
+//! ----------------------
+//!
+//! | f32    | f64    | c32    | c64    |
+//! |:-------|:-------|:-------|:-------|
+//! | sgees  | dgees  | cgees  | zgees  |
+//! | strsen | dtrsen | ctrsen | ztrsen |
+//!
+
+use crate::{error::*, layout::MatrixLayout, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Schur decomposition for general matrix
+///
+/// For a square matrix $A$, this computes
+/// $$
+/// A = Z T Z^\dagger
+/// $$
+/// where $Z$ is unitary (orthogonal for real matrices),
+/// and $T$ is upper triangular for complex matrices,
+/// or upper quasi-triangular with 1-by-1 and 2-by-2 diagonal blocks for real matrices.
+/// Each 2-by-2 block corresponds to a pair of complex conjugate eigenvalues.
+///
+/// Eigenvalue reordering
+/// ----------------------
+/// The eigenvalues appear on the diagonal of $T$ in no particular order.
+/// When a predicate is passed to [SchurWork::calc],
+/// the eigenvalues for which it returns `true` are moved to the leading diagonal block of $T$
+/// by `*trsen`, and $Z$ is updated so that its first `sdim` columns span
+/// the corresponding invariant subspace.
+/// For real matrices, a complex conjugate pair is selected if either of them is selected.
+///
+/// Row-major input is transposed into column-major order before the LAPACK call,
+/// and $T$ and $Z$ are returned in the same layout as the input.
+///
+#[non_exhaustive]
+pub struct SchurWork<T: Scalar> {
+    /// Problem size
+    pub n: i32,
+    /// Layout of input matrix
+    pub layout: MatrixLayout,
+    /// Compute Schur vectors or not
+    pub jobvs: JobEv,
+
+    /// Eigenvalues
+    pub eigs: Vec<MaybeUninit<T::Complex>>,
+    /// Real part of eigenvalues used in real routines
+    pub eigs_re: Option<Vec<MaybeUninit<T::Real>>>,
+    /// Imaginary part of eigenvalues used in real routines
+    pub eigs_im: Option<Vec<MaybeUninit<T::Real>>>,
+
+    /// Schur vectors
+    pub vs: Option<Vec<MaybeUninit<T>>>,
+
+    /// Working memory
+    pub work: Vec<MaybeUninit<T>>,
+    /// Working memory with `T::Real`
+    pub rwork: Option<Vec<MaybeUninit<T::Real>>>,
+}
+
+impl<T> SchurWork<T>
+where
+    T: Scalar,
+    SchurWork<T>: SchurWorkImpl<Elem = T>,
+{
+    /// Create new working memory for Schur decomposition.
+    pub fn new(calc_v: bool, l: MatrixLayout) -> Result<Self> {
+        SchurWorkImpl::new(calc_v, l)
+    }
+
+    /// Compute Schur decomposition on this working memory.
+    ///
+    /// `a` is overwritten by $T$.
+    /// If `select` is given, the selected eigenvalues are moved to the top-left of $T$.
+    pub fn calc(
+        &mut self,
+        a: &mut [T],
+        select: Option<&mut dyn FnMut(T::Complex) -> bool>,
+    ) -> Result<SchurRef<'_, T>> {
+        SchurWorkImpl::calc(self, a, select)
+    }
+
+    /// Compute Schur decomposition by consuming this working memory.
+    ///
+    /// `a` is overwritten by $T$.
+    /// If `select` is given, the selected eigenvalues are moved to the top-left of $T$.
+    pub fn eval(
+        self,
+        a: &mut [T],
+        select: Option<&mut dyn FnMut(T::Complex) -> bool>,
+    ) -> Result<SchurOwned<T>> {
+        SchurWorkImpl::eval(self, a, select)
+    }
+}
+
+/// Owned result of Schur decomposition by [SchurWork::eval]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchurOwned<T: Scalar> {
+    /// Eigenvalues in the order they appear on the diagonal of $T$
+    pub eigs: Vec<T::Complex>,
+    /// Schur vectors $Z$
+    pub vs: Option<Vec<T>>,
+    /// Number of eigenvalues selected by the predicate.
+    /// This is zero if no predicate is given.
+    pub sdim: i32,
+}
+
+/// Reference result of Schur decomposition by [SchurWork::calc]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchurRef<'work, T: Scalar> {
+    /// Eigenvalues in the order they appear on the diagonal of $T$
+    pub eigs: &'work [T::Complex],
+    /// Schur vectors $Z$
+    pub vs: Option<&'work [T]>,
+    /// Number of eigenvalues selected by the predicate.
+    /// This is zero if no predicate is given.
+    pub sdim: i32,
+}
+
+/// Helper trait for implementing [SchurWork] methods
+pub trait SchurWorkImpl: Sized {
+    type Elem: Scalar;
+    fn new(calc_v: bool, l: MatrixLayout) -> Result<Self>;
+    fn calc<'work>(
+        &'work mut self,
+        a: &mut [Self::Elem],
+        select: Option<&mut dyn FnMut(<Self::Elem as Scalar>::Complex) -> bool>,
+    ) -> Result<SchurRef<'work, Self::Elem>>;
+    fn eval(
+        self,
+        a: &mut [Self::Elem],
+        select: Option<&mut dyn FnMut(<Self::Elem as Scalar>::Complex) -> bool>,
+    ) -> Result<SchurOwned<Self::Elem>>;
+}
+
+macro_rules! impl_schur_work_c {
+    ($c:ty, $gees:path, $trsen:path) => {
+        impl SchurWorkImpl for SchurWork<$c> {
+            type Elem = $c;
+
+            fn new(calc_v: bool, layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobvs = if calc_v { JobEv::All } else { JobEv::None };
+                let mut eigs = vec_uninit(n as usize);
+                let mut rwork = vec_uninit(n as usize);
+                let mut vs = jobvs.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut sdim = 0;
+                let mut work_size = [<$c>::zero()];
+                unsafe {
+                    $gees(
+                        jobvs.as_ptr(),
+                        Sort::NotSorted.as_ptr(), // reordering is done by *trsen
+                        None,
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut eigs),
+                        AsPtr::as_mut_ptr(vs.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        AsPtr::as_mut_ptr(&mut rwork),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    layout,
+                    jobvs,
+                    eigs,
+                    eigs_re: None,
+                    eigs_im: None,
+                    vs,
+                    work,
+                    rwork: Some(rwork),
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                select: Option<&mut dyn FnMut(<Self::Elem as Scalar>::Complex) -> bool>,
+            ) -> Result<SchurRef<'work, Self::Elem>> {
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut sdim = 0;
+                unsafe {
+                    $gees(
+                        self.jobvs.as_ptr(),
+                        Sort::NotSorted.as_ptr(), // reordering is done by *trsen
+                        None,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut self.eigs),
+                        AsPtr::as_mut_ptr(self.vs.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        AsPtr::as_mut_ptr(self.rwork.as_mut().unwrap()),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                if let Some(select) = select {
+                    let bselect: Vec<i32> = unsafe { self.eigs.slice_assume_init_ref() }
+                        .iter()
+                        .map(|&e| select(e) as i32)
+                        .collect();
+                    let mut s = <$c as Scalar>::Real::zero();
+                    let mut sep = <$c as Scalar>::Real::zero();
+                    unsafe {
+                        $trsen(
+                            Sense::None.as_ptr(),
+                            self.jobvs.as_ptr(),
+                            bselect.as_ptr(),
+                            &self.n,
+                            AsPtr::as_mut_ptr(a),
+                            &self.n,
+                            AsPtr::as_mut_ptr(self.vs.as_deref_mut().unwrap_or(&mut [])),
+                            &self.n,
+                            AsPtr::as_mut_ptr(&mut self.eigs),
+                            &mut sdim,
+                            &mut s,
+                            &mut sep,
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            &mut info,
+                        )
+                    };
+                    info.as_lapack_result()?;
+                }
+
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                    if let Some(vs) = self.vs.as_mut() {
+                        square_transpose(self.layout, unsafe { vs.slice_assume_init_mut() });
+                    }
+                }
+
+                Ok(SchurRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    vs: self
+                        .vs
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    sdim,
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                select: Option<&mut dyn FnMut(<Self::Elem as Scalar>::Complex) -> bool>,
+            ) -> Result<SchurOwned<Self::Elem>> {
+                let SchurRef { sdim, .. } = self.calc(a, select)?;
+                Ok(SchurOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    vs: self.vs.map(|v| unsafe { v.assume_init() }),
+                    sdim,
+                })
+            }
+        }
+    };
+}
+
+impl_schur_work_c!(c32, lapack_sys::cgees_, lapack_sys::ctrsen_);
+impl_schur_work_c!(c64, lapack_sys::zgees_, lapack_sys::ztrsen_);
+
+macro_rules! impl_schur_work_r {
+    ($f:ty, $gees:path, $trsen:path) => {
+        impl SchurWorkImpl for SchurWork<$f> {
+            type Elem = $f;
+
+            fn new(calc_v: bool, layout: MatrixLayout) -> Result<Self> {
+                assert_eq!(layout.len(), layout.lda());
+                let n = layout.len();
+                let jobvs = if calc_v { JobEv::All } else { JobEv::None };
+                let mut eigs_re = vec_uninit(n as usize);
+                let mut eigs_im = vec_uninit(n as usize);
+                let mut vs = jobvs.then(|| vec_uninit((n * n) as usize));
+
+                // calc work size
+                let mut info = 0;
+                let mut sdim = 0;
+                let mut work_size: [$f; 1] = [0.0];
+                unsafe {
+                    $gees(
+                        jobvs.as_ptr(),
+                        Sort::NotSorted.as_ptr(), // reordering is done by *trsen
+                        None,
+                        &n,
+                        std::ptr::null_mut(),
+                        &n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(&mut eigs_re),
+                        AsPtr::as_mut_ptr(&mut eigs_im),
+                        AsPtr::as_mut_ptr(vs.as_deref_mut().unwrap_or(&mut [])),
+                        &n,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // *gees requires 3n, and *trsen requires n for JOB = 'N'
+                let lwork = work_size[0].to_usize().unwrap();
+                let work = vec_uninit(lwork);
+                Ok(Self {
+                    n,
+                    layout,
+                    jobvs,
+                    eigs: vec_uninit(n as usize),
+                    eigs_re: Some(eigs_re),
+                    eigs_im: Some(eigs_im),
+                    vs,
+                    work,
+                    rwork: None,
+                })
+            }
+
+            fn calc<'work>(
+                &'work mut self,
+                a: &mut [Self::Elem],
+                select: Option<&mut dyn FnMut(<Self::Elem as Scalar>::Complex) -> bool>,
+            ) -> Result<SchurRef<'work, Self::Elem>> {
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                }
+                let lwork = self.work.len().to_i32().unwrap();
+                let mut info = 0;
+                let mut sdim = 0;
+                unsafe {
+                    $gees(
+                        self.jobvs.as_ptr(),
+                        Sort::NotSorted.as_ptr(), // reordering is done by *trsen
+                        None,
+                        &self.n,
+                        AsPtr::as_mut_ptr(a),
+                        &self.n,
+                        &mut sdim,
+                        AsPtr::as_mut_ptr(self.eigs_re.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.eigs_im.as_mut().unwrap()),
+                        AsPtr::as_mut_ptr(self.vs.as_deref_mut().unwrap_or(&mut [])),
+                        &self.n,
+                        AsPtr::as_mut_ptr(&mut self.work),
+                        &lwork,
+                        std::ptr::null_mut(),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                if let Some(select) = select {
+                    let bselect: Vec<i32> = {
+                        let eigs_re =
+                            unsafe { self.eigs_re.as_ref().unwrap().slice_assume_init_ref() };
+                        let eigs_im =
+                            unsafe { self.eigs_im.as_ref().unwrap().slice_assume_init_ref() };
+                        eigs_re
+                            .iter()
+                            .zip(eigs_im)
+                            .map(|(&re, &im)| select(<$f>::complex(re, im)) as i32)
+                            .collect()
+                    };
+                    let mut s = 0.0;
+                    let mut sep = 0.0;
+                    let mut iwork = [0];
+                    unsafe {
+                        $trsen(
+                            Sense::None.as_ptr(),
+                            self.jobvs.as_ptr(),
+                            bselect.as_ptr(),
+                            &self.n,
+                            AsPtr::as_mut_ptr(a),
+                            &self.n,
+                            AsPtr::as_mut_ptr(self.vs.as_deref_mut().unwrap_or(&mut [])),
+                            &self.n,
+                            AsPtr::as_mut_ptr(self.eigs_re.as_mut().unwrap()),
+                            AsPtr::as_mut_ptr(self.eigs_im.as_mut().unwrap()),
+                            &mut sdim,
+                            &mut s,
+                            &mut sep,
+                            AsPtr::as_mut_ptr(&mut self.work),
+                            &lwork,
+                            iwork.as_mut_ptr(),
+                            &1,
+                            &mut info,
+                        )
+                    };
+                    info.as_lapack_result()?;
+                }
+
+                let eigs_re = unsafe { self.eigs_re.as_ref().unwrap().slice_assume_init_ref() };
+                let eigs_im = unsafe { self.eigs_im.as_ref().unwrap().slice_assume_init_ref() };
+                eig::reconstruct_eigs(eigs_re, eigs_im, &mut self.eigs);
+
+                if matches!(self.layout, MatrixLayout::C { .. }) {
+                    square_transpose(self.layout, a);
+                    if let Some(vs) = self.vs.as_mut() {
+                        square_transpose(self.layout, unsafe { vs.slice_assume_init_mut() });
+                    }
+                }
+
+                Ok(SchurRef {
+                    eigs: unsafe { self.eigs.slice_assume_init_ref() },
+                    vs: self
+                        .vs
+                        .as_ref()
+                        .map(|v| unsafe { v.slice_assume_init_ref() }),
+                    sdim,
+                })
+            }
+
+            fn eval(
+                mut self,
+                a: &mut [Self::Elem],
+                select: Option<&mut dyn FnMut(<Self::Elem as Scalar>::Complex) -> bool>,
+            ) -> Result<SchurOwned<Self::Elem>> {
+                let SchurRef { sdim, .. } = self.calc(a, select)?;
+                Ok(SchurOwned {
+                    eigs: unsafe { self.eigs.assume_init() },
+                    vs: self.vs.map(|v| unsafe { v.assume_init() }),
+                    sdim,
+                })
+            }
+        }
+    };
+}
+
+impl_schur_work_r!(f32, lapack_sys::sgees_, lapack_sys::strsen_);
+impl_schur_work_r!(f64, lapack_sys::dgees_, lapack_sys::dtrsen_);
@@ -30,6 +30,22 @@ pub trait Solveh_: Sized {
     ///
     fn bk(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<Pivot>;
 
+    /// Same as [Solveh_::bk], but returns the pivot with the `info` code of LAPACK
+    /// instead of an error if $D$ is exactly singular.
+    ///
+    /// The factorization has been completed even in this case,
+    /// and positive `info` means that `info`-th diagonal element of $D$ (1-based)
+    /// is exactly zero, which is a 1-by-1 block.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | ssytrf | dsytrf | chetrf | zhetrf |
+    ///
+    fn bk_with_info(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<(Pivot, i32)>;
+
     /// Compute inverse matrix $A^{-1}$ from factroized result
     ///
     /// LAPACK correspondance
@@ -51,16 +67,56 @@ pub trait Solveh_: Sized {
     /// | ssytrs | dsytrs | chetrs | zhetrs |
     ///
     fn solveh(l: MatrixLayout, uplo: UPLO, a: &[Self], ipiv: &Pivot, b: &mut [Self]) -> Result<()>;
+
+    /// Factorize input matrix using bounded Bunch-Kaufman ("rook") diagonal pivoting method
+    ///
+    /// Unlike [Solveh_::bk], both of the interchanges for 2-by-2 block are recorded in the pivot,
+    /// i.e. if `ipiv[k]` and `ipiv[k-1]` are negative for [UPLO::Upper],
+    /// rows and columns $k$ and `-ipiv[k]` are interchanged,
+    /// and then $k-1$ and `-ipiv[k-1]` are interchanged (1-based).
+    /// Use [Solveh_::solveh_rook] for the factorized result.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32         | f64         | c32         | c64         |
+    /// |:------------|:------------|:------------|:------------|
+    /// | ssytrf_rook | dsytrf_rook | chetrf_rook | zhetrf_rook |
+    ///
+    fn bk_rook(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<Pivot>;
+
+    /// Solve linear equation $Ax = b$ using result factorized by [Solveh_::bk_rook]
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32         | f64         | c32         | c64         |
+    /// |:------------|:------------|:------------|:------------|
+    /// | ssytrs_rook | dsytrs_rook | chetrs_rook | zhetrs_rook |
+    ///
+    fn solveh_rook(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &[Self],
+        ipiv: &Pivot,
+        b: &mut [Self],
+    ) -> Result<()>;
 }
 
 macro_rules! impl_solveh {
-    ($scalar:ty, $trf:path, $tri:path, $trs:path) => {
+    ($scalar:ty, $trf:path, $tri:path, $trs:path, $trf_rook:path, $trs_rook:path) => {
         impl Solveh_ for $scalar {
             fn bk(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<Pivot> {
+                let (ipiv, info) = Self::bk_with_info(l, uplo, a)?;
+                info.as_lapack_result()?;
+                Ok(ipiv)
+            }
+
+            fn bk_with_info(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<(Pivot, i32)> {
                 let (n, _) = l.size();
                 let mut ipiv = vec_uninit(n as usize);
                 if n == 0 {
-                    return Ok(Vec::new());
+                    return Ok((Vec::new(), 0));
                 }
 
                 // calc work size
@@ -95,9 +151,12 @@ macro_rules! impl_solveh {
                         &mut info,
                     )
                 };
-                info.as_lapack_result()?;
+                // Positive `info` only means that `D` is exactly singular
+                if info < 0 {
+                    info.as_lapack_result()?;
+                }
                 let ipiv = unsafe { ipiv.assume_init() };
-                Ok(ipiv)
+                Ok((ipiv, info))
             }
 
             fn invh(l: MatrixLayout, uplo: UPLO, a: &mut [Self], ipiv: &Pivot) -> Result<()> {
@@ -144,6 +203,76 @@ macro_rules! impl_solveh {
                 info.as_lapack_result()?;
                 Ok(())
             }
+
+            fn bk_rook(l: MatrixLayout, uplo: UPLO, a: &mut [Self]) -> Result<Pivot> {
+                let (n, _) = l.size();
+                let mut ipiv = vec_uninit(n as usize);
+                if n == 0 {
+                    return Ok(Vec::new());
+                }
+
+                // calc work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $trf_rook(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+
+                // actual
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $trf_rook(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &l.lda(),
+                        AsPtr::as_mut_ptr(&mut ipiv),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                let ipiv = unsafe { ipiv.assume_init() };
+                Ok(ipiv)
+            }
+
+            fn solveh_rook(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &[Self],
+                ipiv: &Pivot,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = l.size();
+                let mut info = 0;
+                unsafe {
+                    $trs_rook(
+                        uplo.as_ptr(),
+                        &n,
+                        &1,
+                        AsPtr::as_ptr(a),
+                        &l.lda(),
+                        ipiv.as_ptr(),
+                        AsPtr::as_mut_ptr(b),
+                        &n.max(1),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(())
+            }
         }
     };
 } // impl_solveh!
@@ -152,23 +281,31 @@ impl_solveh!(
     f64,
     lapack_sys::dsytrf_,
     lapack_sys::dsytri_,
-    lapack_sys::dsytrs_
+    lapack_sys::dsytrs_,
+    lapack_sys::dsytrf_rook_,
+    lapack_sys::dsytrs_rook_
 );
 impl_solveh!(
     f32,
     lapack_sys::ssytrf_,
     lapack_sys::ssytri_,
-    lapack_sys::ssytrs_
+    lapack_sys::ssytrs_,
+    lapack_sys::ssytrf_rook_,
+    lapack_sys::ssytrs_rook_
 );
 impl_solveh!(
     c64,
     lapack_sys::zhetrf_,
     lapack_sys::zhetri_,
-    lapack_sys::zhetrs_
+    lapack_sys::zhetrs_,
+    lapack_sys::zhetrf_rook_,
+    lapack_sys::zhetrs_rook_
 );
 impl_solveh!(
     c32,
     lapack_sys::chetrf_,
     lapack_sys::chetri_,
-    lapack_sys::chetrs_
+    lapack_sys::chetrs_,
+    lapack_sys::chetrf_rook_,
+    lapack_sys::chetrs_rook_
 );
@@ -0,0 +1,405 @@
+//! Singular-value decomposition by one-sided Jacobi method
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Singular value decomposition by one-sided Jacobi method
+///
+/// Unlike [SVD_] and [SVDDC_], which are based on bidiagonalization,
+/// one-sided Jacobi method computes the singular values of $A = D_1 C D_2$
+/// with diagonal scalings $D_1, D_2$ and a well-conditioned $C$
+/// to full relative accuracy, i.e. small singular values of graded or badly scaled matrices
+/// are as accurate as large ones.
+pub trait SVDJacobi_: Scalar {
+    /// Compute singular value decomposition $A = U \Sigma V^T$
+    /// by preconditioned one-sided Jacobi method
+    ///
+    /// $U$ and $V^T$ are computed as square matrices as [SVD_::svd].
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sgejsv | dgejsv | cgejsv | zgejsv |
+    ///
+    fn svd_jacobi(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+    ) -> Result<SVDOutput<Self>>;
+
+    /// Compute singular value decomposition $A = U \Sigma V^T$
+    /// by one-sided Jacobi method without preconditioning
+    ///
+    /// For $m \times n$ matrix $A$ with $k = \min(m, n)$,
+    /// $U$ is $m \times k$ and $V^T$ is $k \times n$.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sgesvj | dgesvj | cgesvj | zgesvj |
+    ///
+    fn svd_jacobi_onesided(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        a: &mut [Self],
+    ) -> Result<SVDOutput<Self>>;
+}
+
+/// Conjugate transpose of column-major `m x n` matrix into column-major `n x m` matrix
+fn conj_transpose<T: Scalar>(m: i32, n: i32, a: &[T]) -> Vec<T> {
+    let m = m as usize;
+    let n = n as usize;
+    assert_eq!(a.len(), m * n);
+    let mut out: Vec<MaybeUninit<T>> = vec_uninit(m * n);
+    for i in 0..m {
+        for j in 0..n {
+            out[j + i * n].write(a[i + j * m].conj());
+        }
+    }
+    unsafe { out.assume_init() }
+}
+
+/// Compute SVD of a matrix in any layout using `svd` for column-major `m x n` matrix with `m >= n`,
+/// which `*gejsv` and `*gesvj` require.
+///
+/// `svd` must return $U$ with `m` rows and square $V^T$.
+fn svd_tall<T: Scalar>(
+    l: MatrixLayout,
+    calc_u: bool,
+    calc_vt: bool,
+    a: &mut [T],
+    svd: impl FnOnce(i32, i32, bool, bool, &mut [T]) -> Result<SVDOutput<T>>,
+) -> Result<SVDOutput<T>> {
+    // LAPACK sees the transposed matrix for C layout, see SVD_::svd
+    let (calc_u, calc_vt) = match l {
+        MatrixLayout::F { .. } => (calc_u, calc_vt),
+        MatrixLayout::C { .. } => (calc_vt, calc_u),
+    };
+    let m = l.lda();
+    let n = l.len();
+
+    let res = if m >= n {
+        svd(m, n, calc_u, calc_vt, a)?
+    } else {
+        // A = V \Sigma U^H for A^H = U \Sigma V^H
+        let mut ah = conj_transpose(m, n, a);
+        let res = svd(n, m, calc_vt, calc_u, &mut ah)?;
+        SVDOutput {
+            s: res.s,
+            u: res.vt.map(|vt| conj_transpose(m, m, &vt)),
+            vt: res.u.map(|u| {
+                let k = u.len() as i32 / n;
+                conj_transpose(n, k, &u)
+            }),
+        }
+    };
+
+    match l {
+        MatrixLayout::F { .. } => Ok(res),
+        MatrixLayout::C { .. } => Ok(SVDOutput {
+            s: res.s,
+            u: res.vt,
+            vt: res.u,
+        }),
+    }
+}
+
+macro_rules! impl_svd_jacobi {
+    (@real, $scalar:ty, $gejsv:path, $gesvj:path) => {
+        impl SVDJacobi_ for $scalar {
+            fn svd_jacobi(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SVDOutput<Self>> {
+                svd_tall(l, calc_u, calc_vt, a, |m, n, calc_u, calc_v, a| {
+                    let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                    let mut u: Option<Vec<MaybeUninit<Self>>> =
+                        calc_u.then(|| vec_uninit((m * m) as usize));
+                    let mut v: Option<Vec<MaybeUninit<Self>>> =
+                        calc_v.then(|| vec_uninit((n * n) as usize));
+
+                    // *gejsv does not support workspace query,
+                    // and this is the minimal requirement for full SVD
+                    let lwork = (2 * m + n).max(6 * n + 2 * n * n).max(7);
+                    let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork as usize);
+                    let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit((m + 3 * n).max(3) as usize);
+
+                    let mut info = 0;
+                    unsafe {
+                        $gejsv(
+                            // JOBA = 'F': high relative accuracy for A = D1 C D2
+                            &(b'F' as i8),
+                            // JOBU = 'F': full set of left singular vectors
+                            &(if calc_u { b'F' } else { b'N' } as i8),
+                            &(if calc_v { b'V' } else { b'N' } as i8),
+                            // JOBR = 'R': restrict the range of the singular values to avoid overflow
+                            &(b'R' as i8),
+                            // JOBT = 'N': do not transpose A, since m >= n here
+                            &(b'N' as i8),
+                            // JOBP = 'N': do not perturb denormalized numbers
+                            &(b'N' as i8),
+                            &m,
+                            &n,
+                            AsPtr::as_mut_ptr(a),
+                            &m,
+                            AsPtr::as_mut_ptr(&mut s),
+                            AsPtr::as_mut_ptr(
+                                u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut []),
+                            ),
+                            &m,
+                            AsPtr::as_mut_ptr(
+                                v.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut []),
+                            ),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut work),
+                            &lwork,
+                            AsPtr::as_mut_ptr(&mut iwork),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    // Only the scaling factors at the beginning of `work` are read
+                    let work = unsafe { slice_prefix_assume_init_ref(&work, 2) };
+                    let scale = work[0] / work[1];
+                    let s = unsafe { s.assume_init() };
+                    Ok(SVDOutput {
+                        s: s.into_iter().map(|s| scale * s).collect(),
+                        u: u.map(|u| unsafe { u.assume_init() }),
+                        vt: v.map(|v| conj_transpose(n, n, &unsafe { v.assume_init() })),
+                    })
+                })
+            }
+
+            fn svd_jacobi_onesided(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SVDOutput<Self>> {
+                svd_tall(l, calc_u, calc_vt, a, |m, n, calc_u, calc_v, a| {
+                    let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                    let mut v: Option<Vec<MaybeUninit<Self>>> =
+                        calc_v.then(|| vec_uninit((n * n) as usize));
+
+                    // *gesvj does not support workspace query
+                    let lwork = (m + n).max(6);
+                    let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork as usize);
+
+                    let mut info = 0;
+                    unsafe {
+                        $gesvj(
+                            // JOBA = 'G': general matrix
+                            &(b'G' as i8),
+                            // JOBU = 'U': left singular vectors are written into A
+                            &(if calc_u { b'U' } else { b'N' } as i8),
+                            &(if calc_v { b'V' } else { b'N' } as i8),
+                            &m,
+                            &n,
+                            AsPtr::as_mut_ptr(a),
+                            &m,
+                            AsPtr::as_mut_ptr(&mut s),
+                            &0,
+                            AsPtr::as_mut_ptr(
+                                v.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut []),
+                            ),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut work),
+                            &lwork,
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    // Only the scaling factors at the beginning of `work` are read
+                    let work = unsafe { slice_prefix_assume_init_ref(&work, 2) };
+                    let scale = work[0];
+                    let s = unsafe { s.assume_init() };
+                    Ok(SVDOutput {
+                        s: s.into_iter().map(|s| scale * s).collect(),
+                        u: calc_u.then(|| a.to_vec()),
+                        vt: v.map(|v| conj_transpose(n, n, &unsafe { v.assume_init() })),
+                    })
+                })
+            }
+        }
+    };
+    (@complex, $scalar:ty, $gejsv:path, $gesvj:path) => {
+        impl SVDJacobi_ for $scalar {
+            fn svd_jacobi(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SVDOutput<Self>> {
+                svd_tall(l, calc_u, calc_vt, a, |m, n, calc_u, calc_v, a| {
+                    let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                    let mut u: Option<Vec<MaybeUninit<Self>>> =
+                        calc_u.then(|| vec_uninit((m * m) as usize));
+                    let mut v: Option<Vec<MaybeUninit<Self>>> =
+                        calc_v.then(|| vec_uninit((n * n) as usize));
+                    let joba = b'F' as i8;
+                    let jobu = if calc_u { b'F' } else { b'N' } as i8;
+                    let jobv = if calc_v { b'V' } else { b'N' } as i8;
+                    let jobr = b'R' as i8;
+                    let jobt = b'N' as i8;
+                    let jobp = b'N' as i8;
+
+                    // eval work size
+                    let mut info = 0;
+                    let mut work_size = [Self::zero(); 2];
+                    let mut rwork_size = [Self::Real::zero()];
+                    let mut iwork_size = [0];
+                    unsafe {
+                        $gejsv(
+                            &joba,
+                            &jobu,
+                            &jobv,
+                            &jobr,
+                            &jobt,
+                            &jobp,
+                            &m,
+                            &n,
+                            AsPtr::as_mut_ptr(a),
+                            &m,
+                            AsPtr::as_mut_ptr(&mut s),
+                            AsPtr::as_mut_ptr(
+                                u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut []),
+                            ),
+                            &m,
+                            AsPtr::as_mut_ptr(
+                                v.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut []),
+                            ),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut work_size),
+                            &(-1),
+                            AsPtr::as_mut_ptr(&mut rwork_size),
+                            &(-1),
+                            AsPtr::as_mut_ptr(&mut iwork_size),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    // calc
+                    let lwork = work_size[0].to_usize().unwrap();
+                    let lrwork = rwork_size[0].to_usize().unwrap().max(7);
+                    let liwork = iwork_size[0].to_usize().unwrap();
+                    let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                    let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(lrwork);
+                    let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(liwork);
+                    unsafe {
+                        $gejsv(
+                            &joba,
+                            &jobu,
+                            &jobv,
+                            &jobr,
+                            &jobt,
+                            &jobp,
+                            &m,
+                            &n,
+                            AsPtr::as_mut_ptr(a),
+                            &m,
+                            AsPtr::as_mut_ptr(&mut s),
+                            AsPtr::as_mut_ptr(
+                                u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut []),
+                            ),
+                            &m,
+                            AsPtr::as_mut_ptr(
+                                v.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut []),
+                            ),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut work),
+                            &(lwork as i32),
+                            AsPtr::as_mut_ptr(&mut rwork),
+                            &(lrwork as i32),
+                            AsPtr::as_mut_ptr(&mut iwork),
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    // Only the scaling factors at the beginning of `rwork` are read
+                    let rwork = unsafe { slice_prefix_assume_init_ref(&rwork, 2) };
+                    let scale = rwork[0] / rwork[1];
+                    let s = unsafe { s.assume_init() };
+                    Ok(SVDOutput {
+                        s: s.into_iter().map(|s| scale * s).collect(),
+                        u: u.map(|u| unsafe { u.assume_init() }),
+                        vt: v.map(|v| conj_transpose(n, n, &unsafe { v.assume_init() })),
+                    })
+                })
+            }
+
+            fn svd_jacobi_onesided(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                a: &mut [Self],
+            ) -> Result<SVDOutput<Self>> {
+                svd_tall(l, calc_u, calc_vt, a, |m, n, calc_u, calc_v, a| {
+                    let mut s: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                    let mut v: Option<Vec<MaybeUninit<Self>>> =
+                        calc_v.then(|| vec_uninit((n * n) as usize));
+
+                    let lwork = m + n;
+                    let lrwork = n.max(6);
+                    let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork as usize);
+                    let mut rwork: Vec<MaybeUninit<Self::Real>> = vec_uninit(lrwork as usize);
+
+                    let mut info = 0;
+                    unsafe {
+                        $gesvj(
+                            // JOBA = 'G': general matrix
+                            &(b'G' as i8),
+                            // JOBU = 'U': left singular vectors are written into A
+                            &(if calc_u { b'U' } else { b'N' } as i8),
+                            &(if calc_v { b'V' } else { b'N' } as i8),
+                            &m,
+                            &n,
+                            AsPtr::as_mut_ptr(a),
+                            &m,
+                            AsPtr::as_mut_ptr(&mut s),
+                            &0,
+                            AsPtr::as_mut_ptr(
+                                v.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut []),
+                            ),
+                            &n,
+                            AsPtr::as_mut_ptr(&mut work),
+                            &lwork,
+                            AsPtr::as_mut_ptr(&mut rwork),
+                            &lrwork,
+                            &mut info,
+                        );
+                    }
+                    info.as_lapack_result()?;
+
+                    // Only the scaling factors at the beginning of `rwork` are read
+                    let rwork = unsafe { slice_prefix_assume_init_ref(&rwork, 2) };
+                    let scale = rwork[0];
+                    let s = unsafe { s.assume_init() };
+                    Ok(SVDOutput {
+                        s: s.into_iter().map(|s| scale * s).collect(),
+                        u: calc_u.then(|| a.to_vec()),
+                        vt: v.map(|v| conj_transpose(n, n, &unsafe { v.assume_init() })),
+                    })
+                })
+            }
+        }
+    };
+} // impl_svd_jacobi!
+
+impl_svd_jacobi!(@real, f64, lapack_sys::dgejsv_, lapack_sys::dgesvj_);
+impl_svd_jacobi!(@real, f32, lapack_sys::sgejsv_, lapack_sys::sgesvj_);
+impl_svd_jacobi!(@complex, c64, lapack_sys::zgejsv_, lapack_sys::zgesvj_);
+impl_svd_jacobi!(@complex, c32, lapack_sys::cgejsv_, lapack_sys::cgesvj_);
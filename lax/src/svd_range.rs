@@ -0,0 +1,180 @@
+//! Singular-value decomposition for selected singular values
+
+use super::{error::*, layout::*, *};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Singular value decomposition for selected singular values
+pub trait SVDRange_: Scalar {
+    /// Compute singular triplets selected by [EigRange]
+    ///
+    /// Singular values are sorted in descending order as [SVD_::svd],
+    /// and thus [EigRange::Index] counts from the largest singular value,
+    /// e.g. `EigRange::Index(0..k)` selects the `k` largest singular values.
+    /// [EigRange::Value] selects singular values in the half-open interval `(vl, vu]`.
+    ///
+    /// For $m \times n$ matrix $A$ with $k$ selected singular values,
+    /// $U$ is $m \times k$ and $V^T$ is $k \times n$.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32     | f64     | c32     | c64     |
+    /// |:--------|:--------|:--------|:--------|
+    /// | sgesvdx | dgesvdx | cgesvdx | zgesvdx |
+    ///
+    fn svd_range(
+        l: MatrixLayout,
+        calc_u: bool,
+        calc_vt: bool,
+        range: EigRange<Self::Real>,
+        a: &mut [Self],
+    ) -> Result<SVDOutput<Self>>;
+}
+
+macro_rules! impl_svd_range {
+    (@real, $scalar:ty, $gesvdx:path) => {
+        impl_svd_range!(@body, $scalar, $gesvdx, );
+    };
+    (@complex, $scalar:ty, $gesvdx:path) => {
+        impl_svd_range!(@body, $scalar, $gesvdx, rwork);
+    };
+    (@body, $scalar:ty, $gesvdx:path, $($rwork_ident:ident),*) => {
+        impl SVDRange_ for $scalar {
+            fn svd_range(
+                l: MatrixLayout,
+                calc_u: bool,
+                calc_vt: bool,
+                range: EigRange<Self::Real>,
+                a: &mut [Self],
+            ) -> Result<SVDOutput<Self>> {
+                // LAPACK sees the transposed matrix for C layout, see SVD_::svd
+                let (calc_u, calc_vt) = match l {
+                    MatrixLayout::F { .. } => (calc_u, calc_vt),
+                    MatrixLayout::C { .. } => (calc_vt, calc_u),
+                };
+                let ju = if calc_u { JobEv::All } else { JobEv::None };
+                let jvt = if calc_vt { JobEv::All } else { JobEv::None };
+
+                let m = l.lda();
+                let n = l.len();
+                let k = std::cmp::min(m, n);
+                let (vl, vu, il, iu) = range.bounds();
+
+                // Upper bound of the number of singular values found
+                let ucol = match range {
+                    EigRange::Index(_) => iu - il + 1,
+                    _ => k,
+                };
+                let ldvt = ucol.max(1);
+                let mut u = match ju {
+                    JobEv::All => Some(vec_uninit((m * ucol) as usize)),
+                    JobEv::None => None,
+                };
+                let mut vt = match jvt {
+                    JobEv::All => Some(vec_uninit((ldvt * n) as usize)),
+                    JobEv::None => None,
+                };
+
+                let mut ns = 0;
+                let mut s = vec_uninit(k as usize);
+                let mut iwork: Vec<MaybeUninit<i32>> = vec_uninit(12 * k as usize);
+                $(
+                let mut $rwork_ident: Vec<MaybeUninit<Self::Real>> =
+                    vec_uninit((17 * k * k).max(1) as usize);
+                )*
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gesvdx(
+                        ju.as_ptr(),
+                        jvt.as_ptr(),
+                        range.as_ptr(),
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &mut ns,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &m,
+                        AsPtr::as_mut_ptr(vt.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvt,
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        $(AsPtr::as_mut_ptr(&mut $rwork_ident),)*
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gesvdx(
+                        ju.as_ptr(),
+                        jvt.as_ptr(),
+                        range.as_ptr(),
+                        &m,
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &m,
+                        &vl,
+                        &vu,
+                        &il,
+                        &iu,
+                        &mut ns,
+                        AsPtr::as_mut_ptr(&mut s),
+                        AsPtr::as_mut_ptr(u.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &m,
+                        AsPtr::as_mut_ptr(vt.as_mut().map(|x| x.as_mut_slice()).unwrap_or(&mut [])),
+                        &ldvt,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        $(AsPtr::as_mut_ptr(&mut $rwork_ident),)*
+                        AsPtr::as_mut_ptr(&mut iwork),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // Only the first `ns` singular values and vectors are written
+                let ns = ns as usize;
+                s.truncate(ns);
+                let s = unsafe { s.assume_init() };
+                let u = u.map(|mut u| {
+                    u.truncate(m as usize * ns);
+                    unsafe { u.assume_init() }
+                });
+                // Pack the first `ns` rows of `ldvt x n` matrix into `ns x n` matrix
+                let vt = vt.map(|vt| {
+                    let mut packed = Vec::with_capacity(ns * n as usize);
+                    for j in 0..n as usize {
+                        let col = &vt[j * ldvt as usize..];
+                        packed.extend_from_slice(unsafe { slice_prefix_assume_init_ref(col, ns) });
+                    }
+                    packed
+                });
+
+                match l {
+                    MatrixLayout::F { .. } => Ok(SVDOutput { s, u, vt }),
+                    MatrixLayout::C { .. } => Ok(SVDOutput { s, u: vt, vt: u }),
+                }
+            }
+        }
+    };
+} // impl_svd_range!
+
+impl_svd_range!(@real, f64, lapack_sys::dgesvdx_);
+impl_svd_range!(@real, f32, lapack_sys::sgesvdx_);
+impl_svd_range!(@complex, c64, lapack_sys::zgesvdx_);
+impl_svd_range!(@complex, c32, lapack_sys::cgesvdx_);
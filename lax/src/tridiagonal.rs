@@ -257,3 +257,146 @@ impl_tridiagonal!(@real, f64, lapack_sys::dgttrf_, lapack_sys::dgtcon_, lapack_s
 impl_tridiagonal!(@real, f32, lapack_sys::sgttrf_, lapack_sys::sgtcon_, lapack_sys::sgttrs_);
 impl_tridiagonal!(@complex, c64, lapack_sys::zgttrf_, lapack_sys::zgtcon_, lapack_sys::zgttrs_);
 impl_tridiagonal!(@complex, c32, lapack_sys::cgttrf_, lapack_sys::cgtcon_, lapack_sys::cgttrs_);
+
+/// Represents the `L * D * L^H` factorization of a hermite (or real symmetric)
+/// positive definite tridiagonal matrix `A`.
+#[derive(Clone, PartialEq)]
+pub struct LDLFactorizedTridiagonal<A: Scalar> {
+    /// layout of raw matrix
+    pub l: MatrixLayout,
+    /// (n) diagonal elements of the diagonal matrix D.
+    pub d: Vec<A::Real>,
+    /// (n-1) sub-diagonal elements of the unit lower bidiagonal matrix L.
+    pub e: Vec<A>,
+
+    a_opnorm_one: A::Real,
+}
+
+/// Wraps `*pttrf`, `*ptcon` and `*pttrs`
+pub trait LDLTridiagonal_: Scalar + Sized {
+    /// Computes the `L * D * L^H` factorization of a hermite positive definite
+    /// tridiagonal matrix `a`.
+    ///
+    /// Only `d` and `dl` of `a` are referenced,
+    /// i.e. `du` is assumed to be the complex conjugate of `dl`,
+    /// and the imaginary parts of `d` are ignored.
+    fn ldl_tridiagonal(a: &Tridiagonal<Self>) -> Result<LDLFactorizedTridiagonal<Self>>;
+
+    fn rcond_ldl_tridiagonal(f: &LDLFactorizedTridiagonal<Self>) -> Result<Self::Real>;
+
+    fn solve_ldl_tridiagonal(
+        f: &LDLFactorizedTridiagonal<Self>,
+        bl: MatrixLayout,
+        b: &mut [Self],
+    ) -> Result<()>;
+}
+
+macro_rules! impl_ldl_tridiagonal {
+    (@real, $scalar:ty, $pttrf:path, $ptcon:path, $pttrs:path) => {
+        impl_ldl_tridiagonal!(@body, $scalar, $pttrf, $ptcon, $pttrs, );
+    };
+    (@complex, $scalar:ty, $pttrf:path, $ptcon:path, $pttrs:path) => {
+        impl_ldl_tridiagonal!(@body, $scalar, $pttrf, $ptcon, $pttrs, UPLO::Lower);
+    };
+    (@body, $scalar:ty, $pttrf:path, $ptcon:path, $pttrs:path, $($uplo:expr)?) => {
+        impl LDLTridiagonal_ for $scalar {
+            fn ldl_tridiagonal(a: &Tridiagonal<Self>) -> Result<LDLFactorizedTridiagonal<Self>> {
+                let (n, _) = a.l.size();
+                let mut d: Vec<Self::Real> = a.d.iter().map(|x| x.re()).collect();
+                let mut e = a.dl.clone();
+                // We have to calc one-norm before factorization
+                let mut a_opnorm_one = Self::Real::zero();
+                for i in 0..n as usize {
+                    let mut col_sum = d[i].abs();
+                    if i < e.len() {
+                        col_sum += e[i].abs();
+                    }
+                    if i > 0 {
+                        col_sum += e[i - 1].abs();
+                    }
+                    if a_opnorm_one < col_sum {
+                        a_opnorm_one = col_sum;
+                    }
+                }
+                let mut info = 0;
+                unsafe {
+                    $pttrf(
+                        &n,
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        &mut info,
+                    )
+                };
+                info.as_lapack_result()?;
+                Ok(LDLFactorizedTridiagonal {
+                    l: a.l,
+                    d,
+                    e,
+                    a_opnorm_one,
+                })
+            }
+
+            fn rcond_ldl_tridiagonal(f: &LDLFactorizedTridiagonal<Self>) -> Result<Self::Real> {
+                let (n, _) = f.l.size();
+                let mut work: Vec<MaybeUninit<Self::Real>> = vec_uninit(n as usize);
+                let mut rcond = Self::Real::zero();
+                let mut info = 0;
+                unsafe {
+                    $ptcon(
+                        &n,
+                        AsPtr::as_ptr(&f.d),
+                        AsPtr::as_ptr(&f.e),
+                        &f.a_opnorm_one,
+                        &mut rcond,
+                        AsPtr::as_mut_ptr(&mut work),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                Ok(rcond)
+            }
+
+            fn solve_ldl_tridiagonal(
+                f: &LDLFactorizedTridiagonal<Self>,
+                b_layout: MatrixLayout,
+                b: &mut [Self],
+            ) -> Result<()> {
+                let (n, _) = f.l.size();
+                // Transpose if b is C-continuous
+                let mut b_t = None;
+                let b_layout = match b_layout {
+                    MatrixLayout::C { .. } => {
+                        let (layout, t) = transpose(b_layout, b);
+                        b_t = Some(t);
+                        layout
+                    }
+                    MatrixLayout::F { .. } => b_layout,
+                };
+                let (ldb, nrhs) = b_layout.size();
+                let mut info = 0;
+                unsafe {
+                    $pttrs(
+                        $($uplo.as_ptr(),)?
+                        &n,
+                        &nrhs,
+                        AsPtr::as_ptr(&f.d),
+                        AsPtr::as_ptr(&f.e),
+                        AsPtr::as_mut_ptr(b_t.as_mut().map(|v| v.as_mut_slice()).unwrap_or(b)),
+                        &ldb,
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+                if let Some(b_t) = b_t {
+                    transpose_over(b_layout, &b_t, b);
+                }
+                Ok(())
+            }
+        }
+    };
+} // impl_ldl_tridiagonal!
+
+impl_ldl_tridiagonal!(@real, f64, lapack_sys::dpttrf_, lapack_sys::dptcon_, lapack_sys::dpttrs_);
+impl_ldl_tridiagonal!(@real, f32, lapack_sys::spttrf_, lapack_sys::sptcon_, lapack_sys::spttrs_);
+impl_ldl_tridiagonal!(@complex, c64, lapack_sys::zpttrf_, lapack_sys::zptcon_, lapack_sys::zpttrs_);
+impl_ldl_tridiagonal!(@complex, c32, lapack_sys::cpttrf_, lapack_sys::cptcon_, lapack_sys::cpttrs_);
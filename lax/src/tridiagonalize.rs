@@ -0,0 +1,182 @@
+use super::*;
+use crate::{error::*, layout::*};
+use cauchy::*;
+use num_traits::{ToPrimitive, Zero};
+
+#[cfg_attr(doc, katexit::katexit)]
+/// Reduce symmetric/hermite matrix into real symmetric tridiagonal form
+///
+/// For a given symmetric/hermite matrix $A$, this computes
+/// $$
+/// A = Q T Q^\dagger
+/// $$
+/// where $Q$ is unitary (orthogonal for real matrices) and
+/// $T$ is real symmetric tridiagonal.
+///
+/// This is designed as two step computation according to LAPACK API
+///
+/// 1. Reduce $A$ into $T$ by Householder reflections
+/// 2. Reconstruct $Q$ from the Householder reflectors
+pub trait Tridiagonalize_: Scalar {
+    /// Reduce $A$ into real symmetric tridiagonal form $T$
+    ///
+    /// Only the triangle of `a` specified by [UPLO] is referenced.
+    /// It is overwritten by the Householder reflectors,
+    /// whose scalar factors are returned with $T$.
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | ssytrd | dsytrd | chetrd | zhetrd |
+    ///
+    fn tridiagonalize(
+        l: MatrixLayout,
+        uplo: UPLO,
+        a: &mut [Self],
+    ) -> Result<(Tridiagonal<Self>, Vec<Self>)>;
+
+    /// Reconstruct $Q$ from the Householder reflectors computed by [Tridiagonalize_::tridiagonalize]
+    ///
+    /// LAPACK correspondance
+    /// ----------------------
+    ///
+    /// | f32    | f64    | c32    | c64    |
+    /// |:-------|:-------|:-------|:-------|
+    /// | sorgtr | dorgtr | cungtr | zungtr |
+    ///
+    fn tridiagonalize_q(l: MatrixLayout, uplo: UPLO, a: &mut [Self], tau: &[Self]) -> Result<()>;
+}
+
+macro_rules! impl_tridiagonalize {
+    ($scalar:ty, $trd:path, $gtr:path) => {
+        impl Tridiagonalize_ for $scalar {
+            fn tridiagonalize(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+            ) -> Result<(Tridiagonal<Self>, Vec<Self>)> {
+                let (n, _) = l.size();
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let mut d = vec_uninit(n as usize);
+                let mut e = vec_uninit((n - 1).max(0) as usize);
+                let mut tau = vec_uninit((n - 1).max(0) as usize);
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $trd(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $trd(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_mut_ptr(&mut d),
+                        AsPtr::as_mut_ptr(&mut e),
+                        AsPtr::as_mut_ptr(&mut tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                let d: Vec<Self::Real> = unsafe { d.assume_init() };
+                let e: Vec<Self::Real> = unsafe { e.assume_init() };
+                let tau = unsafe { tau.assume_init() };
+
+                // T is real symmetric, thus dl = du = e
+                let e: Vec<Self> = e.into_iter().map(Self::from_real).collect();
+                let t = Tridiagonal {
+                    l,
+                    dl: e.clone(),
+                    d: d.into_iter().map(Self::from_real).collect(),
+                    du: e,
+                };
+                Ok((t, tau))
+            }
+
+            fn tridiagonalize_q(
+                l: MatrixLayout,
+                uplo: UPLO,
+                a: &mut [Self],
+                tau: &[Self],
+            ) -> Result<()> {
+                let (n, _) = l.size();
+                assert_eq!(tau.len(), (n - 1).max(0) as usize);
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+
+                // eval work size
+                let mut info = 0;
+                let mut work_size = [Self::zero()];
+                unsafe {
+                    $gtr(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work_size),
+                        &(-1),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                // calc
+                let lwork = work_size[0].to_usize().unwrap();
+                let mut work: Vec<MaybeUninit<Self>> = vec_uninit(lwork);
+                unsafe {
+                    $gtr(
+                        uplo.as_ptr(),
+                        &n,
+                        AsPtr::as_mut_ptr(a),
+                        &n.max(1),
+                        AsPtr::as_ptr(tau),
+                        AsPtr::as_mut_ptr(&mut work),
+                        &(lwork as i32),
+                        &mut info,
+                    );
+                }
+                info.as_lapack_result()?;
+
+                if matches!(l, MatrixLayout::C { .. }) {
+                    square_transpose(l, a);
+                }
+                Ok(())
+            }
+        }
+    };
+} // endmacro
+
+impl_tridiagonalize!(f64, lapack_sys::dsytrd_, lapack_sys::dorgtr_);
+impl_tridiagonalize!(f32, lapack_sys::ssytrd_, lapack_sys::sorgtr_);
+impl_tridiagonalize!(c64, lapack_sys::zhetrd_, lapack_sys::zungtr_);
+impl_tridiagonalize!(c32, lapack_sys::chetrd_, lapack_sys::cungtr_);
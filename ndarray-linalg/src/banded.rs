@@ -0,0 +1,705 @@
+//! Vectors as a Banded matrix
+//! &
+//! Methods for general and hermite banded matrices
+
+use super::convert::*;
+use super::error::*;
+use super::layout::*;
+use cauchy::Scalar;
+use lax::*;
+use ndarray::*;
+
+pub use lax::{Banded, BandedHermite, CholeskyFactorizedBanded, LUFactorizedBanded};
+
+/// An interface for making a Banded struct.
+pub trait ExtractBanded<A: Scalar> {
+    /// Extract the elements in the band of `kl` sub-diagonals and `ku` super-diagonals
+    /// and layout of the raw matrix.
+    ///
+    /// If the raw matrix has some elements outside the band,
+    /// they will be ignored.
+    fn extract_banded(&self, kl: usize, ku: usize) -> Result<Banded<A>>;
+}
+
+impl<A, S> ExtractBanded<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn extract_banded(&self, kl: usize, ku: usize) -> Result<Banded<A>> {
+        let l = self.square_layout()?;
+        let n = self.nrows();
+        let mut a = Banded::zeros(l, kl as i32, ku as i32);
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..n.min(j + kl + 1) {
+                a[(i as i32, j as i32)] = self[(i, j)];
+            }
+        }
+        Ok(a)
+    }
+}
+
+pub trait SolveBanded<A: Scalar, D: Dimension> {
+    /// Solves a system of linear equations `A * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+    /// Solves a system of linear equations `A * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>>;
+    /// Solves a system of linear equations `A^T * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_t_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+    /// Solves a system of linear equations `A^T * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_t_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>>;
+    /// Solves a system of linear equations `A^H * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_h_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+    /// Solves a system of linear equations `A^H * x = b` with banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result.
+    fn solve_h_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>>;
+}
+
+pub trait SolveBandedInplace<A: Scalar, D: Dimension> {
+    /// Solves a system of linear equations `A * x = b` banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result. The value of `x` is also assigned to the
+    /// argument.
+    fn solve_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+    /// Solves a system of linear equations `A^T * x = b` banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result. The value of `x` is also assigned to the
+    /// argument.
+    fn solve_t_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+    /// Solves a system of linear equations `A^H * x = b` banded
+    /// matrix `A`, where `A` is `self`, `b` is the argument, and
+    /// `x` is the successful result. The value of `x` is also assigned to the
+    /// argument.
+    fn solve_h_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+}
+
+impl<A> SolveBanded<A, Ix2> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveBanded<A, Ix2> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_t_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solve_h_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveBandedInplace<A, Ix2> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        A::solve_banded(
+            self,
+            rhs.layout()?,
+            Transpose::No,
+            rhs.as_slice_mut().unwrap(),
+        )?;
+        Ok(rhs)
+    }
+    fn solve_t_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        A::solve_banded(
+            self,
+            rhs.layout()?,
+            Transpose::Transpose,
+            rhs.as_slice_mut().unwrap(),
+        )?;
+        Ok(rhs)
+    }
+    fn solve_h_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        A::solve_banded(
+            self,
+            rhs.layout()?,
+            Transpose::Hermite,
+            rhs.as_slice_mut().unwrap(),
+        )?;
+        Ok(rhs)
+    }
+}
+
+impl<A> SolveBandedInplace<A, Ix2> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize_banded()?;
+        f.solve_banded_inplace(rhs)
+    }
+    fn solve_t_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize_banded()?;
+        f.solve_t_banded_inplace(rhs)
+    }
+    fn solve_h_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorize_banded()?;
+        f.solve_h_banded_inplace(rhs)
+    }
+}
+
+impl<A> SolveBanded<A, Ix1> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_banded_into(b)
+    }
+    fn solve_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, Ix1>,
+    ) -> Result<ArrayBase<S, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_t_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_t_banded_into(b)
+    }
+    fn solve_t_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, Ix1>,
+    ) -> Result<ArrayBase<S, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_t_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_h_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_h_banded_into(b)
+    }
+    fn solve_h_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, Ix1>,
+    ) -> Result<ArrayBase<S, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_h_banded_into(b)?;
+        Ok(flatten(b))
+    }
+}
+
+impl<A> SolveBanded<A, Ix1> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_banded_into(b)
+    }
+    fn solve_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let f = self.factorize_banded()?;
+        let b = f.solve_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_t_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_t_banded_into(b)
+    }
+    fn solve_t_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let f = self.factorize_banded()?;
+        let b = f.solve_t_banded_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_h_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_h_banded_into(b)
+    }
+    fn solve_h_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let f = self.factorize_banded()?;
+        let b = f.solve_h_banded_into(b)?;
+        Ok(flatten(b))
+    }
+}
+
+/// An interface for computing LU factorizations of banded matrix refs.
+pub trait FactorizeBanded<A: Scalar> {
+    /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
+    /// matrix.
+    fn factorize_banded(&self) -> Result<LUFactorizedBanded<A>>;
+}
+
+/// An interface for computing LU factorizations of banded matrices.
+pub trait FactorizeBandedInto<A: Scalar> {
+    /// Computes the LU factorization `A = P*L*U`, where `P` is a permutation
+    /// matrix.
+    fn factorize_banded_into(self) -> Result<LUFactorizedBanded<A>>;
+}
+
+impl<A> FactorizeBandedInto<A> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorize_banded_into(self) -> Result<LUFactorizedBanded<A>> {
+        self.factorize_banded()
+    }
+}
+
+impl<A> FactorizeBanded<A> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorize_banded(&self) -> Result<LUFactorizedBanded<A>> {
+        Ok(A::lu_banded(self)?)
+    }
+}
+
+/// An interface for *estimating* the reciprocal condition number of banded matrix refs.
+pub trait ReciprocalConditionNumBanded<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the banded matrix in
+    /// 1-norm.
+    ///
+    /// This method uses the LAPACK `*gbcon` routines, which *estimate*
+    /// `self.inv().opnorm_one()` and then compute `rcond = 1. /
+    /// (self.opnorm_one() * self.inv().opnorm_one())`.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcond_banded(&self) -> Result<A::Real>;
+}
+
+/// An interface for *estimating* the reciprocal condition number of banded matrices.
+pub trait ReciprocalConditionNumBandedInto<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the banded matrix in
+    /// 1-norm.
+    ///
+    /// This method uses the LAPACK `*gbcon` routines, which *estimate*
+    /// `self.inv().opnorm_one()` and then compute `rcond = 1. /
+    /// (self.opnorm_one() * self.inv().opnorm_one())`.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcond_banded_into(self) -> Result<A::Real>;
+}
+
+impl<A> ReciprocalConditionNumBanded<A> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcond_banded(&self) -> Result<A::Real> {
+        Ok(A::rcond_banded(self)?)
+    }
+}
+
+impl<A> ReciprocalConditionNumBandedInto<A> for LUFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcond_banded_into(self) -> Result<A::Real> {
+        self.rcond_banded()
+    }
+}
+
+impl<A> ReciprocalConditionNumBanded<A> for Banded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcond_banded(&self) -> Result<A::Real> {
+        self.factorize_banded()?.rcond_banded_into()
+    }
+}
+
+/// An interface for making a BandedHermite struct.
+pub trait ExtractBandedHermite<A: Scalar> {
+    /// Extract the elements in the band of `kd` super-diagonals (sub-diagonals)
+    /// in the triangle specified by `uplo` and layout of the raw matrix.
+    ///
+    /// The elements in the other triangle and outside the band will be ignored.
+    fn extract_banded_hermite(&self, uplo: UPLO, kd: usize) -> Result<BandedHermite<A>>;
+}
+
+impl<A, S> ExtractBandedHermite<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn extract_banded_hermite(&self, uplo: UPLO, kd: usize) -> Result<BandedHermite<A>> {
+        let l = self.square_layout()?;
+        let n = self.nrows();
+        let mut a = BandedHermite::zeros(l, uplo, kd as i32);
+        for j in 0..n {
+            let rows = match uplo {
+                UPLO::Upper => j.saturating_sub(kd)..j + 1,
+                UPLO::Lower => j..n.min(j + kd + 1),
+            };
+            for i in rows {
+                a[(i as i32, j as i32)] = self[(i, j)];
+            }
+        }
+        Ok(a)
+    }
+}
+
+/// An interface for computing Cholesky factorizations of hermite banded matrix refs.
+pub trait FactorizeCBanded<A: Scalar> {
+    /// Computes the Cholesky factorization `A = U^H * U` or `A = L * L^H`
+    /// according to `uplo` of the banded matrix.
+    fn factorizec_banded(&self) -> Result<CholeskyFactorizedBanded<A>>;
+}
+
+/// An interface for computing Cholesky factorizations of hermite banded matrices.
+pub trait FactorizeCBandedInto<A: Scalar> {
+    /// Computes the Cholesky factorization `A = U^H * U` or `A = L * L^H`
+    /// according to `uplo` of the banded matrix.
+    fn factorizec_banded_into(self) -> Result<CholeskyFactorizedBanded<A>>;
+}
+
+impl<A> FactorizeCBanded<A> for BandedHermite<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizec_banded(&self) -> Result<CholeskyFactorizedBanded<A>> {
+        Ok(A::cholesky_banded(self)?)
+    }
+}
+
+impl<A> FactorizeCBandedInto<A> for BandedHermite<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizec_banded_into(self) -> Result<CholeskyFactorizedBanded<A>> {
+        self.factorizec_banded()
+    }
+}
+
+/// Solve systems of linear equations with hermite (or real symmetric)
+/// positive definite banded coefficient matrices
+pub trait SolveCBanded<A: Scalar, D: Dimension> {
+    /// Solves a system of linear equations `A * x = b` with hermite (or real symmetric)
+    /// positive definite banded matrix `A`, where `A` is `self`, `b` is the argument,
+    /// and `x` is the successful result.
+    fn solvec_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>>;
+    /// Solves a system of linear equations `A * x = b` with hermite (or real symmetric)
+    /// positive definite banded matrix `A`, where `A` is `self`, `b` is the argument,
+    /// and `x` is the successful result.
+    fn solvec_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, D>,
+    ) -> Result<ArrayBase<S, D>>;
+}
+
+pub trait SolveCBandedInplace<A: Scalar, D: Dimension> {
+    /// Solves a system of linear equations `A * x = b` with hermite (or real symmetric)
+    /// positive definite banded matrix `A`, where `A` is `self`, `b` is the argument,
+    /// and `x` is the successful result. The value of `x` is also assigned to the
+    /// argument.
+    fn solvec_banded_inplace<'a, S: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<S, D>,
+    ) -> Result<&'a mut ArrayBase<S, D>>;
+}
+
+impl<A> SolveCBanded<A, Ix2> for CholeskyFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solvec_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solvec_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solvec_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveCBanded<A, Ix2> for BandedHermite<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solvec_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solvec_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<Sb, Ix2>,
+    ) -> Result<ArrayBase<Sb, Ix2>> {
+        self.solvec_banded_inplace(&mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveCBandedInplace<A, Ix2> for CholeskyFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        A::solve_cholesky_banded(self, rhs.layout()?, rhs.as_slice_mut().unwrap())?;
+        Ok(rhs)
+    }
+}
+
+impl<A> SolveCBandedInplace<A, Ix2> for BandedHermite<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_banded_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let f = self.factorizec_banded()?;
+        f.solvec_banded_inplace(rhs)
+    }
+}
+
+impl<A> SolveCBanded<A, Ix1> for CholeskyFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_banded<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solvec_banded_into(b)
+    }
+    fn solvec_banded_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, Ix1>,
+    ) -> Result<ArrayBase<S, Ix1>> {
+        let b = into_col(b);
+        let b = self.solvec_banded_into(b)?;
+        Ok(flatten(b))
+    }
+}
+
+impl<A> SolveCBanded<A, Ix1> for BandedHermite<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_banded<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solvec_banded_into(b)
+    }
+    fn solvec_banded_into<Sb: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<Sb, Ix1>,
+    ) -> Result<ArrayBase<Sb, Ix1>> {
+        let b = into_col(b);
+        let f = self.factorizec_banded()?;
+        let b = f.solvec_banded_into(b)?;
+        Ok(flatten(b))
+    }
+}
+
+/// An interface for *estimating* the reciprocal condition number of hermite positive definite
+/// banded matrix refs.
+pub trait ReciprocalConditionNumCBanded<A: Scalar> {
+    /// *Estimates* the reciprocal of the condition number of the banded matrix in
+    /// 1-norm.
+    ///
+    /// This method uses the LAPACK `*pbcon` routines, which *estimate*
+    /// `self.inv().opnorm_one()` and then compute `rcond = 1. /
+    /// (self.opnorm_one() * self.inv().opnorm_one())`.
+    ///
+    /// * If `rcond` is near `0.`, the matrix is badly conditioned.
+    /// * If `rcond` is near `1.`, the matrix is well conditioned.
+    fn rcondc_banded(&self) -> Result<A::Real>;
+}
+
+impl<A> ReciprocalConditionNumCBanded<A> for CholeskyFactorizedBanded<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcondc_banded(&self) -> Result<A::Real> {
+        Ok(A::rcond_cholesky_banded(self)?)
+    }
+}
+
+impl<A> ReciprocalConditionNumCBanded<A> for BandedHermite<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcondc_banded(&self) -> Result<A::Real> {
+        self.factorizec_banded()?.rcondc_banded()
+    }
+}
+
+/// Eigenvalue problem for hermite (or real symmetric) banded matrix
+///
+/// Eigenvalues are returned in ascending order,
+/// and eigenvectors are returned as the columns of the matrix.
+pub trait EighBanded<A: Scalar> {
+    /// Compute all eigenvalues and eigenvectors by divide and conquer method
+    fn eigh_banded(&self) -> Result<(Array1<A::Real>, Array2<A>)>;
+
+    /// Compute all eigenvalues
+    fn eigvalsh_banded(&self) -> Result<Array1<A::Real>>;
+}
+
+impl<A> EighBanded<A> for BandedHermite<A>
+where
+    A: Scalar + Lapack,
+{
+    fn eigh_banded(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let n = self.l.len() as usize;
+        let res = A::eigh_banded(true, self)?;
+        let z = Array2::from_shape_vec((n, n).f(), res.z.unwrap()).unwrap();
+        Ok((ArrayBase::from(res.eigs), z))
+    }
+
+    fn eigvalsh_banded(&self) -> Result<Array1<A::Real>> {
+        let res = A::eigh_banded(false, self)?;
+        Ok(ArrayBase::from(res.eigs))
+    }
+}
@@ -0,0 +1,140 @@
+//! Bidiagonal reduction
+//!
+//! For a `m x n` matrix `A`, this computes `A = U B V^H`
+//! where `U` is `m x k` and `V^H` is `k x n` with `k = min(m, n)`, both with orthonormal columns/rows,
+//! and `B` is a `k x k` real bidiagonal matrix.
+//! `B` is upper bidiagonal if `m >= n`, and lower bidiagonal otherwise.
+//!
+//! This is the first step of the singular value decomposition,
+//! and the singular values of `A` are those of `B`.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [1.0, 2.0, 3.0],
+//!     [4.0, 5.0, 6.0],
+//!     [7.0, 8.0, 0.0],
+//!     [1.0, 0.0, 1.0],
+//! ];
+//! let (u, d, e, vt) = a.bidiagonalize(true, true).unwrap();
+//! let (u, vt) = (u.unwrap(), vt.unwrap());
+//! // B is upper bidiagonal since m >= n
+//! let mut b = Array2::from_diag(&d);
+//! for i in 0..e.len() {
+//!     b[(i, i + 1)] = e[i];
+//! }
+//! assert_close_l2!(&u.dot(&b).dot(&vt), &a, 1e-7);
+//! ```
+
+use crate::{convert::*, error::*, layout::*, types::*};
+use ndarray::*;
+
+/// Bidiagonal reduction of matrix reference
+pub trait Bidiagonalize {
+    type U;
+    type VT;
+    type D;
+    type E;
+    fn bidiagonalize(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::D, Self::E, Option<Self::VT>)>;
+}
+
+/// Bidiagonal reduction
+pub trait BidiagonalizeInto {
+    type U;
+    type VT;
+    type D;
+    type E;
+    fn bidiagonalize_into(
+        self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::D, Self::E, Option<Self::VT>)>;
+}
+
+/// Bidiagonal reduction for mutable reference of matrix
+///
+/// The matrix is destroyed.
+pub trait BidiagonalizeInplace {
+    type U;
+    type VT;
+    type D;
+    type E;
+    fn bidiagonalize_inplace(
+        &mut self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::D, Self::E, Option<Self::VT>)>;
+}
+
+impl<A, S> BidiagonalizeInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type D = Array1<A::Real>;
+    type E = Array1<A::Real>;
+
+    fn bidiagonalize_into(
+        mut self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::D, Self::E, Option<Self::VT>)> {
+        self.bidiagonalize_inplace(calc_u, calc_vt)
+    }
+}
+
+impl<A, S> Bidiagonalize for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type D = Array1<A::Real>;
+    type E = Array1<A::Real>;
+
+    fn bidiagonalize(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::D, Self::E, Option<Self::VT>)> {
+        let a = self.to_owned();
+        a.bidiagonalize_into(calc_u, calc_vt)
+    }
+}
+
+impl<A, S> BidiagonalizeInplace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type D = Array1<A::Real>;
+    type E = Array1<A::Real>;
+
+    fn bidiagonalize_inplace(
+        &mut self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::D, Self::E, Option<Self::VT>)> {
+        let l = self.layout()?;
+        let res = A::bidiagonal(l, calc_u, calc_vt, self.as_allocated_mut()?)?;
+        let (m, n) = l.size();
+        let k = m.min(n);
+
+        let u = res.u.map(|u| into_matrix(l.resized(m, k), u).unwrap());
+        let vt = res.vt.map(|vt| into_matrix(l.resized(k, n), vt).unwrap());
+        Ok((u, ArrayBase::from(res.d), ArrayBase::from(res.e), vt))
+    }
+}
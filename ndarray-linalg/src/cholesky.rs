@@ -466,3 +466,100 @@ where
         Ok(self.factorizec_into(UPLO::Upper)?.ln_detc_into())
     }
 }
+
+/// Cholesky decomposition with complete pivoting of Hermitian (or real
+/// symmetric) positive semi-definite matrix reference
+///
+/// If the argument is `UPLO::Upper`, this computes `P^T A P = U^H * U`
+/// using the upper triangular portion of `A`, and otherwise `P^T A P = L * L^H`
+/// using the lower triangular portion, where `P` is a permutation matrix.
+/// The factorization stops when the largest remaining diagonal element is not
+/// greater than `tol`, and the number of computed steps is returned as the rank `r`.
+/// A negative `tol` is replaced by `n * eps * max(A[i, i])`.
+///
+/// The factor is truncated to the leading `r` rows of `U` (`r x n`) or
+/// the leading `r` columns of `L` (`n x r`).
+/// The permutation is returned as an array of indices,
+/// i.e. `A.select(Axis(0), &p).select(Axis(1), &p) = U^H U` up to the truncated part.
+pub trait CholeskyPivoted<A: Scalar> {
+    type Output;
+    type P;
+    fn cholesky_pivoted(&self, uplo: UPLO, tol: A::Real) -> Result<(Self::Output, Self::P, usize)>;
+}
+
+/// Cholesky decomposition with complete pivoting of Hermitian (or real
+/// symmetric) positive semi-definite matrix
+///
+/// See [CholeskyPivoted] for detail.
+pub trait CholeskyPivotedInto<A: Scalar>: Sized {
+    type Output;
+    type P;
+    fn cholesky_pivoted_into(
+        self,
+        uplo: UPLO,
+        tol: A::Real,
+    ) -> Result<(Self::Output, Self::P, usize)>;
+}
+
+impl<A, S> CholeskyPivotedInto<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Output = Array2<A>;
+    type P = Array1<usize>;
+
+    fn cholesky_pivoted_into(
+        mut self,
+        uplo: UPLO,
+        tol: A::Real,
+    ) -> Result<(Self::Output, Self::P, usize)> {
+        let n = self.nrows();
+        let (piv, rank) =
+            A::cholesky_pivoted(self.square_layout()?, uplo, tol, self.as_allocated_mut()?)?;
+        let r = rank as usize;
+        let factor = match uplo {
+            UPLO::Upper => {
+                Array2::from_shape_fn(
+                    (r, n),
+                    |(i, j)| {
+                        if i <= j {
+                            self[(i, j)]
+                        } else {
+                            A::zero()
+                        }
+                    },
+                )
+            }
+            UPLO::Lower => {
+                Array2::from_shape_fn(
+                    (n, r),
+                    |(i, j)| {
+                        if i >= j {
+                            self[(i, j)]
+                        } else {
+                            A::zero()
+                        }
+                    },
+                )
+            }
+        };
+        // LAPACK returns 1-based index
+        let p = piv.into_iter().map(|j| j as usize - 1).collect();
+        Ok((factor, p, r))
+    }
+}
+
+impl<A, S> CholeskyPivoted<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+    type P = Array1<usize>;
+
+    fn cholesky_pivoted(&self, uplo: UPLO, tol: A::Real) -> Result<(Self::Output, Self::P, usize)> {
+        let a: Array2<A> = replicate(self);
+        a.cholesky_pivoted_into(uplo, tol)
+    }
+}
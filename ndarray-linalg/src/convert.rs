@@ -1,6 +1,6 @@
 //! utilities for convert array
 
-use lax::UPLO;
+use lax::{Packed, RectangularFullPacked, UPLO};
 use ndarray::*;
 
 use super::error::*;
@@ -142,3 +142,47 @@ where
         }
     }
 }
+
+/// Pack the triangle specified by `uplo` of a square matrix
+/// into [Packed] storage.
+pub fn to_packed<A, S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> Result<Packed<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let l = a.square_layout()?;
+    Ok(A::to_packed(l, uplo, a.as_allocated()?)?)
+}
+
+/// Unpack [Packed] storage into a full Hermitian matrix.
+pub fn from_packed<A>(a: &Packed<A>) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+{
+    let (n, _) = a.l.size();
+    let mut full = into_matrix(MatrixLayout::F { col: n, lda: n }, A::from_packed(a)?)?;
+    triangular_fill_hermitian(&mut full, a.uplo);
+    Ok(full)
+}
+
+/// Rearrange the triangle specified by `uplo` of a square matrix
+/// into [RectangularFullPacked] storage.
+pub fn to_rfp<A, S>(a: &ArrayBase<S, Ix2>, uplo: UPLO) -> Result<RectangularFullPacked<A>>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let l = a.square_layout()?;
+    Ok(A::to_rfp(l, uplo, a.as_allocated()?)?)
+}
+
+/// Unpack [RectangularFullPacked] storage into a full Hermitian matrix.
+pub fn from_rfp<A>(a: &RectangularFullPacked<A>) -> Result<Array2<A>>
+where
+    A: Scalar + Lapack,
+{
+    let (n, _) = a.l.size();
+    let mut full = into_matrix(MatrixLayout::F { col: n, lda: n }, A::from_rfp(a)?)?;
+    triangular_fill_hermitian(&mut full, a.uplo);
+    Ok(full)
+}
@@ -58,6 +58,47 @@ where
     }
 }
 
+impl<A, S, S2> Eig for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    /// Pair of `(alpha, beta)` where the eigenvalues are `alpha / beta`
+    type EigVal = (Array1<A::Complex>, Array1<A>);
+    type EigVec = Array2<A::Complex>;
+
+    /// Solves the generalized eigenvalue problem `A v = λ B v`.
+    ///
+    /// The eigenvalues are returned as pairs `(alpha, beta)` with `λ = alpha / beta`,
+    /// so that infinite eigenvalues for singular `B` are represented by `beta = 0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn eig(&self) -> Result<(Self::EigVal, Self::EigVec)> {
+        assert_eq!(
+            self.0.shape(),
+            self.1.shape(),
+            "The shapes of the matrices must be identical.",
+        );
+        // Both matrices must be in the same layout
+        let mut a = Array2::zeros(self.0.dim().f());
+        a.assign(&self.0);
+        let mut b = Array2::zeros(self.1.dim().f());
+        b.assign(&self.1);
+        let layout = a.square_layout()?;
+        let lax::eig_generalized::GeneralizedEigOwned {
+            alpha, beta, vr, ..
+        } = A::eig_generalized(true, layout, a.as_allocated_mut()?, b.as_allocated_mut()?)?;
+        let n = layout.len() as usize;
+        Ok((
+            (ArrayBase::from(alpha), ArrayBase::from(beta)),
+            Array2::from_shape_vec((n, n).f(), vr.unwrap()).unwrap(),
+        ))
+    }
+}
+
 /// Calculate eigenvalues without eigenvectors
 pub trait EigVals {
     type EigVal;
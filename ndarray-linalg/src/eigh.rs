@@ -35,12 +35,15 @@
 
 use ndarray::*;
 
+use crate::convert::*;
 use crate::diagonal::*;
 use crate::error::*;
 use crate::layout::*;
 use crate::operator::LinearOperator;
 use crate::types::*;
-use crate::UPLO;
+use crate::{EigRange, UPLO};
+
+pub use lax::EighDriver;
 
 /// Eigenvalue decomposition of Hermite matrix reference
 pub trait Eigh {
@@ -125,14 +128,7 @@ where
     type EigVal = Array1<A::Real>;
 
     fn eigh_inplace(&mut self, uplo: UPLO) -> Result<(Self::EigVal, &mut Self)> {
-        let layout = self.square_layout()?;
-        // XXX Force layout to be Fortran (see #146)
-        match layout {
-            MatrixLayout::C { .. } => self.swap_axes(0, 1),
-            MatrixLayout::F { .. } => {}
-        }
-        let s = A::eigh(true, self.square_layout()?, uplo, self.as_allocated_mut()?)?;
-        Ok((ArrayBase::from(s), self))
+        self.eigh_inplace_with(uplo, EighDriver::default())
     }
 }
 
@@ -236,6 +232,166 @@ where
     }
 }
 
+/// Eigenvalue decomposition of Hermite matrix reference using the specified driver
+///
+/// [Eigh] is equivalent to this with [EighDriver::Qr].
+pub trait EighWith {
+    type EigVal;
+    type EigVec;
+    fn eigh_with(&self, uplo: UPLO, driver: EighDriver) -> Result<(Self::EigVal, Self::EigVec)>;
+}
+
+/// Eigenvalue decomposition of mutable reference of Hermite matrix using the specified driver
+pub trait EighInplaceWith {
+    type EigVal;
+    fn eigh_inplace_with(
+        &mut self,
+        uplo: UPLO,
+        driver: EighDriver,
+    ) -> Result<(Self::EigVal, &mut Self)>;
+}
+
+/// Eigenvalue decomposition of Hermite matrix using the specified driver
+pub trait EighIntoWith: Sized {
+    type EigVal;
+    fn eigh_into_with(self, uplo: UPLO, driver: EighDriver) -> Result<(Self::EigVal, Self)>;
+}
+
+/// Calculate eigenvalues without eigenvectors using the specified driver
+pub trait EigValshWith {
+    type EigVal;
+    fn eigvalsh_with(&self, uplo: UPLO, driver: EighDriver) -> Result<Self::EigVal>;
+}
+
+impl<A, S> EighInplaceWith for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+
+    fn eigh_inplace_with(
+        &mut self,
+        uplo: UPLO,
+        driver: EighDriver,
+    ) -> Result<(Self::EigVal, &mut Self)> {
+        let layout = self.square_layout()?;
+        // XXX Force layout to be Fortran (see #146)
+        match layout {
+            MatrixLayout::C { .. } => self.swap_axes(0, 1),
+            MatrixLayout::F { .. } => {}
+        }
+        let s = A::eigh_with_driver(
+            true,
+            self.square_layout()?,
+            uplo,
+            driver,
+            self.as_allocated_mut()?,
+        )?;
+        // Swapped matrix is the complex conjugate of the original hermite matrix
+        if let MatrixLayout::C { .. } = layout {
+            self.map_inplace(|x| *x = x.conj());
+        }
+        Ok((ArrayBase::from(s), self))
+    }
+}
+
+impl<A, S> EighIntoWith for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+
+    fn eigh_into_with(mut self, uplo: UPLO, driver: EighDriver) -> Result<(Self::EigVal, Self)> {
+        let (val, _) = self.eigh_inplace_with(uplo, driver)?;
+        Ok((val, self))
+    }
+}
+
+impl<A, S> EighWith for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+    type EigVec = Array2<A>;
+
+    fn eigh_with(&self, uplo: UPLO, driver: EighDriver) -> Result<(Self::EigVal, Self::EigVec)> {
+        let a = self.to_owned();
+        a.eigh_into_with(uplo, driver)
+    }
+}
+
+impl<A, S> EigValshWith for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type EigVal = Array1<A::Real>;
+
+    fn eigvalsh_with(&self, uplo: UPLO, driver: EighDriver) -> Result<Self::EigVal> {
+        let mut a = self.to_owned();
+        let s = A::eigh_with_driver(
+            false,
+            a.square_layout()?,
+            uplo,
+            driver,
+            a.as_allocated_mut()?,
+        )?;
+        Ok(ArrayBase::from(s))
+    }
+}
+
+/// Eigenvalue decomposition of Hermite matrix reference for selected eigenvalues
+///
+/// Only the eigenvalues selected by [EigRange] and their eigenvectors are computed,
+/// which is much cheaper than [Eigh] when a small part of the spectrum is needed.
+pub trait EighRange<A: Scalar> {
+    fn eigh_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)>;
+}
+
+/// Calculate selected eigenvalues without eigenvectors
+pub trait EigValshRange<A: Scalar> {
+    fn eigvalsh_range(&self, uplo: UPLO, range: EigRange<A::Real>) -> Result<Array1<A::Real>>;
+}
+
+impl<A, S> EighRange<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn eigh_range(
+        &self,
+        uplo: UPLO,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let mut a = self.to_owned();
+        let l = a.square_layout()?;
+        let res = A::eigh_range(true, l, uplo, range, a.as_allocated_mut()?)?;
+        let m = res.eigs.len() as i32;
+        let v = into_matrix(l.resized(l.len(), m), res.z.unwrap())?;
+        Ok((ArrayBase::from(res.eigs), v))
+    }
+}
+
+impl<A, S> EigValshRange<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn eigvalsh_range(&self, uplo: UPLO, range: EigRange<A::Real>) -> Result<Array1<A::Real>> {
+        let mut a = self.to_owned();
+        let l = a.square_layout()?;
+        let res = A::eigh_range(false, l, uplo, range, a.as_allocated_mut()?)?;
+        Ok(ArrayBase::from(res.eigs))
+    }
+}
+
 /// Calculate symmetric square-root matrix using `eigh`
 pub trait SymmetricSqrt {
     type Output;
@@ -0,0 +1,153 @@
+//! Generalized singular-value decomposition (GSVD) for pairs of general matrices
+//!
+//! For a `m x n` matrix `A` and a `p x n` matrix `B`, this computes
+//! `U^H A Q = D1 (0 R)` and `V^H B Q = D2 (0 R)`
+//! where `U`, `V` and `Q` are unitary (orthogonal for real matrices),
+//! `R` is a `(k + l) x (k + l)` non-singular upper triangular matrix,
+//! and `D1`, `D2` are "diagonal" matrices consisting of `C = diag(alpha)` and `S = diag(beta)`
+//! with `C^2 + S^2 = I`.
+//! See [GSVDDecomposition::d1] and [GSVDDecomposition::d2] for their shapes.
+//!
+//! The generalized singular values of `(A, B)` are `alpha / beta`.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [1.0, 2.0, 3.0],
+//!     [4.0, 5.0, 6.0],
+//!     [7.0, 8.0, 0.0],
+//!     [1.0, 0.0, 1.0],
+//! ];
+//! // first-order derivative operator
+//! let b: Array2<f64> = array![
+//!     [1.0, -1.0, 0.0],
+//!     [0.0, 1.0, -1.0],
+//! ];
+//! let gsvd = (a.clone(), b.clone()).gsvd().unwrap();
+//! let n = a.ncols();
+//! let r = gsvd.r.nrows();
+//! // (0 R) Q^H
+//! let mut zr = Array2::zeros((r, n));
+//! zr.slice_mut(s![.., n - r..]).assign(&gsvd.r);
+//! let zrq = zr.dot(&gsvd.q.t());
+//! assert_close_l2!(&gsvd.u.dot(&gsvd.d1()).dot(&zrq), &a, 1e-7);
+//! assert_close_l2!(&gsvd.v.dot(&gsvd.d2()).dot(&zrq), &b, 1e-7);
+//! ```
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Result of generalized singular value decomposition
+#[derive(Debug, Clone)]
+pub struct GSVDDecomposition<E: Scalar> {
+    /// Unitary matrix `U` for `A`
+    pub u: Array2<E>,
+    /// Unitary matrix `V` for `B`
+    pub v: Array2<E>,
+    /// Unitary matrix `Q`
+    pub q: Array2<E>,
+    /// Upper triangular matrix `R`
+    pub r: Array2<E>,
+    /// Diagonal values of `C` with the leading `k` ones
+    pub alpha: Array1<E::Real>,
+    /// Diagonal values of `S` with the leading `k` zeros
+    pub beta: Array1<E::Real>,
+    /// Number of rows of `R` coupled only to `A`
+    pub k: usize,
+    /// Number of rows of `R` coupled to both `A` and `B`.
+    /// `k + l` is the effective numerical rank of `(A^H, B^H)^H`.
+    pub l: usize,
+}
+
+impl<E: Scalar> GSVDDecomposition<E> {
+    /// `m x (k + l)` matrix `D1` satisfying `U^H A Q = D1 (0 R)`
+    ///
+    /// `D1[(i, i)] = alpha[i]` for `i < min(m, k + l)`, and the other elements are zero.
+    pub fn d1(&self) -> Array2<E> {
+        let m = self.u.nrows();
+        let r = self.k + self.l;
+        let mut d1 = Array2::zeros((m, r));
+        for i in 0..m.min(r) {
+            d1[(i, i)] = E::from_real(self.alpha[i]);
+        }
+        d1
+    }
+
+    /// `p x (k + l)` matrix `D2` satisfying `V^H B Q = D2 (0 R)`
+    ///
+    /// `D2[(i - k, i)] = beta[i]` for `k <= i < k + l`, and the other elements are zero.
+    pub fn d2(&self) -> Array2<E> {
+        let p = self.v.nrows();
+        let r = self.k + self.l;
+        let mut d2 = Array2::zeros((p, r));
+        for i in self.k..r {
+            d2[(i - self.k, i)] = E::from_real(self.beta[i]);
+        }
+        d2
+    }
+}
+
+/// Generalized singular value decomposition of a pair of general matrices
+pub trait GSVD {
+    type Elem: Scalar;
+
+    /// Compute the generalized singular value decomposition
+    fn gsvd(&self) -> Result<GSVDDecomposition<Self::Elem>>;
+}
+
+impl<A, S, S2> GSVD for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type Elem = A;
+
+    /// # Panics
+    ///
+    /// Panics if the numbers of columns of the matrices are different.
+    fn gsvd(&self) -> Result<GSVDDecomposition<A>> {
+        let mut a = self.0.to_owned();
+        let mut b = self.1.to_owned();
+        let la = a.layout()?;
+        let lb = b.layout()?;
+        let lax::GSVDOutput {
+            k,
+            l,
+            alpha,
+            beta,
+            u,
+            v,
+            q,
+            r,
+        } = A::gsvd(
+            la,
+            lb,
+            true,
+            true,
+            true,
+            a.as_allocated_mut()?,
+            b.as_allocated_mut()?,
+        )?;
+        let (m, n) = la.size();
+        let (p, _) = lb.size();
+        Ok(GSVDDecomposition {
+            u: into_matrix(la.resized(m, m), u.unwrap())?,
+            v: into_matrix(la.resized(p, p), v.unwrap())?,
+            q: into_matrix(la.resized(n, n), q.unwrap())?,
+            r: into_matrix(la.resized(k + l, k + l), r)?,
+            alpha: ArrayBase::from(alpha),
+            beta: ArrayBase::from(beta),
+            k: k as usize,
+            l: l as usize,
+        })
+    }
+}
@@ -0,0 +1,108 @@
+//! Hessenberg reduction of general square matrices
+//!
+//! For a square matrix `A`, this computes `A = Q H Q^H`
+//! where `Q` is unitary (orthogonal for real matrices)
+//! and `H` is upper Hessenberg, i.e. `H[i, j] = 0` for `i > j + 1`.
+//!
+//! This is the same form as [krylov::H](crate::krylov::H) produced by the Arnoldi iteration,
+//! but computed by Householder reflections on the whole matrix.
+//! Since `A - σI = Q (H - σI) Q^H`, the Hessenberg form can be reused
+//! for shifted linear equations with many shifts `σ`.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [1.0, 2.0, 3.0, 4.0],
+//!     [5.0, 6.0, 7.0, 8.0],
+//!     [9.0, 1.0, 2.0, 3.0],
+//!     [4.0, 5.0, 6.0, 0.0],
+//! ];
+//! let (q, h) = a.hessenberg().unwrap();
+//! assert_eq!(h[(3, 0)], 0.0);
+//! assert_close_l2!(&q.dot(&h).dot(&q.t()), &a, 1e-7);
+//! ```
+
+use ndarray::*;
+
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Hessenberg reduction for square matrix reference
+pub trait Hessenberg {
+    type Q;
+    type H;
+    fn hessenberg(&self) -> Result<(Self::Q, Self::H)>;
+}
+
+/// Hessenberg reduction for square matrix
+///
+/// The input matrix is overwritten by `H`.
+pub trait HessenbergInto: Sized {
+    type Q;
+    fn hessenberg_into(self) -> Result<(Self::Q, Self)>;
+}
+
+/// Hessenberg reduction for mutable reference of square matrix
+///
+/// The input matrix is overwritten by `H`.
+pub trait HessenbergInplace {
+    type Q;
+    fn hessenberg_inplace(&mut self) -> Result<(Self::Q, &mut Self)>;
+}
+
+impl<A, S> HessenbergInplace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Q = Array2<A>;
+
+    fn hessenberg_inplace(&mut self) -> Result<(Self::Q, &mut Self)> {
+        let l = self.square_layout()?;
+        let tau = A::hessenberg(l, self.as_allocated_mut()?)?;
+
+        let mut q = self.to_owned();
+        let lq = q.square_layout()?;
+        A::hessenberg_q(lq, q.as_allocated_mut()?, &tau)?;
+
+        // Clear Householder reflectors stored below the first subdiagonal
+        Zip::indexed(&mut *self).for_each(|(i, j), elt| {
+            if i > j + 1 {
+                *elt = A::zero()
+            }
+        });
+        Ok((q, self))
+    }
+}
+
+impl<A, S> HessenbergInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Q = Array2<A>;
+
+    fn hessenberg_into(mut self) -> Result<(Self::Q, Self)> {
+        let (q, _) = self.hessenberg_inplace()?;
+        Ok((q, self))
+    }
+}
+
+impl<A, S> Hessenberg for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type H = Array2<A>;
+
+    fn hessenberg(&self) -> Result<(Self::Q, Self::H)> {
+        let a = self.to_owned();
+        a.hessenberg_into()
+    }
+}
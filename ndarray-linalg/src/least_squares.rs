@@ -21,6 +21,16 @@
 //! All methods use the Lapacke family of methods `*gelsd` which solves the least
 //! squares problem using the SVD with a divide-and-conquer strategy.
 //!
+//! `LeastSquaresQrPivoted`, `LeastSquaresQrPivotedInto` and `LeastSquaresQrPivotedInPlace`
+//! provide the same interfaces using `*gelsy`, which solves the problem using
+//! a complete orthogonal decomposition and is usually faster than `*gelsd`.
+//! `LeastSquaresQr`, `LeastSquaresQrInto` and `LeastSquaresQrInPlace` use `*gels`,
+//! which is the cheapest one but assumes that `A` has full rank.
+//!
+//! Generalized problems are also supported:
+//! `LeastSquaresEqualityConstrained` solves the least squares problem with linear equality constraints,
+//! and `GeneralLinearModel` solves the general Gauss-Markov linear model problem.
+//!
 //! The traits are implemented for value types `f32`, `f64`, `c32` and `c64`
 //! and vector or matrix right-hand-sides (`ArrayBase<S, Ix1>` or `ArrayBase<S, Ix2>`).
 //!
@@ -62,6 +72,7 @@
 
 use lax::*;
 use ndarray::*;
+use num_traits::Float;
 
 use crate::error::*;
 use crate::layout::*;
@@ -272,42 +283,70 @@ where
         &mut self,
         rhs: &mut ArrayBase<D2, Ix1>,
     ) -> Result<LeastSquaresResult<E, Ix1>> {
-        if self.shape()[0] != rhs.shape()[0] {
-            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
-        }
-        let (m, n) = (self.shape()[0], self.shape()[1]);
-        if n > m {
-            // we need a new rhs b/c it will be overwritten with the solution
-            // for which we need `n` entries
-            let mut new_rhs = Array1::<E>::zeros((n,));
-            new_rhs.slice_mut(s![0..m]).assign(rhs);
-            compute_least_squares_srhs(self, &mut new_rhs)
-        } else {
-            compute_least_squares_srhs(self, rhs)
-        }
+        least_squares_srhs_in_place(self, Transpose::No, rhs, |l, a, b| {
+            Ok(E::least_squares(l, a, b)?)
+        })
+    }
+}
+
+/// Shape of `op(A)` specified by `t`
+fn op_shape<S: Data>(a: &ArrayBase<S, Ix2>, t: Transpose) -> (usize, usize) {
+    match t {
+        Transpose::No => (a.shape()[0], a.shape()[1]),
+        Transpose::Transpose | Transpose::Hermite => (a.shape()[1], a.shape()[0]),
+    }
+}
+
+fn least_squares_srhs_in_place<E, D1, D2, F>(
+    a: &mut ArrayBase<D1, Ix2>,
+    t: Transpose,
+    rhs: &mut ArrayBase<D2, Ix1>,
+    driver: F,
+) -> Result<LeastSquaresResult<E, Ix1>>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+    F: FnOnce(MatrixLayout, &mut [E], &mut [E]) -> Result<LeastSquaresOutput<E>>,
+{
+    let (m, n) = op_shape(a, t);
+    if m != rhs.shape()[0] {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if n > m {
+        // we need a new rhs b/c it will be overwritten with the solution
+        // for which we need `n` entries
+        let mut new_rhs = Array1::<E>::zeros((n,));
+        new_rhs.slice_mut(s![0..m]).assign(rhs);
+        compute_least_squares_srhs(a, t, &mut new_rhs, driver)
+    } else {
+        compute_least_squares_srhs(a, t, rhs, driver)
     }
 }
 
-fn compute_least_squares_srhs<E, D1, D2>(
+fn compute_least_squares_srhs<E, D1, D2, F>(
     a: &mut ArrayBase<D1, Ix2>,
+    t: Transpose,
     rhs: &mut ArrayBase<D2, Ix1>,
+    driver: F,
 ) -> Result<LeastSquaresResult<E, Ix1>>
 where
     E: Scalar + Lapack,
     D1: DataMut<Elem = E>,
     D2: DataMut<Elem = E>,
+    F: FnOnce(MatrixLayout, &mut [E], &mut [E]) -> Result<LeastSquaresOutput<E>>,
 {
     let LeastSquaresOutput::<E> {
         singular_values,
         rank,
-    } = E::least_squares(
+    } = driver(
         a.layout()?,
         a.as_allocated_mut()?,
         rhs.as_slice_memory_order_mut()
             .ok_or(LinalgError::MemoryNotCont)?,
     )?;
 
-    let (m, n) = (a.shape()[0], a.shape()[1]);
+    let (m, n) = op_shape(a, t);
     let solution = rhs.slice(s![0..n]).to_owned();
     let residual_sum_of_squares = compute_residual_scalar(m, n, rank, rhs);
     Ok(LeastSquaresResult {
@@ -355,50 +394,70 @@ where
         &mut self,
         rhs: &mut ArrayBase<D2, Ix2>,
     ) -> Result<LeastSquaresResult<E, Ix2>> {
-        if self.shape()[0] != rhs.shape()[0] {
-            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
-        }
-        let (m, n) = (self.shape()[0], self.shape()[1]);
-        if n > m {
-            // we need a new rhs b/c it will be overwritten with the solution
-            // for which we need `n` entries
-            let k = rhs.shape()[1];
-            let mut new_rhs = match self.layout()? {
-                MatrixLayout::C { .. } => Array2::<E>::zeros((n, k)),
-                MatrixLayout::F { .. } => Array2::<E>::zeros((n, k).f()),
-            };
-            new_rhs.slice_mut(s![0..m, ..]).assign(rhs);
-            compute_least_squares_nrhs(self, &mut new_rhs)
-        } else {
-            compute_least_squares_nrhs(self, rhs)
-        }
+        least_squares_nrhs_in_place(self, Transpose::No, rhs, |la, a, lb, b| {
+            Ok(E::least_squares_nrhs(la, a, lb, b)?)
+        })
+    }
+}
+
+fn least_squares_nrhs_in_place<E, D1, D2, F>(
+    a: &mut ArrayBase<D1, Ix2>,
+    t: Transpose,
+    rhs: &mut ArrayBase<D2, Ix2>,
+    driver: F,
+) -> Result<LeastSquaresResult<E, Ix2>>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+    F: FnOnce(MatrixLayout, &mut [E], MatrixLayout, &mut [E]) -> Result<LeastSquaresOutput<E>>,
+{
+    let (m, n) = op_shape(a, t);
+    if m != rhs.shape()[0] {
+        return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+    }
+    if n > m {
+        // we need a new rhs b/c it will be overwritten with the solution
+        // for which we need `n` entries
+        let k = rhs.shape()[1];
+        let mut new_rhs = match a.layout()? {
+            MatrixLayout::C { .. } => Array2::<E>::zeros((n, k)),
+            MatrixLayout::F { .. } => Array2::<E>::zeros((n, k).f()),
+        };
+        new_rhs.slice_mut(s![0..m, ..]).assign(rhs);
+        compute_least_squares_nrhs(a, t, &mut new_rhs, driver)
+    } else {
+        compute_least_squares_nrhs(a, t, rhs, driver)
     }
 }
 
-fn compute_least_squares_nrhs<E, D1, D2>(
+fn compute_least_squares_nrhs<E, D1, D2, F>(
     a: &mut ArrayBase<D1, Ix2>,
+    t: Transpose,
     rhs: &mut ArrayBase<D2, Ix2>,
+    driver: F,
 ) -> Result<LeastSquaresResult<E, Ix2>>
 where
     E: Scalar + Lapack,
     D1: DataMut<Elem = E>,
     D2: DataMut<Elem = E>,
+    F: FnOnce(MatrixLayout, &mut [E], MatrixLayout, &mut [E]) -> Result<LeastSquaresOutput<E>>,
 {
     let a_layout = a.layout()?;
     let rhs_layout = rhs.layout()?;
     let LeastSquaresOutput::<E> {
         singular_values,
         rank,
-    } = E::least_squares_nrhs(
+    } = driver(
         a_layout,
         a.as_allocated_mut()?,
         rhs_layout,
         rhs.as_allocated_mut()?,
     )?;
 
-    let solution: Array2<E> = rhs.slice(s![..a.shape()[1], ..]).to_owned();
+    let (m, n) = op_shape(a, t);
+    let solution: Array2<E> = rhs.slice(s![..n, ..]).to_owned();
     let singular_values = Array::from_shape_vec((singular_values.len(),), singular_values)?;
-    let (m, n) = (a.shape()[0], a.shape()[1]);
     let residual_sum_of_squares = compute_residual_array1(m, n, rank, rhs);
     Ok(LeastSquaresResult {
         solution,
@@ -424,6 +483,575 @@ fn compute_residual_array1<E: Scalar, D: Data<Elem = E>>(
     )
 }
 
+/// Solve least squares by complete orthogonal decomposition for immutable references
+///
+/// This is an alternative to [LeastSquaresSvd] using `*gelsy`,
+/// which is usually faster than `*gelsd` and also returns the minimum norm solution
+/// for rank-deficient `A`.
+/// The effective rank of `A` is determined by column-pivoted QR decomposition
+/// with `rcond = eps * max(m, n)`.
+/// `singular_values` of [LeastSquaresResult] is empty since they are not computed.
+pub trait LeastSquaresQrPivoted<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// by calling `A.least_squares_qr_pivoted(&rhs)`. `A` and `rhs`
+    /// are unchanged.
+    fn least_squares_qr_pivoted(&self, rhs: &ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+}
+
+/// Solve least squares by complete orthogonal decomposition for owned matrices
+///
+/// See [LeastSquaresQrPivoted] for detail.
+pub trait LeastSquaresQrPivotedInto<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// by calling `A.least_squares_qr_pivoted_into(rhs)`, consuming both `A`
+    /// and `rhs`.
+    fn least_squares_qr_pivoted_into(
+        self,
+        rhs: ArrayBase<D, I>,
+    ) -> Result<LeastSquaresResult<E, I>>;
+}
+
+/// Solve least squares by complete orthogonal decomposition for mutable references,
+/// overwriting the input fields in the process
+///
+/// See [LeastSquaresQrPivoted] for detail.
+pub trait LeastSquaresQrPivotedInPlace<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// by calling `A.least_squares_qr_pivoted_in_place(&mut rhs)`, overwriting both `A`
+    /// and `rhs`.
+    fn least_squares_qr_pivoted_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D, I>,
+    ) -> Result<LeastSquaresResult<E, I>>;
+}
+
+impl<E, D1, D2> LeastSquaresQrPivoted<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_qr_pivoted(
+        &self,
+        rhs: &ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        let a = self.to_owned();
+        let b = rhs.to_owned();
+        a.least_squares_qr_pivoted_into(b)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQrPivoted<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_qr_pivoted(
+        &self,
+        rhs: &ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        let a = self.to_owned();
+        let b = rhs.to_owned();
+        a.least_squares_qr_pivoted_into(b)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQrPivotedInto<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    fn least_squares_qr_pivoted_into(
+        mut self,
+        mut rhs: ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        self.least_squares_qr_pivoted_in_place(&mut rhs)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQrPivotedInto<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    fn least_squares_qr_pivoted_into(
+        mut self,
+        mut rhs: ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        self.least_squares_qr_pivoted_in_place(&mut rhs)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQrPivotedInPlace<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    fn least_squares_qr_pivoted_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        let rcond = default_rcond::<E>(self.shape()[0], self.shape()[1]);
+        least_squares_srhs_in_place(self, Transpose::No, rhs, |l, a, b| {
+            let rank = E::least_squares_qr_pivoted(l, a, b, rcond)?;
+            Ok(LeastSquaresOutput {
+                singular_values: Vec::new(),
+                rank,
+            })
+        })
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQrPivotedInPlace<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    fn least_squares_qr_pivoted_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        let rcond = default_rcond::<E>(self.shape()[0], self.shape()[1]);
+        least_squares_nrhs_in_place(self, Transpose::No, rhs, |la, a, lb, b| {
+            let rank = E::least_squares_qr_pivoted_nrhs(la, a, lb, b, rcond)?;
+            Ok(LeastSquaresOutput {
+                singular_values: Vec::new(),
+                rank,
+            })
+        })
+    }
+}
+
+/// Solve least squares by QR or LQ decomposition for immutable references
+///
+/// This is an alternative to [LeastSquaresSvd] using `*gels`,
+/// which is much cheaper than `*gelsd` but assumes that `A` has full rank.
+/// No rank check is done: an error is returned only if a diagonal element of
+/// the triangular factor is exactly zero, and the result is not reliable
+/// if `A` is numerically rank-deficient. Use [LeastSquaresSvd] or
+/// [LeastSquaresQrPivoted] for such problems.
+/// For underdetermined systems, i.e. `op(A)` has more columns than rows,
+/// this returns the minimum norm solution.
+/// `singular_values` of [LeastSquaresResult] is empty since they are not computed,
+/// and `rank` is `min(m, n)`.
+///
+/// The `_t` and `_h` variants solve the problem for `A^T` and `A^H`
+/// without transposing `A` explicitly.
+pub trait LeastSquaresQr<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// by calling `A.least_squares_qr(&rhs)`. `A` and `rhs`
+    /// are unchanged.
+    fn least_squares_qr(&self, rhs: &ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `A^T x = rhs`
+    /// by calling `A.least_squares_qr_t(&rhs)`. `A` and `rhs`
+    /// are unchanged.
+    fn least_squares_qr_t(&self, rhs: &ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `A^H x = rhs`
+    /// by calling `A.least_squares_qr_h(&rhs)`. `A` and `rhs`
+    /// are unchanged.
+    fn least_squares_qr_h(&self, rhs: &ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+}
+
+/// Solve least squares by QR or LQ decomposition for owned matrices
+///
+/// See [LeastSquaresQr] for detail.
+pub trait LeastSquaresQrInto<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// by calling `A.least_squares_qr_into(rhs)`, consuming both `A`
+    /// and `rhs`.
+    fn least_squares_qr_into(self, rhs: ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `A^T x = rhs`
+    /// by calling `A.least_squares_qr_t_into(rhs)`, consuming both `A`
+    /// and `rhs`.
+    fn least_squares_qr_t_into(self, rhs: ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `A^H x = rhs`
+    /// by calling `A.least_squares_qr_h_into(rhs)`, consuming both `A`
+    /// and `rhs`.
+    fn least_squares_qr_h_into(self, rhs: ArrayBase<D, I>) -> Result<LeastSquaresResult<E, I>>;
+}
+
+/// Solve least squares by QR or LQ decomposition for mutable references,
+/// overwriting the input fields in the process
+///
+/// See [LeastSquaresQr] for detail.
+pub trait LeastSquaresQrInPlace<D, E, I>
+where
+    D: Data<Elem = E>,
+    E: Scalar + Lapack,
+    I: Dimension,
+{
+    /// Solve a least squares problem of the form `Ax = rhs`
+    /// by calling `A.least_squares_qr_in_place(&mut rhs)`, overwriting both `A`
+    /// and `rhs`.
+    fn least_squares_qr_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D, I>,
+    ) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `A^T x = rhs`
+    /// by calling `A.least_squares_qr_t_in_place(&mut rhs)`, overwriting both `A`
+    /// and `rhs`.
+    fn least_squares_qr_t_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D, I>,
+    ) -> Result<LeastSquaresResult<E, I>>;
+
+    /// Solve a least squares problem of the form `A^H x = rhs`
+    /// by calling `A.least_squares_qr_h_in_place(&mut rhs)`, overwriting both `A`
+    /// and `rhs`.
+    fn least_squares_qr_h_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D, I>,
+    ) -> Result<LeastSquaresResult<E, I>>;
+}
+
+impl<E, D1, D2> LeastSquaresQr<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_qr(&self, rhs: &ArrayBase<D2, Ix1>) -> Result<LeastSquaresResult<E, Ix1>> {
+        let a = self.to_owned();
+        let b = rhs.to_owned();
+        a.least_squares_qr_into(b)
+    }
+
+    fn least_squares_qr_t(&self, rhs: &ArrayBase<D2, Ix1>) -> Result<LeastSquaresResult<E, Ix1>> {
+        let a = self.to_owned();
+        let b = rhs.to_owned();
+        a.least_squares_qr_t_into(b)
+    }
+
+    fn least_squares_qr_h(&self, rhs: &ArrayBase<D2, Ix1>) -> Result<LeastSquaresResult<E, Ix1>> {
+        let a = self.to_owned();
+        let b = rhs.to_owned();
+        a.least_squares_qr_h_into(b)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQr<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: Data<Elem = E>,
+    D2: Data<Elem = E>,
+{
+    fn least_squares_qr(&self, rhs: &ArrayBase<D2, Ix2>) -> Result<LeastSquaresResult<E, Ix2>> {
+        let a = self.to_owned();
+        let b = rhs.to_owned();
+        a.least_squares_qr_into(b)
+    }
+
+    fn least_squares_qr_t(&self, rhs: &ArrayBase<D2, Ix2>) -> Result<LeastSquaresResult<E, Ix2>> {
+        let a = self.to_owned();
+        let b = rhs.to_owned();
+        a.least_squares_qr_t_into(b)
+    }
+
+    fn least_squares_qr_h(&self, rhs: &ArrayBase<D2, Ix2>) -> Result<LeastSquaresResult<E, Ix2>> {
+        let a = self.to_owned();
+        let b = rhs.to_owned();
+        a.least_squares_qr_h_into(b)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQrInto<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    fn least_squares_qr_into(
+        mut self,
+        mut rhs: ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        self.least_squares_qr_in_place(&mut rhs)
+    }
+
+    fn least_squares_qr_t_into(
+        mut self,
+        mut rhs: ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        self.least_squares_qr_t_in_place(&mut rhs)
+    }
+
+    fn least_squares_qr_h_into(
+        mut self,
+        mut rhs: ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        self.least_squares_qr_h_in_place(&mut rhs)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQrInto<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    fn least_squares_qr_into(
+        mut self,
+        mut rhs: ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        self.least_squares_qr_in_place(&mut rhs)
+    }
+
+    fn least_squares_qr_t_into(
+        mut self,
+        mut rhs: ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        self.least_squares_qr_t_in_place(&mut rhs)
+    }
+
+    fn least_squares_qr_h_into(
+        mut self,
+        mut rhs: ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        self.least_squares_qr_h_in_place(&mut rhs)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQrInPlace<D2, E, Ix1> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    fn least_squares_qr_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        least_squares_qr_srhs_in_place(self, Transpose::No, rhs)
+    }
+
+    fn least_squares_qr_t_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        least_squares_qr_srhs_in_place(self, Transpose::Transpose, rhs)
+    }
+
+    fn least_squares_qr_h_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix1>,
+    ) -> Result<LeastSquaresResult<E, Ix1>> {
+        least_squares_qr_srhs_in_place(self, Transpose::Hermite, rhs)
+    }
+}
+
+impl<E, D1, D2> LeastSquaresQrInPlace<D2, E, Ix2> for ArrayBase<D1, Ix2>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    fn least_squares_qr_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        least_squares_qr_nrhs_in_place(self, Transpose::No, rhs)
+    }
+
+    fn least_squares_qr_t_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        least_squares_qr_nrhs_in_place(self, Transpose::Transpose, rhs)
+    }
+
+    fn least_squares_qr_h_in_place(
+        &mut self,
+        rhs: &mut ArrayBase<D2, Ix2>,
+    ) -> Result<LeastSquaresResult<E, Ix2>> {
+        least_squares_qr_nrhs_in_place(self, Transpose::Hermite, rhs)
+    }
+}
+
+fn least_squares_qr_srhs_in_place<E, D1, D2>(
+    a: &mut ArrayBase<D1, Ix2>,
+    t: Transpose,
+    rhs: &mut ArrayBase<D2, Ix1>,
+) -> Result<LeastSquaresResult<E, Ix1>>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    let rank = a.shape()[0].min(a.shape()[1]) as i32;
+    least_squares_srhs_in_place(a, t, rhs, |l, a, b| {
+        E::least_squares_qr(l, t, a, b)?;
+        Ok(LeastSquaresOutput {
+            singular_values: Vec::new(),
+            rank,
+        })
+    })
+}
+
+fn least_squares_qr_nrhs_in_place<E, D1, D2>(
+    a: &mut ArrayBase<D1, Ix2>,
+    t: Transpose,
+    rhs: &mut ArrayBase<D2, Ix2>,
+) -> Result<LeastSquaresResult<E, Ix2>>
+where
+    E: Scalar + Lapack,
+    D1: DataMut<Elem = E>,
+    D2: DataMut<Elem = E>,
+{
+    let rank = a.shape()[0].min(a.shape()[1]) as i32;
+    least_squares_nrhs_in_place(a, t, rhs, |la, a, lb, b| {
+        E::least_squares_qr_nrhs(la, t, a, lb, b)?;
+        Ok(LeastSquaresOutput {
+            singular_values: Vec::new(),
+            rank,
+        })
+    })
+}
+
+/// Threshold of the reciprocal condition number to determine the effective rank
+fn default_rcond<E: Scalar>(m: usize, n: usize) -> E::Real {
+    E::Real::epsilon() * E::real(m.max(n))
+}
+
+/// Solve linear equality-constrained least squares problem
+///
+/// This computes `x` which minimizes `|c - A x|` subject to `B x = d` using `*gglse`,
+/// where `A` is `self`.
+/// For `m x n` matrix `A` and `p x n` matrix `B`, `p <= n <= m + p` is required,
+/// and `B` must have full row rank `p` and `[A; B]` must have full column rank `n`.
+pub trait LeastSquaresEqualityConstrained<E: Scalar> {
+    fn least_squares_equality_constrained<S1, S2, S3>(
+        &self,
+        c: &ArrayBase<S1, Ix1>,
+        b: &ArrayBase<S2, Ix2>,
+        d: &ArrayBase<S3, Ix1>,
+    ) -> Result<Array1<E>>
+    where
+        S1: Data<Elem = E>,
+        S2: Data<Elem = E>,
+        S3: Data<Elem = E>;
+}
+
+impl<E, D> LeastSquaresEqualityConstrained<E> for ArrayBase<D, Ix2>
+where
+    E: Scalar + Lapack,
+    D: Data<Elem = E>,
+{
+    fn least_squares_equality_constrained<S1, S2, S3>(
+        &self,
+        c: &ArrayBase<S1, Ix1>,
+        b: &ArrayBase<S2, Ix2>,
+        d: &ArrayBase<S3, Ix1>,
+    ) -> Result<Array1<E>>
+    where
+        S1: Data<Elem = E>,
+        S2: Data<Elem = E>,
+        S3: Data<Elem = E>,
+    {
+        if self.nrows() != c.len() || self.ncols() != b.ncols() || b.nrows() != d.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut a = self.to_owned();
+        let mut b = b.to_owned();
+        let mut c = c.to_vec();
+        let mut d = d.to_vec();
+        let x = E::least_squares_equality_constrained(
+            a.layout()?,
+            a.as_allocated_mut()?,
+            b.layout()?,
+            b.as_allocated_mut()?,
+            &mut c,
+            &mut d,
+        )?;
+        Ok(Array1::from(x))
+    }
+}
+
+/// Solve general Gauss-Markov linear model problem
+///
+/// This computes `x` and `y` which minimize `|y|` subject to `d = A x + B y` using `*ggglm`,
+/// where `A` is `self`, and returns `(x, y)`.
+/// For `n x m` matrix `A` and `n x p` matrix `B`, `m <= n <= m + p` is required,
+/// and `A` must have full column rank `m` and `[A, B]` must have full row rank `n`.
+///
+/// When `B` is square and non-singular, `x` is the solution of
+/// the weighted linear regression `min |B^-1 (d - A x)|`,
+/// e.g. `B` is the Cholesky factor of the covariance matrix of correlated noise.
+pub trait GeneralLinearModel<E: Scalar> {
+    fn general_linear_model<S1, S2>(
+        &self,
+        b: &ArrayBase<S1, Ix2>,
+        d: &ArrayBase<S2, Ix1>,
+    ) -> Result<(Array1<E>, Array1<E>)>
+    where
+        S1: Data<Elem = E>,
+        S2: Data<Elem = E>;
+}
+
+impl<E, D> GeneralLinearModel<E> for ArrayBase<D, Ix2>
+where
+    E: Scalar + Lapack,
+    D: Data<Elem = E>,
+{
+    fn general_linear_model<S1, S2>(
+        &self,
+        b: &ArrayBase<S1, Ix2>,
+        d: &ArrayBase<S2, Ix1>,
+    ) -> Result<(Array1<E>, Array1<E>)>
+    where
+        S1: Data<Elem = E>,
+        S2: Data<Elem = E>,
+    {
+        if self.nrows() != b.nrows() || self.nrows() != d.len() {
+            return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape).into());
+        }
+        let mut a = self.to_owned();
+        let mut b = b.to_owned();
+        let mut d = d.to_vec();
+        let (x, y) = E::general_linear_model(
+            a.layout()?,
+            a.as_allocated_mut()?,
+            b.layout()?,
+            b.as_allocated_mut()?,
+            &mut d,
+        )?;
+        Ok((Array1::from(x), Array1::from(y)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{error::LinalgError, *};
@@ -9,12 +9,20 @@
 //!     - [Cholesky/LU decomposition](cholesky/index.html)
 //!     - [Eigenvalue decomposition](eig/index.html)
 //!     - [Eigenvalue decomposition for Hermite matrices](eigh/index.html)
+//!     - [Schur decomposition](schur/index.html)
+//!     - [Generalized Schur (QZ) decomposition](qz/index.html)
+//!     - [Hessenberg reduction](hessenberg/index.html)
 //!     - [**S**ingular **V**alue **D**ecomposition](svd/index.html)
+//!     - [SVD by one-sided Jacobi method](svd_jacobi/index.html)
+//!     - [Generalized SVD](gsvd/index.html)
+//!     - [Bidiagonal reduction](bidiagonal/index.html)
 //! - Solution of linear systems:
 //!    - [General matrices](solve/index.html)
 //!    - [Triangular matrices](triangular/index.html)
 //!    - [Hermitian/real symmetric matrices](solveh/index.html)
 //!    - [Tridiagonal matrices](tridiagonal/index.html)
+//!    - [Banded matrices](banded/index.html)
+//!    - [Packed and RFP storage](packed/index.html)
 //! - [Inverse matrix computation](solve/trait.Inverse.html)
 //!
 //! Naming Convention
@@ -50,6 +58,8 @@
 extern crate ndarray;
 
 pub mod assert;
+pub mod banded;
+pub mod bidiagonal;
 pub mod cholesky;
 pub mod convert;
 pub mod diagonal;
@@ -57,6 +67,8 @@ pub mod eig;
 pub mod eigh;
 pub mod error;
 pub mod generate;
+pub mod gsvd;
+pub mod hessenberg;
 pub mod inner;
 pub mod krylov;
 pub mod layout;
@@ -65,10 +77,14 @@ pub mod lobpcg;
 pub mod norm;
 pub mod operator;
 pub mod opnorm;
+pub mod packed;
 pub mod qr;
+pub mod qz;
+pub mod schur;
 pub mod solve;
 pub mod solveh;
 pub mod svd;
+pub mod svd_jacobi;
 pub mod svddc;
 pub mod trace;
 pub mod triangular;
@@ -76,12 +92,16 @@ pub mod tridiagonal;
 pub mod types;
 
 pub use crate::assert::*;
+pub use crate::banded::*;
+pub use crate::bidiagonal::*;
 pub use crate::cholesky::*;
 pub use crate::convert::*;
 pub use crate::diagonal::*;
 pub use crate::eig::*;
 pub use crate::eigh::*;
 pub use crate::generate::*;
+pub use crate::gsvd::*;
+pub use crate::hessenberg::*;
 pub use crate::inner::*;
 pub use crate::layout::*;
 pub use crate::least_squares::*;
@@ -89,10 +109,14 @@ pub use crate::lobpcg::{TruncatedEig, TruncatedOrder, TruncatedSvd};
 pub use crate::norm::*;
 pub use crate::operator::*;
 pub use crate::opnorm::*;
+pub use crate::packed::*;
 pub use crate::qr::*;
+pub use crate::qz::*;
+pub use crate::schur::*;
 pub use crate::solve::*;
 pub use crate::solveh::*;
 pub use crate::svd::*;
+pub use crate::svd_jacobi::*;
 pub use crate::svddc::*;
 pub use crate::trace::*;
 pub use crate::triangular::*;
@@ -0,0 +1,227 @@
+//! Methods for symmetric/hermite matrices in packed and
+//! rectangular full packed (RFP) storage
+//!
+//! These matrices can be converted from and into [Array2] by
+//! [to_packed](crate::convert::to_packed), [from_packed](crate::convert::from_packed),
+//! [to_rfp](crate::convert::to_rfp) and [from_rfp](crate::convert::from_rfp).
+
+use super::cholesky::SolveC;
+use super::error::*;
+use super::layout::*;
+use super::solveh::SolveH;
+use cauchy::Scalar;
+use lax::*;
+use ndarray::*;
+
+pub use lax::{Packed, RectangularFullPacked};
+
+/// Represents the Cholesky factorization of a Hermitian (or real symmetric)
+/// positive definite matrix in packed storage
+pub struct CholeskyFactorizedPacked<A: Scalar> {
+    /// `U` of `A = U^H * U` or `L` of `A = L * L^H` according to `uplo`
+    pub factor: Packed<A>,
+}
+
+/// Represents the Cholesky factorization of a Hermitian (or real symmetric)
+/// positive definite matrix in RFP storage
+pub struct CholeskyFactorizedRFP<A: Scalar> {
+    /// `U` of `A = U^H * U` or `L` of `A = L * L^H` according to `uplo`
+    pub factor: RectangularFullPacked<A>,
+}
+
+/// Represents the Bunch–Kaufman factorization of a Hermitian (or real
+/// symmetric) matrix in packed storage as `A = P * U * D * U^H * P^T`
+/// or `A = P * L * D * L^H * P^T` according to `uplo`.
+pub struct BKFactorizedPacked<A: Scalar> {
+    pub a: Packed<A>,
+    pub ipiv: Pivot,
+}
+
+/// Cholesky decomposition of Hermitian (or real symmetric) positive definite
+/// matrix in packed storage
+pub trait FactorizeCPacked<A: Scalar> {
+    fn factorizec_packed(&self) -> Result<CholeskyFactorizedPacked<A>>;
+}
+
+/// Cholesky decomposition of Hermitian (or real symmetric) positive definite
+/// matrix in RFP storage
+pub trait FactorizeCRFP<A: Scalar> {
+    fn factorizec_rfp(&self) -> Result<CholeskyFactorizedRFP<A>>;
+}
+
+/// Bunch–Kaufman factorization of Hermitian (or real symmetric) matrix
+/// in packed storage
+pub trait FactorizeHPacked<A: Scalar> {
+    fn factorizeh_packed(&self) -> Result<BKFactorizedPacked<A>>;
+}
+
+impl<A> FactorizeCPacked<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizec_packed(&self) -> Result<CholeskyFactorizedPacked<A>> {
+        let mut factor = self.clone();
+        A::cholesky_packed(&mut factor)?;
+        Ok(CholeskyFactorizedPacked { factor })
+    }
+}
+
+impl<A> FactorizeCRFP<A> for RectangularFullPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizec_rfp(&self) -> Result<CholeskyFactorizedRFP<A>> {
+        let mut factor = self.clone();
+        A::cholesky_rfp(&mut factor)?;
+        Ok(CholeskyFactorizedRFP { factor })
+    }
+}
+
+impl<A> FactorizeHPacked<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorizeh_packed(&self) -> Result<BKFactorizedPacked<A>> {
+        let mut a = self.clone();
+        let ipiv = A::bk_packed(&mut a)?;
+        Ok(BKFactorizedPacked { a, ipiv })
+    }
+}
+
+impl<A> SolveC<A> for CholeskyFactorizedPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let n = b.len() as i32;
+        A::solve_cholesky_packed(
+            &self.factor,
+            MatrixLayout::F { col: 1, lda: n },
+            b.as_slice_mut().unwrap(),
+        )?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveC<A> for CholeskyFactorizedRFP<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let n = b.len() as i32;
+        A::solve_cholesky_rfp(
+            &self.factor,
+            MatrixLayout::F { col: 1, lda: n },
+            b.as_slice_mut().unwrap(),
+        )?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveH<A> for BKFactorizedPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        let n = b.len() as i32;
+        A::solve_bk_packed(
+            &self.a,
+            &self.ipiv,
+            MatrixLayout::F { col: 1, lda: n },
+            b.as_slice_mut().unwrap(),
+        )?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveC<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorizec_packed()?.solvec_inplace(b)
+    }
+}
+
+impl<A> SolveC<A> for RectangularFullPacked<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solvec_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorizec_rfp()?.solvec_inplace(b)
+    }
+}
+
+impl<A> SolveH<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.factorizeh_packed()?.solveh_inplace(b)
+    }
+}
+
+/// Eigenvalue problem for Hermitian (or real symmetric) matrix in packed storage
+///
+/// Eigenvalues are returned in ascending order,
+/// and eigenvectors are returned as the columns of the matrix.
+pub trait EighPacked<A: Scalar> {
+    /// Compute all eigenvalues and eigenvectors
+    fn eigh_packed(&self) -> Result<(Array1<A::Real>, Array2<A>)>;
+
+    /// Compute all eigenvalues
+    fn eigvalsh_packed(&self) -> Result<Array1<A::Real>>;
+}
+
+impl<A> EighPacked<A> for Packed<A>
+where
+    A: Scalar + Lapack,
+{
+    fn eigh_packed(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let n = self.l.len() as usize;
+        let res = A::eigh_packed(true, self)?;
+        let z = Array2::from_shape_vec((n, n).f(), res.z.unwrap()).unwrap();
+        Ok((ArrayBase::from(res.eigs), z))
+    }
+
+    fn eigvalsh_packed(&self) -> Result<Array1<A::Real>> {
+        let res = A::eigh_packed(false, self)?;
+        Ok(ArrayBase::from(res.eigs))
+    }
+}
@@ -1,4 +1,7 @@
-//! QR decomposition
+//! QR decomposition, and LQ, QL and RQ decompositions
+//!
+//! [QRFactorized] keeps `Q` as Householder reflectors without forming it explicitly,
+//! which can be used for solving linear systems and least squares problems.
 //!
 //! [Wikipedia article on QR decomposition](https://en.wikipedia.org/wiki/QR_decomposition)
 
@@ -7,7 +10,9 @@ use num_traits::Zero;
 
 use crate::convert::*;
 use crate::error::*;
+use crate::generate::conjugate;
 use crate::layout::*;
+use crate::solve::{Solve, Transpose};
 use crate::triangular::*;
 use crate::types::*;
 
@@ -128,6 +133,730 @@ where
     }
 }
 
+/// Represents the QR factorization of a matrix `A` as `A = Q*R`
+/// without forming `Q` explicitly.
+///
+/// `Q` is kept as the Householder reflectors and their scalar factors,
+/// and applied to vectors via `*ormqr/*unmqr`.
+/// For a tall-skinny `m x n` matrix this avoids allocating another `m x n` array for `Q`,
+/// which is needed by [QR] and [QRInto].
+pub struct QRFactorized<S: Data + RawDataClone> {
+    /// The factor `R` in the upper triangle,
+    /// and the Householder reflectors below the diagonal
+    a: ArrayBase<S, Ix2>,
+    /// The scalar factors of the Householder reflectors
+    tau: Vec<S::Elem>,
+}
+
+impl<A, S> Clone for QRFactorized<S>
+where
+    A: Clone,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn clone(&self) -> Self {
+        QRFactorized {
+            a: self.a.clone(),
+            tau: self.tau.clone(),
+        }
+    }
+}
+
+impl<A, S> QRFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    /// Upper trapezoidal factor `R` of size `min(m, n) x n`
+    pub fn r(&self) -> Array2<A> {
+        take_slice_upper(&self.a, self.tau.len(), self.a.ncols())
+    }
+
+    /// Computes `Q * b` where `Q` is the `m x m` unitary matrix
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not equal to the number of rows of `A`.
+    pub fn apply_q<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>> {
+        let mut b = replicate(b);
+        self.apply_q_inplace(&mut b)?;
+        Ok(b)
+    }
+
+    /// Computes `Q * b` where `Q` is the `m x m` unitary matrix
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not equal to the number of rows of `A`.
+    pub fn apply_q_inplace<'a, Sb: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>> {
+        self.apply_inplace(Transpose::No, b)
+    }
+
+    /// Computes `Q^H * b` where `Q` is the `m x m` unitary matrix
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not equal to the number of rows of `A`.
+    pub fn apply_qh<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>> {
+        let mut b = replicate(b);
+        self.apply_qh_inplace(&mut b)?;
+        Ok(b)
+    }
+
+    /// Computes `Q^H * b` where `Q` is the `m x m` unitary matrix
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `b` is not equal to the number of rows of `A`.
+    pub fn apply_qh_inplace<'a, Sb: DataMut<Elem = A>>(
+        &self,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>> {
+        self.apply_inplace(Transpose::Hermite, b)
+    }
+
+    fn apply_inplace<'a, Sb: DataMut<Elem = A>>(
+        &self,
+        t: Transpose,
+        b: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>> {
+        assert_eq!(
+            b.len(),
+            self.a.nrows(),
+            "The length of `b` must be equal to the number of rows of the factored matrix.",
+        );
+        let lb = MatrixLayout::F {
+            col: 1,
+            lda: b.len() as i32,
+        };
+        A::apply_q(
+            self.a.layout()?,
+            self.a.as_allocated()?,
+            &self.tau,
+            t,
+            lb,
+            b.as_slice_mut().unwrap(),
+        )?;
+        Ok(b)
+    }
+
+    /// Solves the least squares problem `min_x |b - A * x|`
+    /// for an `m x n` matrix `A` of full column rank with `m >= n`
+    ///
+    /// This computes `x = R^-1 (Q^H b)[..n]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m < n`, or the length of `b` is not equal to `m`.
+    pub fn least_squares<Sb: Data<Elem = A>>(&self, b: &ArrayBase<Sb, Ix1>) -> Result<Array1<A>> {
+        let (m, n) = self.a.dim();
+        assert!(
+            m >= n,
+            "The factored matrix must have at least as many rows as columns."
+        );
+        let y = self.apply_qh(b)?;
+        let y = y.slice(s![..n]).to_owned();
+        self.r()
+            .solve_triangular_into(UPLO::Upper, Diag::NonUnit, y)
+    }
+}
+
+impl<A, S> Solve<A> for QRFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A> + RawDataClone,
+{
+    fn solve_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.a.ensure_square()?;
+        // A x = Q R x = b  <=>  R x = Q^H b
+        self.apply_qh_inplace(rhs)?;
+        let x = self
+            .r()
+            .solve_triangular_into(UPLO::Upper, Diag::NonUnit, rhs.to_owned())?;
+        rhs.assign(&x);
+        Ok(rhs)
+    }
+    fn solve_t_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        // A^T x = b  <=>  A^H conj(x) = conj(b)
+        rhs.mapv_inplace(|x| x.conj());
+        self.solve_h_inplace(rhs)?;
+        rhs.mapv_inplace(|x| x.conj());
+        Ok(rhs)
+    }
+    fn solve_h_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.a.ensure_square()?;
+        // A^H x = R^H Q^H x = b  <=>  x = Q (R^H)^-1 b
+        let rh: Array2<A> = conjugate(&self.r());
+        let y = rh.solve_triangular_into(UPLO::Lower, Diag::NonUnit, rhs.to_owned())?;
+        rhs.assign(&y);
+        self.apply_q_inplace(rhs)
+    }
+}
+
+/// An interface for computing QR factorizations of matrix refs
+/// keeping `Q` as Householder reflectors
+pub trait FactorizeQR<S: Data + RawDataClone> {
+    /// Computes the QR factorization `A = Q*R`
+    fn factorize_qr(&self) -> Result<QRFactorized<S>>;
+}
+
+/// An interface for computing QR factorizations of matrices
+/// keeping `Q` as Householder reflectors
+pub trait FactorizeQRInto<S: Data + RawDataClone> {
+    /// Computes the QR factorization `A = Q*R`
+    fn factorize_qr_into(self) -> Result<QRFactorized<S>>;
+}
+
+impl<A, S> FactorizeQRInto<S> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A> + RawDataClone,
+{
+    fn factorize_qr_into(mut self) -> Result<QRFactorized<S>> {
+        let tau = A::householder(self.layout()?, self.as_allocated_mut()?)?;
+        Ok(QRFactorized { a: self, tau })
+    }
+}
+
+impl<A, Si> FactorizeQR<OwnedRepr<A>> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+{
+    fn factorize_qr(&self) -> Result<QRFactorized<OwnedRepr<A>>> {
+        let mut a: Array2<A> = replicate(self);
+        let tau = A::householder(a.layout()?, a.as_allocated_mut()?)?;
+        Ok(QRFactorized { a, tau })
+    }
+}
+
+/// LQ decomposition for matrix reference
+///
+/// This creates copy due for reshaping array.
+/// To avoid copy and the matrix is square, please use `LQSquare*` traits.
+pub trait LQ {
+    type L;
+    type Q;
+    fn lq(&self) -> Result<(Self::L, Self::Q)>;
+}
+
+/// LQ decomposition
+///
+/// This creates copy due for reshaping array.
+/// To avoid copy and the matrix is square, please use `LQSquare*` traits.
+pub trait LQInto: Sized {
+    type L;
+    type Q;
+    fn lq_into(self) -> Result<(Self::L, Self::Q)>;
+}
+
+/// LQ decomposition for square matrix reference
+pub trait LQSquare: Sized {
+    type L;
+    type Q;
+    fn lq_square(&self) -> Result<(Self::L, Self::Q)>;
+}
+
+/// LQ decomposition for square matrix
+pub trait LQSquareInto: Sized {
+    type L;
+    fn lq_square_into(self) -> Result<(Self::L, Self)>;
+}
+
+/// LQ decomposition for mutable reference of square matrix
+pub trait LQSquareInplace: Sized {
+    type L;
+    fn lq_square_inplace(&mut self) -> Result<(Self::L, &mut Self)>;
+}
+
+impl<A, S> LQSquareInplace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type L = Array2<A>;
+
+    fn lq_square_inplace(&mut self) -> Result<(Self::L, &mut Self)> {
+        let l = self.square_layout()?;
+        let tri = A::lq(l, self.as_allocated_mut()?)?;
+        let tri: Array2<_> = into_matrix(l, tri)?;
+        Ok((tri.into_triangular(UPLO::Lower), self))
+    }
+}
+
+impl<A, S> LQSquareInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type L = Array2<A>;
+
+    fn lq_square_into(mut self) -> Result<(Self::L, Self)> {
+        let (tri, _) = self.lq_square_inplace()?;
+        Ok((tri, self))
+    }
+}
+
+impl<A, S> LQSquare for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type L = Array2<A>;
+    type Q = Array2<A>;
+
+    fn lq_square(&self) -> Result<(Self::L, Self::Q)> {
+        let a = self.to_owned();
+        a.lq_square_into()
+    }
+}
+
+impl<A, S> LQInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type L = Array2<A>;
+    type Q = Array2<A>;
+
+    fn lq_into(mut self) -> Result<(Self::L, Self::Q)> {
+        let n = self.nrows();
+        let m = self.ncols();
+        let k = ::std::cmp::min(n, m);
+        let l = self.layout()?;
+        let tri = A::lq(l, self.as_allocated_mut()?)?;
+        let tri: Array2<_> = into_matrix(l, tri)?;
+        let q = self;
+        Ok((
+            take_trapezoid(&tri.slice(s![.., ..k]), UPLO::Lower, 0),
+            replicate(&q.slice(s![..k, ..])),
+        ))
+    }
+}
+
+impl<A, S> LQ for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type L = Array2<A>;
+    type Q = Array2<A>;
+
+    fn lq(&self) -> Result<(Self::L, Self::Q)> {
+        let a = self.to_owned();
+        a.lq_into()
+    }
+}
+
+/// QL decomposition for matrix reference
+///
+/// This creates copy due for reshaping array.
+/// To avoid copy and the matrix is square, please use `QLSquare*` traits.
+pub trait QL {
+    type Q;
+    type L;
+    fn ql(&self) -> Result<(Self::Q, Self::L)>;
+}
+
+/// QL decomposition
+///
+/// This creates copy due for reshaping array.
+/// To avoid copy and the matrix is square, please use `QLSquare*` traits.
+pub trait QLInto: Sized {
+    type Q;
+    type L;
+    fn ql_into(self) -> Result<(Self::Q, Self::L)>;
+}
+
+/// QL decomposition for square matrix reference
+pub trait QLSquare: Sized {
+    type Q;
+    type L;
+    fn ql_square(&self) -> Result<(Self::Q, Self::L)>;
+}
+
+/// QL decomposition for square matrix
+pub trait QLSquareInto: Sized {
+    type L;
+    fn ql_square_into(self) -> Result<(Self, Self::L)>;
+}
+
+/// QL decomposition for mutable reference of square matrix
+pub trait QLSquareInplace: Sized {
+    type L;
+    fn ql_square_inplace(&mut self) -> Result<(&mut Self, Self::L)>;
+}
+
+impl<A, S> QLSquareInplace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type L = Array2<A>;
+
+    fn ql_square_inplace(&mut self) -> Result<(&mut Self, Self::L)> {
+        let l = self.square_layout()?;
+        let tri = A::ql(l, self.as_allocated_mut()?)?;
+        let tri: Array2<_> = into_matrix(l, tri)?;
+        Ok((self, tri.into_triangular(UPLO::Lower)))
+    }
+}
+
+impl<A, S> QLSquareInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type L = Array2<A>;
+
+    fn ql_square_into(mut self) -> Result<(Self, Self::L)> {
+        let (_, tri) = self.ql_square_inplace()?;
+        Ok((self, tri))
+    }
+}
+
+impl<A, S> QLSquare for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type L = Array2<A>;
+
+    fn ql_square(&self) -> Result<(Self::Q, Self::L)> {
+        let a = self.to_owned();
+        a.ql_square_into()
+    }
+}
+
+impl<A, S> QLInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Q = Array2<A>;
+    type L = Array2<A>;
+
+    fn ql_into(mut self) -> Result<(Self::Q, Self::L)> {
+        let n = self.nrows();
+        let m = self.ncols();
+        let k = ::std::cmp::min(n, m);
+        let l = self.layout()?;
+        let tri = A::ql(l, self.as_allocated_mut()?)?;
+        let tri: Array2<_> = into_matrix(l, tri)?;
+        let q = self;
+        Ok((
+            replicate(&q.slice(s![.., m - k..])),
+            take_trapezoid(&tri.slice(s![n - k.., ..]), UPLO::Lower, m - k),
+        ))
+    }
+}
+
+impl<A, S> QL for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type L = Array2<A>;
+
+    fn ql(&self) -> Result<(Self::Q, Self::L)> {
+        let a = self.to_owned();
+        a.ql_into()
+    }
+}
+
+/// RQ decomposition for matrix reference
+///
+/// This creates copy due for reshaping array.
+/// To avoid copy and the matrix is square, please use `RQSquare*` traits.
+pub trait RQ {
+    type R;
+    type Q;
+    fn rq(&self) -> Result<(Self::R, Self::Q)>;
+}
+
+/// RQ decomposition
+///
+/// This creates copy due for reshaping array.
+/// To avoid copy and the matrix is square, please use `RQSquare*` traits.
+pub trait RQInto: Sized {
+    type R;
+    type Q;
+    fn rq_into(self) -> Result<(Self::R, Self::Q)>;
+}
+
+/// RQ decomposition for square matrix reference
+pub trait RQSquare: Sized {
+    type R;
+    type Q;
+    fn rq_square(&self) -> Result<(Self::R, Self::Q)>;
+}
+
+/// RQ decomposition for square matrix
+pub trait RQSquareInto: Sized {
+    type R;
+    fn rq_square_into(self) -> Result<(Self::R, Self)>;
+}
+
+/// RQ decomposition for mutable reference of square matrix
+pub trait RQSquareInplace: Sized {
+    type R;
+    fn rq_square_inplace(&mut self) -> Result<(Self::R, &mut Self)>;
+}
+
+impl<A, S> RQSquareInplace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type R = Array2<A>;
+
+    fn rq_square_inplace(&mut self) -> Result<(Self::R, &mut Self)> {
+        let l = self.square_layout()?;
+        let r = A::rq(l, self.as_allocated_mut()?)?;
+        let r: Array2<_> = into_matrix(l, r)?;
+        Ok((r.into_triangular(UPLO::Upper), self))
+    }
+}
+
+impl<A, S> RQSquareInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type R = Array2<A>;
+
+    fn rq_square_into(mut self) -> Result<(Self::R, Self)> {
+        let (r, _) = self.rq_square_inplace()?;
+        Ok((r, self))
+    }
+}
+
+impl<A, S> RQSquare for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type R = Array2<A>;
+    type Q = Array2<A>;
+
+    fn rq_square(&self) -> Result<(Self::R, Self::Q)> {
+        let a = self.to_owned();
+        a.rq_square_into()
+    }
+}
+
+impl<A, S> RQInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type R = Array2<A>;
+    type Q = Array2<A>;
+
+    fn rq_into(mut self) -> Result<(Self::R, Self::Q)> {
+        let n = self.nrows();
+        let m = self.ncols();
+        let k = ::std::cmp::min(n, m);
+        let l = self.layout()?;
+        let r = A::rq(l, self.as_allocated_mut()?)?;
+        let r: Array2<_> = into_matrix(l, r)?;
+        let q = self;
+        Ok((
+            take_trapezoid(&r.slice(s![.., m - k..]), UPLO::Upper, n - k),
+            replicate(&q.slice(s![n - k.., ..])),
+        ))
+    }
+}
+
+impl<A, S> RQ for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type R = Array2<A>;
+    type Q = Array2<A>;
+
+    fn rq(&self) -> Result<(Self::R, Self::Q)> {
+        let a = self.to_owned();
+        a.rq_into()
+    }
+}
+
+/// QR decomposition with column pivoting for matrix reference
+///
+/// This computes `A P = Q R` where `P` is a permutation matrix
+/// chosen so that the absolute values of the diagonal elements of `R` are non-increasing.
+/// The permutation is returned as an array of column indices,
+/// i.e. `A.select(Axis(1), &p) = Q R`.
+/// The numerical rank of `A` can be estimated by [numerical_rank] from `R`.
+pub trait QRPivoted {
+    type Q;
+    type R;
+    type P;
+    fn qr_pivoted(&self) -> Result<(Self::Q, Self::R, Self::P)>;
+}
+
+/// QR decomposition with column pivoting
+///
+/// See [QRPivoted] for detail.
+pub trait QRPivotedInto: Sized {
+    type Q;
+    type R;
+    type P;
+    fn qr_pivoted_into(self) -> Result<(Self::Q, Self::R, Self::P)>;
+}
+
+impl<A, S> QRPivotedInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Q = Array2<A>;
+    type R = Array2<A>;
+    type P = Array1<usize>;
+
+    fn qr_pivoted_into(mut self) -> Result<(Self::Q, Self::R, Self::P)> {
+        let n = self.nrows();
+        let m = self.ncols();
+        let k = ::std::cmp::min(n, m);
+        let l = self.layout()?;
+        let (r, jpvt) = A::qr_pivoted(l, self.as_allocated_mut()?)?;
+        let r: Array2<_> = into_matrix(l, r)?;
+        let q = self;
+        // LAPACK returns 1-based index
+        let p = jpvt.into_iter().map(|j| j as usize - 1).collect();
+        Ok((take_slice(&q, n, k), take_slice_upper(&r, k, m), p))
+    }
+}
+
+impl<A, S> QRPivoted for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type R = Array2<A>;
+    type P = Array1<usize>;
+
+    fn qr_pivoted(&self) -> Result<(Self::Q, Self::R, Self::P)> {
+        let a = self.to_owned();
+        a.qr_pivoted_into()
+    }
+}
+
+/// Estimate the numerical rank from `R` of [QRPivoted]
+///
+/// This counts the diagonal elements of `R` satisfying `|R[i, i]| > rtol * |R[0, 0]|`,
+/// which is valid since they are sorted in non-increasing order of absolute values.
+pub fn numerical_rank<A, S>(r: &ArrayBase<S, Ix2>, rtol: A::Real) -> usize
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+{
+    let d = r.diag();
+    match d.first() {
+        Some(r00) => {
+            let tol = rtol * r00.abs();
+            d.iter().take_while(|rii| rii.abs() > tol).count()
+        }
+        None => 0,
+    }
+}
+
+/// Complete orthogonal decomposition for matrix reference
+///
+/// This computes `A P = Q [T 0; 0 0] Z` where `P` is a permutation matrix,
+/// `Q` and `Z` are unitary, and `T` is `r x r` non-singular upper triangular matrix
+/// for the numerical rank `r` of `A` determined as [numerical_rank] with `rtol`.
+/// Only the blocks coupled to `T` are returned, i.e. `Q` is `m x r` and `Z` is `r x n`
+/// satisfying `A.select(Axis(1), &p) = Q T Z` up to the truncated part.
+pub trait CompleteOrthogonal<A: Scalar> {
+    type Q;
+    type T;
+    type Z;
+    type P;
+    fn complete_orthogonal(&self, rtol: A::Real) -> Result<(Self::Q, Self::T, Self::Z, Self::P)>;
+}
+
+/// Complete orthogonal decomposition
+///
+/// See [CompleteOrthogonal] for detail.
+pub trait CompleteOrthogonalInto<A: Scalar>: Sized {
+    type Q;
+    type T;
+    type Z;
+    type P;
+    fn complete_orthogonal_into(
+        self,
+        rtol: A::Real,
+    ) -> Result<(Self::Q, Self::T, Self::Z, Self::P)>;
+}
+
+impl<A, S> CompleteOrthogonalInto<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Q = Array2<A>;
+    type T = Array2<A>;
+    type Z = Array2<A>;
+    type P = Array1<usize>;
+
+    fn complete_orthogonal_into(
+        mut self,
+        rtol: A::Real,
+    ) -> Result<(Self::Q, Self::T, Self::Z, Self::P)> {
+        let (m, n) = self.dim();
+        let l = self.layout()?;
+        let out = A::complete_orthogonal(l, rtol, self.as_allocated_mut()?)?;
+        let r = out.rank as usize;
+        let q = into_matrix(l.resized(m as i32, r as i32), out.q)?;
+        let t = into_matrix(l.resized(r as i32, r as i32), out.t)?;
+        let z = into_matrix(l.resized(r as i32, n as i32), out.z)?;
+        // LAPACK returns 1-based index
+        let p = out.jpvt.into_iter().map(|j| j as usize - 1).collect();
+        Ok((q, t, z, p))
+    }
+}
+
+impl<A, S> CompleteOrthogonal<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+    type T = Array2<A>;
+    type Z = Array2<A>;
+    type P = Array1<usize>;
+
+    fn complete_orthogonal(&self, rtol: A::Real) -> Result<(Self::Q, Self::T, Self::Z, Self::P)> {
+        let a = self.to_owned();
+        a.complete_orthogonal_into(rtol)
+    }
+}
+
 fn take_slice<A, S1, S2>(a: &ArrayBase<S1, Ix2>, n: usize, m: usize) -> ArrayBase<S2, Ix2>
 where
     A: Copy,
@@ -153,3 +882,28 @@ where
     });
     a
 }
+
+/// Copy `a` with zeroing the elements above the `offset`-th superdiagonal for [UPLO::Lower],
+/// or below the `offset`-th subdiagonal for [UPLO::Upper]
+fn take_trapezoid<A, S1, S2>(
+    a: &ArrayBase<S1, Ix2>,
+    uplo: UPLO,
+    offset: usize,
+) -> ArrayBase<S2, Ix2>
+where
+    A: Copy + Zero,
+    S1: Data<Elem = A>,
+    S2: DataMut<Elem = A> + DataOwned,
+{
+    let mut a = replicate(a);
+    Zip::indexed(&mut a).for_each(|(i, j), elt| {
+        let outside = match uplo {
+            UPLO::Upper => i > j + offset,
+            UPLO::Lower => j > i + offset,
+        };
+        if outside {
+            *elt = A::zero()
+        }
+    });
+    a
+}
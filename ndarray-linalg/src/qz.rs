@@ -0,0 +1,158 @@
+//! Generalized Schur (QZ) decomposition for pairs of general square matrices
+//!
+//! For a pair of square matrices `(A, B)`, this computes
+//! `A = Q S Z^H` and `B = Q T Z^H`
+//! where `Q` and `Z` are unitary (orthogonal for real matrices),
+//! `T` is upper triangular, and `S` is upper triangular for complex matrices
+//! or upper quasi-triangular with 1x1 and 2x2 diagonal blocks for real matrices.
+//!
+//! The generalized eigenvalues are `alpha / beta`,
+//! and `beta = 0` represents an infinite eigenvalue.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [1.0, 2.0, 3.0],
+//!     [4.0, 5.0, 6.0],
+//!     [7.0, 8.0, 0.0],
+//! ];
+//! let b: Array2<f64> = array![
+//!     [1.0, 0.0, 0.0],
+//!     [0.0, 1.0, 0.0],
+//!     [0.0, 0.0, 0.0],
+//! ];
+//! // Move finite eigenvalues to the top-left
+//! let qz = (a.clone(), b.clone()).qz_sorted(|_alpha, beta| beta.abs() > 1e-10).unwrap();
+//! assert_eq!(qz.sdim, 2);
+//! assert_close_l2!(&qz.q.dot(&qz.s).dot(&qz.z.t()), &a, 1e-7);
+//! assert_close_l2!(&qz.q.dot(&qz.t).dot(&qz.z.t()), &b, 1e-7);
+//! ```
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Result of QZ decomposition
+#[derive(Debug, Clone)]
+pub struct QZDecomposition<E: Scalar> {
+    /// Unitary matrix `Q`
+    pub q: Array2<E>,
+    /// Unitary matrix `Z`
+    pub z: Array2<E>,
+    /// Generalized Schur form of `A`
+    pub s: Array2<E>,
+    /// Generalized Schur form of `B`
+    pub t: Array2<E>,
+    /// Numerator of the generalized eigenvalues
+    pub alpha: Array1<E::Complex>,
+    /// Denominator of the generalized eigenvalues
+    pub beta: Array1<E>,
+    /// Number of eigenvalues selected by the predicate of [QZ::qz_sorted].
+    /// The first `sdim` columns of `Q` and `Z` span the left and right deflating subspaces.
+    pub sdim: usize,
+}
+
+/// QZ decomposition of a pair of general matrices
+pub trait QZ {
+    type Elem: Scalar;
+
+    /// Compute the QZ decomposition
+    fn qz(&self) -> Result<QZDecomposition<Self::Elem>>;
+
+    /// Compute the QZ decomposition with reordering eigenvalues
+    ///
+    /// The eigenvalues `(alpha, beta)` for which `select` returns `true` are moved to
+    /// the leading diagonal blocks of `S` and `T`.
+    /// For real matrices, a complex conjugate pair is selected if either of them is selected,
+    /// and thus counted twice in `sdim`.
+    fn qz_sorted<F>(&self, select: F) -> Result<QZDecomposition<Self::Elem>>
+    where
+        F: FnMut(<Self::Elem as Scalar>::Complex, Self::Elem) -> bool;
+}
+
+fn qz_impl<A>(
+    mut a: Array2<A>,
+    mut b: Array2<A>,
+    select: Option<&mut dyn FnMut(A::Complex, A) -> bool>,
+) -> Result<QZDecomposition<A>>
+where
+    A: Scalar + Lapack,
+{
+    let layout = a.square_layout()?;
+    let lax::qz::QZOwned {
+        alpha,
+        beta,
+        vsl,
+        vsr,
+        sdim,
+    } = A::qz(
+        true,
+        layout,
+        a.as_allocated_mut()?,
+        b.as_allocated_mut()?,
+        select,
+    )?;
+    Ok(QZDecomposition {
+        q: into_matrix(layout, vsl.unwrap())?,
+        z: into_matrix(layout, vsr.unwrap())?,
+        s: a,
+        t: b,
+        alpha: ArrayBase::from(alpha),
+        beta: ArrayBase::from(beta),
+        sdim: sdim as usize,
+    })
+}
+
+impl<A, S, S2> QZ for (ArrayBase<S, Ix2>, ArrayBase<S2, Ix2>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    type Elem = A;
+
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn qz(&self) -> Result<QZDecomposition<A>> {
+        let (a, b) = pair_to_owned(&self.0, &self.1);
+        qz_impl(a, b, None)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the shapes of the matrices are different.
+    fn qz_sorted<F>(&self, mut select: F) -> Result<QZDecomposition<A>>
+    where
+        F: FnMut(A::Complex, A) -> bool,
+    {
+        let (a, b) = pair_to_owned(&self.0, &self.1);
+        qz_impl(a, b, Some(&mut select))
+    }
+}
+
+/// Copy a pair of matrices into the same layout
+fn pair_to_owned<A, S, S2>(a: &ArrayBase<S, Ix2>, b: &ArrayBase<S2, Ix2>) -> (Array2<A>, Array2<A>)
+where
+    A: Scalar,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    assert_eq!(
+        a.shape(),
+        b.shape(),
+        "The shapes of the matrices must be identical.",
+    );
+    let mut a_ = Array2::zeros(a.dim().f());
+    a_.assign(a);
+    let mut b_ = Array2::zeros(b.dim().f());
+    b_.assign(b);
+    (a_, b_)
+}
@@ -0,0 +1,164 @@
+//! Schur decomposition for general square matrices
+//!
+//! For a square matrix `A`, this computes `A = Z T Z^H`
+//! where `Z` is unitary (orthogonal for real matrices) and
+//! `T` is the Schur form of `A`.
+//! `T` is upper triangular for complex matrices,
+//! and upper quasi-triangular with 1x1 and 2x2 diagonal blocks for real matrices
+//! where each 2x2 block corresponds to a pair of complex conjugate eigenvalues.
+//!
+//! Unlike eigenvectors, `Z` is always well-conditioned,
+//! and the decomposition is backward stable.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! let a: Array2<f64> = array![
+//!     [1.0, 2.0, 3.0],
+//!     [4.0, 5.0, 6.0],
+//!     [7.0, 8.0, 0.0],
+//! ];
+//! let (z, t) = a.schur().unwrap();
+//! assert_close_l2!(&z.dot(&t).dot(&z.t()), &a, 1e-7);
+//! ```
+
+use ndarray::*;
+
+use crate::convert::*;
+use crate::error::*;
+use crate::layout::*;
+use crate::types::*;
+
+/// Schur decomposition of general matrix reference
+pub trait Schur {
+    type Elem: Scalar;
+    type Z;
+    type T;
+
+    /// Compute the Schur vectors `Z` and the Schur form `T`
+    fn schur(&self) -> Result<(Self::Z, Self::T)>;
+
+    /// Compute the Schur decomposition with reordering eigenvalues
+    ///
+    /// The eigenvalues for which `select` returns `true` are moved to
+    /// the leading diagonal block of `T`, and the number of these eigenvalues `sdim` is returned.
+    /// The first `sdim` columns of `Z` span the corresponding invariant subspace.
+    /// For real matrices, a complex conjugate pair is selected if either of them is selected,
+    /// and thus counted twice in `sdim`.
+    fn schur_sorted<F>(&self, select: F) -> Result<(Self::Z, Self::T, usize)>
+    where
+        F: FnMut(<Self::Elem as Scalar>::Complex) -> bool;
+}
+
+/// Schur decomposition of mutable reference of general matrix
+///
+/// The matrix is overwritten by its Schur form `T`.
+pub trait SchurInplace {
+    type Elem: Scalar;
+    type Z;
+
+    fn schur_inplace(&mut self) -> Result<(Self::Z, &mut Self)>;
+
+    fn schur_sorted_inplace<F>(&mut self, select: F) -> Result<(Self::Z, &mut Self, usize)>
+    where
+        F: FnMut(<Self::Elem as Scalar>::Complex) -> bool;
+}
+
+/// Schur decomposition of general matrix
+///
+/// The matrix is consumed and returned as its Schur form `T`.
+pub trait SchurInto: Sized {
+    type Elem: Scalar;
+    type Z;
+
+    fn schur_into(self) -> Result<(Self::Z, Self)>;
+
+    fn schur_sorted_into<F>(self, select: F) -> Result<(Self::Z, Self, usize)>
+    where
+        F: FnMut(<Self::Elem as Scalar>::Complex) -> bool;
+}
+
+fn schur_impl<A, S>(
+    a: &mut ArrayBase<S, Ix2>,
+    select: Option<&mut dyn FnMut(A::Complex) -> bool>,
+) -> Result<(Array2<A>, usize)>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    let layout = a.square_layout()?;
+    let lax::schur::SchurOwned { vs, sdim, .. } =
+        A::schur(true, layout, a.as_allocated_mut()?, select)?;
+    let z = into_matrix(layout, vs.unwrap())?;
+    Ok((z, sdim as usize))
+}
+
+impl<A, S> SchurInplace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Elem = A;
+    type Z = Array2<A>;
+
+    fn schur_inplace(&mut self) -> Result<(Self::Z, &mut Self)> {
+        let (z, _) = schur_impl(self, None)?;
+        Ok((z, self))
+    }
+
+    fn schur_sorted_inplace<F>(&mut self, mut select: F) -> Result<(Self::Z, &mut Self, usize)>
+    where
+        F: FnMut(A::Complex) -> bool,
+    {
+        let (z, sdim) = schur_impl(self, Some(&mut select))?;
+        Ok((z, self, sdim))
+    }
+}
+
+impl<A, S> SchurInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Elem = A;
+    type Z = Array2<A>;
+
+    fn schur_into(mut self) -> Result<(Self::Z, Self)> {
+        let (z, _) = self.schur_inplace()?;
+        Ok((z, self))
+    }
+
+    fn schur_sorted_into<F>(mut self, select: F) -> Result<(Self::Z, Self, usize)>
+    where
+        F: FnMut(A::Complex) -> bool,
+    {
+        let (z, _, sdim) = self.schur_sorted_inplace(select)?;
+        Ok((z, self, sdim))
+    }
+}
+
+impl<A, S> Schur for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+    type Z = Array2<A>;
+    type T = Array2<A>;
+
+    fn schur(&self) -> Result<(Self::Z, Self::T)> {
+        let a = self.to_owned();
+        a.schur_into()
+    }
+
+    fn schur_sorted<F>(&self, select: F) -> Result<(Self::Z, Self::T, usize)>
+    where
+        F: FnMut(A::Complex) -> bool,
+    {
+        let a = self.to_owned();
+        a.schur_sorted_into(select)
+    }
+}
@@ -104,11 +104,25 @@ pub trait SolveH<A: Scalar> {
 
 /// Represents the Bunch–Kaufman factorization of a Hermitian (or real
 /// symmetric) matrix as `A = P * U * D * U^H * P^T`.
+///
+/// Use [BKFactorized::factors] to obtain `U`, `D` and `P` explicitly.
 pub struct BKFactorized<S: Data> {
     pub a: ArrayBase<S, Ix2>,
     pub ipiv: Pivot,
 }
 
+/// Represents the bounded Bunch–Kaufman ("rook") factorization of a Hermitian
+/// (or real symmetric) matrix as `A = P * U * D * U^H * P^T` computed by `*sytrf_rook`.
+///
+/// Unlike [BKFactorized], the elements of `U` are bounded at the cost of
+/// searching more pivot candidates, and both of the interchanges for each
+/// 2x2 block of `D` are recorded in `ipiv`.
+/// Use [BKRookFactorized::factors] to obtain `U`, `D` and `P` explicitly.
+pub struct BKRookFactorized<S: Data> {
+    pub a: ArrayBase<S, Ix2>,
+    pub ipiv: Pivot,
+}
+
 impl<A, S> SolveH<A> for BKFactorized<S>
 where
     A: Scalar + Lapack,
@@ -137,6 +151,34 @@ where
     }
 }
 
+impl<A, S> SolveH<A> for BKRookFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn solveh_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix1>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix1>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            rhs.len(),
+            self.a.len_of(Axis(1)),
+            "The length of `rhs` must be compatible with the shape of the factored matrix.",
+        );
+        A::solveh_rook(
+            self.a.square_layout()?,
+            UPLO::Upper,
+            self.a.as_allocated()?,
+            &self.ipiv,
+            rhs.as_slice_mut().unwrap(),
+        )?;
+        Ok(rhs)
+    }
+}
+
 impl<A, S> SolveH<A> for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -193,6 +235,45 @@ where
     }
 }
 
+/// An interface for computing the bounded Bunch–Kaufman ("rook") factorization
+/// of Hermitian (or real symmetric) matrix refs.
+pub trait FactorizeHRook<S: Data> {
+    /// Computes the bounded Bunch–Kaufman ("rook") factorization of a
+    /// Hermitian (or real symmetric) matrix.
+    fn factorizeh_rook(&self) -> Result<BKRookFactorized<S>>;
+}
+
+/// An interface for computing the bounded Bunch–Kaufman ("rook") factorization
+/// of Hermitian (or real symmetric) matrices.
+pub trait FactorizeHRookInto<S: Data> {
+    /// Computes the bounded Bunch–Kaufman ("rook") factorization of a
+    /// Hermitian (or real symmetric) matrix.
+    fn factorizeh_rook_into(self) -> Result<BKRookFactorized<S>>;
+}
+
+impl<A, S> FactorizeHRookInto<S> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    fn factorizeh_rook_into(mut self) -> Result<BKRookFactorized<S>> {
+        let ipiv = A::bk_rook(self.square_layout()?, UPLO::Upper, self.as_allocated_mut()?)?;
+        Ok(BKRookFactorized { a: self, ipiv })
+    }
+}
+
+impl<A, Si> FactorizeHRook<OwnedRepr<A>> for ArrayBase<Si, Ix2>
+where
+    A: Scalar + Lapack,
+    Si: Data<Elem = A>,
+{
+    fn factorizeh_rook(&self) -> Result<BKRookFactorized<OwnedRepr<A>>> {
+        let mut a: Array2<A> = replicate(self);
+        let ipiv = A::bk_rook(a.square_layout()?, UPLO::Upper, a.as_allocated_mut()?)?;
+        Ok(BKRookFactorized { a, ipiv })
+    }
+}
+
 /// An interface for inverting Hermitian (or real symmetric) matrix refs.
 pub trait InverseH {
     type Output;
@@ -242,6 +323,48 @@ where
     }
 }
 
+impl<A, S> InverseHInto for BKRookFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type Output = ArrayBase<S, Ix2>;
+
+    fn invh_into(mut self) -> Result<ArrayBase<S, Ix2>> {
+        // LAPACK does not provide `*sytri_rook`, so solve `A * X = I` column by column
+        let n = self.a.nrows();
+        let mut inv = Array2::<A>::zeros((n, n));
+        for (i, mut col) in inv.axis_iter_mut(Axis(1)).enumerate() {
+            let mut e = Array1::<A>::zeros(n);
+            e[i] = A::one();
+            self.solveh_inplace(&mut e)?;
+            col.assign(&e);
+        }
+        // The factorized matrix is the transpose of `A` for row-major layout
+        match self.a.layout()? {
+            MatrixLayout::C { .. } => self.a.assign(&inv.t()),
+            MatrixLayout::F { .. } => self.a.assign(&inv),
+        }
+        Ok(self.a)
+    }
+}
+
+impl<A, S> InverseH for BKRookFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Output = Array2<A>;
+
+    fn invh(&self) -> Result<Self::Output> {
+        let f = BKRookFactorized {
+            a: replicate(&self.a),
+            ipiv: self.ipiv.clone(),
+        };
+        f.invh_into()
+    }
+}
+
 impl<A, S> InverseHInto for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -316,16 +439,23 @@ pub trait DeterminantHInto {
     fn sln_deth_into(self) -> Result<(<Self::Elem as Scalar>::Real, <Self::Elem as Scalar>::Real)>;
 }
 
-/// Returns the sign and natural log of the determinant.
-fn bk_sln_det<P, S, A>(uplo: UPLO, ipiv_iter: P, a: &ArrayBase<S, Ix2>) -> (A::Real, A::Real)
+/// Diagonal block of `D` in the Bunch–Kaufman factorization
+enum BKBlock<R> {
+    /// 1x1 block `d`
+    One(R),
+    /// 2x2 block `[a b; b^* c]` as `(a, c, |b|^2)`
+    Two(R, R, R),
+}
+
+/// Returns the 1x1 and 2x2 diagonal blocks of `D` from the top.
+fn bk_blocks<P, S, A>(uplo: UPLO, ipiv_iter: P, a: &ArrayBase<S, Ix2>) -> Vec<BKBlock<A::Real>>
 where
     P: Iterator<Item = i32>,
     S: Data<Elem = A>,
     A: Scalar + Lapack,
 {
     let layout = a.layout().unwrap();
-    let mut sign = A::Real::one();
-    let mut ln_det = A::Real::zero();
+    let mut blocks = Vec::new();
     let mut ipiv_enum = ipiv_iter.enumerate();
     while let Some((k, ipiv_k)) = ipiv_enum.next() {
         debug_assert!(k < a.nrows() && k < a.ncols());
@@ -333,8 +463,7 @@ where
             // 1x1 block at k, must be real.
             let elem = unsafe { a.uget((k, k)) }.re();
             debug_assert_eq!(elem.im(), Zero::zero());
-            sign *= elem.signum();
-            ln_det += Float::ln(Float::abs(elem));
+            blocks.push(BKBlock::One(elem));
         } else {
             // 2x2 block at k..k+2.
 
@@ -357,24 +486,180 @@ where
                     UPLO::Lower => unsafe { a.uget((k + 1, k)) },
                 },
             };
-
-            // Determinant of 2x2 block.
-            let block_det = upper_diag * lower_diag - off_diag.square();
-            sign *= block_det.signum();
-            ln_det += Float::ln(Float::abs(block_det));
+            blocks.push(BKBlock::Two(upper_diag, lower_diag, off_diag.square()));
 
             // Skip the k+1 ipiv value.
             ipiv_enum.next();
         }
     }
+    blocks
+}
+
+/// Returns the sign and natural log of the determinant.
+fn bk_sln_det<P, S, A>(uplo: UPLO, ipiv_iter: P, a: &ArrayBase<S, Ix2>) -> (A::Real, A::Real)
+where
+    P: Iterator<Item = i32>,
+    S: Data<Elem = A>,
+    A: Scalar + Lapack,
+{
+    let mut sign = A::Real::one();
+    let mut ln_det = A::Real::zero();
+    for block in bk_blocks(uplo, ipiv_iter, a) {
+        let det = match block {
+            BKBlock::One(elem) => elem,
+            // Determinant of 2x2 block.
+            BKBlock::Two(upper_diag, lower_diag, off_diag_sq) => {
+                upper_diag * lower_diag - off_diag_sq
+            }
+        };
+        sign *= det.signum();
+        ln_det += Float::ln(Float::abs(det));
+    }
     (sign, ln_det)
 }
 
+/// Inertia of Hermitian (or real symmetric) matrix, i.e. the numbers of
+/// positive, negative and zero eigenvalues
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Inertia {
+    pub positive: usize,
+    pub negative: usize,
+    pub zero: usize,
+}
+
+/// Returns the inertia of `D`, which equals to that of `A` by Sylvester's law of inertia.
+fn bk_inertia<P, S, A>(uplo: UPLO, ipiv_iter: P, a: &ArrayBase<S, Ix2>) -> Inertia
+where
+    P: Iterator<Item = i32>,
+    S: Data<Elem = A>,
+    A: Scalar + Lapack,
+{
+    let mut inertia = Inertia {
+        positive: 0,
+        negative: 0,
+        zero: 0,
+    };
+    let mut count = |x: A::Real| {
+        if x > A::Real::zero() {
+            inertia.positive += 1;
+        } else if x < A::Real::zero() {
+            inertia.negative += 1;
+        } else {
+            inertia.zero += 1;
+        }
+    };
+    for block in bk_blocks(uplo, ipiv_iter, a) {
+        match block {
+            BKBlock::One(elem) => count(elem),
+            BKBlock::Two(upper_diag, lower_diag, off_diag_sq) => {
+                // The product and sum of the two eigenvalues of the 2x2 block
+                let det = upper_diag * lower_diag - off_diag_sq;
+                let trace = upper_diag + lower_diag;
+                if det < A::Real::zero() {
+                    count(A::Real::one());
+                    count(-A::Real::one());
+                } else if det > A::Real::zero() {
+                    count(trace);
+                    count(trace);
+                } else {
+                    count(trace);
+                    count(A::Real::zero());
+                }
+            }
+        }
+    }
+    inertia
+}
+
+/// Unpacks `(U, D, p)` from the factorization computed with `UPLO::Upper`.
+///
+/// Both of the interchanges for each 2x2 block are recorded in `ipiv` if `rook` is true.
+fn bk_factors<A, S>(
+    a: &ArrayBase<S, Ix2>,
+    ipiv: &Pivot,
+    rook: bool,
+) -> (Array2<A>, Array2<A>, Array1<usize>)
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    let n = a.nrows();
+    let layout = a.layout().unwrap();
+    // Element of the matrix factorized by LAPACK, which is the transpose
+    // (i.e. conjugate for Hermitian matrix) of `A` for row-major layout
+    let get = |i: usize, j: usize| match layout {
+        MatrixLayout::C { .. } => a[(j, i)].conj(),
+        MatrixLayout::F { .. } => a[(i, j)],
+    };
+
+    let mut u = Array2::<A>::eye(n);
+    let mut d = Array2::<A>::zeros((n, n));
+    let mut p: Array1<usize> = (0..n).collect();
+    // LAPACK eliminates the blocks from the bottom for `UPLO::Upper`,
+    // i.e. `U = P(n) * U(n) * ... * P(k) * U(k) * ...`
+    let mut k = n;
+    while k > 0 {
+        let (s, interchanges) = if ipiv[k - 1] > 0 {
+            // 1x1 block at k-1
+            (1, vec![(k - 1, ipiv[k - 1] as usize - 1)])
+        } else {
+            // 2x2 block at k-2..k
+            let interchanges = if rook {
+                vec![
+                    (k - 1, (-ipiv[k - 1]) as usize - 1),
+                    (k - 2, (-ipiv[k - 2]) as usize - 1),
+                ]
+            } else {
+                vec![(k - 2, (-ipiv[k - 1]) as usize - 1)]
+            };
+            (2, interchanges)
+        };
+        for j in k - s..k {
+            for i in k - s..k {
+                d[(i, j)] = if i <= j { get(i, j) } else { get(j, i).conj() };
+            }
+            for i in 0..k - s {
+                u[(i, j)] = get(i, j);
+            }
+        }
+        // Move `P(k)` to the left of `U(k+1), ..., U(n)` eliminated before
+        for (r1, r2) in interchanges {
+            for j in k..n {
+                u.swap((r1, j), (r2, j));
+            }
+            p.swap(r1, r2);
+        }
+        k -= s;
+    }
+    (u, d, p)
+}
+
 impl<A, S> BKFactorized<S>
 where
     A: Scalar + Lapack,
     S: Data<Elem = A>,
 {
+    /// Computes the inertia of the factorized Hermitian (or real symmetric)
+    /// matrix from the 1x1 and 2x2 diagonal blocks of `D`.
+    pub fn inertia(&self) -> Inertia {
+        bk_inertia(UPLO::Upper, self.ipiv.iter().cloned(), &self.a)
+    }
+
+    /// Unpacks the factorization into `(U, D, p)`.
+    ///
+    /// `U` is a unit upper triangular matrix, `D` is a Hermitian (or real
+    /// symmetric) block diagonal matrix consisting of 1x1 and 2x2 blocks, and
+    /// `p` is the permutation as an array of indices satisfying
+    /// `A.select(Axis(0), &p).select(Axis(1), &p) = U * D * U^H`.
+    /// The elements of `U` coupled to a 2x2 block of `D` form an identity block.
+    ///
+    /// Only this upper form is available since the factorization is always
+    /// computed with `UPLO::Upper`; the lower form `L * D * L^H` with unit lower
+    /// triangular `L` is not supported.
+    pub fn factors(&self) -> (Array2<A>, Array2<A>, Array1<usize>) {
+        bk_factors(&self.a, &self.ipiv, false)
+    }
+
     /// Computes the determinant of the factorized Hermitian (or real
     /// symmetric) matrix.
     pub fn deth(&self) -> A::Real {
@@ -424,6 +709,33 @@ where
     }
 }
 
+impl<A, S> BKRookFactorized<S>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    /// Computes the inertia of the factorized Hermitian (or real symmetric)
+    /// matrix from the 1x1 and 2x2 diagonal blocks of `D`.
+    pub fn inertia(&self) -> Inertia {
+        bk_inertia(UPLO::Upper, self.ipiv.iter().cloned(), &self.a)
+    }
+
+    /// Unpacks the factorization into `(U, D, p)`.
+    ///
+    /// `U` is a unit upper triangular matrix, `D` is a Hermitian (or real
+    /// symmetric) block diagonal matrix consisting of 1x1 and 2x2 blocks, and
+    /// `p` is the permutation as an array of indices satisfying
+    /// `A.select(Axis(0), &p).select(Axis(1), &p) = U * D * U^H`.
+    /// The elements of `U` coupled to a 2x2 block of `D` form an identity block.
+    ///
+    /// Only this upper form is available since the factorization is always
+    /// computed with `UPLO::Upper`; the lower form `L * D * L^H` with unit lower
+    /// triangular `L` is not supported.
+    pub fn factors(&self) -> (Array2<A>, Array2<A>, Array1<usize>) {
+        bk_factors(&self.a, &self.ipiv, true)
+    }
+}
+
 impl<A, S> DeterminantH for ArrayBase<S, Ix2>
 where
     A: Scalar + Lapack,
@@ -475,3 +787,54 @@ where
         }
     }
 }
+
+/// An interface for counting eigenvalues of Hermitian (or real symmetric)
+/// matrix refs without computing them.
+///
+/// These are computed from the Bunch–Kaufman factorization by Sylvester's law
+/// of inertia. Unlike [FactorizeH], an exactly singular matrix is not an error
+/// since LAPACK completes the factorization in that case, and each exactly zero
+/// 1x1 block of `D` is counted as a zero eigenvalue.
+pub trait InertiaH {
+    /// The element type of the matrix.
+    type Elem: Scalar;
+
+    /// Computes the numbers of positive, negative and zero eigenvalues of
+    /// the Hermitian (or real symmetric) matrix.
+    fn inertia(&self) -> Result<Inertia>;
+
+    /// Counts the eigenvalues less than `sigma` of the Hermitian (or real
+    /// symmetric) matrix `A` from the inertia of `A - sigma * I`.
+    fn count_eigenvalues_below(&self, sigma: <Self::Elem as Scalar>::Real) -> Result<usize>;
+}
+
+impl<A, S> InertiaH for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Elem = A;
+
+    fn inertia(&self) -> Result<Inertia> {
+        let a: Array2<A> = replicate(self);
+        bk_inertia_into(a)
+    }
+
+    fn count_eigenvalues_below(&self, sigma: A::Real) -> Result<usize> {
+        let mut a: Array2<A> = replicate(self);
+        for x in a.diag_mut() {
+            *x -= A::from_real(sigma);
+        }
+        Ok(bk_inertia_into(a)?.negative)
+    }
+}
+
+/// Factorizes `a` and returns its inertia even if `a` is exactly singular.
+fn bk_inertia_into<A, S>(mut a: ArrayBase<S, Ix2>) -> Result<Inertia>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    let (ipiv, _) = A::bk_with_info(a.square_layout()?, UPLO::Upper, a.as_allocated_mut()?)?;
+    Ok(bk_inertia(UPLO::Upper, ipiv.into_iter(), &a))
+}
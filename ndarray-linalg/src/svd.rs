@@ -2,7 +2,7 @@
 //!
 //! [Wikipedia article on SVD](https://en.wikipedia.org/wiki/Singular_value_decomposition)
 
-use crate::{convert::*, error::*, layout::*, types::*};
+use crate::{convert::*, error::*, layout::*, types::*, EigRange};
 use ndarray::*;
 
 /// singular-value decomposition of matrix reference
@@ -104,3 +104,51 @@ where
         Ok((u, s, vt))
     }
 }
+
+/// Singular-value decomposition of matrix reference for selected singular values
+///
+/// Only the singular values selected by [EigRange] and their singular vectors are computed.
+/// Singular values are sorted in descending order,
+/// e.g. `EigRange::Index(0..k)` selects the `k` largest singular values,
+/// which is an exact dense alternative to [TruncatedSvd](crate::TruncatedSvd).
+pub trait SVDRange<A: Scalar> {
+    type U;
+    type VT;
+    type Sigma;
+    fn svd_range(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+        range: EigRange<A::Real>,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+}
+
+impl<A, S> SVDRange<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_range(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+        range: EigRange<A::Real>,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let mut a = self.to_owned();
+        let l = a.layout()?;
+        let svd_res = A::svd_range(l, calc_u, calc_vt, range, a.as_allocated_mut()?)?;
+        let (n, m) = l.size();
+        let k = svd_res.s.len() as i32;
+
+        let u = svd_res.u.map(|u| into_matrix(l.resized(n, k), u).unwrap());
+        let vt = svd_res
+            .vt
+            .map(|vt| into_matrix(l.resized(k, m), vt).unwrap());
+        let s = ArrayBase::from(svd_res.s);
+        Ok((u, s, vt))
+    }
+}
@@ -0,0 +1,128 @@
+//! Singular-value decomposition (SVD) by one-sided Jacobi method (?gejsv)
+//!
+//! This computes the singular values to high relative accuracy,
+//! i.e. small singular values of graded or badly scaled matrices are as accurate as large ones,
+//! which [SVD](crate::svd) and [SVDDC](crate::svddc) do not guarantee.
+//!
+//! # Example
+//!
+//! ```
+//! use ndarray::*;
+//! use ndarray_linalg::*;
+//!
+//! // graded matrix
+//! let a: Array2<f64> = array![
+//!     [1.0, 1e-10, 0.0],
+//!     [1e-10, 1e-20, 1e-30],
+//!     [0.0, 1e-30, 1e-40],
+//! ];
+//! let (u, s, vt) = a.svd_jacobi(true, true).unwrap();
+//! let (u, vt) = (u.unwrap(), vt.unwrap());
+//! let sm = Array2::from_diag(&s);
+//! assert_close_l2!(&u.dot(&sm).dot(&vt), &a, 1e-7);
+//! ```
+
+use crate::{convert::*, error::*, layout::*, types::*};
+use ndarray::*;
+
+/// Singular-value decomposition of matrix reference by one-sided Jacobi method
+pub trait SVDJacobi {
+    type U;
+    type VT;
+    type Sigma;
+    fn svd_jacobi(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+}
+
+/// Singular-value decomposition by one-sided Jacobi method
+pub trait SVDJacobiInto {
+    type U;
+    type VT;
+    type Sigma;
+    fn svd_jacobi_into(
+        self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+}
+
+/// Singular-value decomposition for mutable reference of matrix by one-sided Jacobi method
+///
+/// The matrix is destroyed.
+pub trait SVDJacobiInplace {
+    type U;
+    type VT;
+    type Sigma;
+    fn svd_jacobi_inplace(
+        &mut self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)>;
+}
+
+impl<A, S> SVDJacobiInto for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_jacobi_into(
+        mut self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        self.svd_jacobi_inplace(calc_u, calc_vt)
+    }
+}
+
+impl<A, S> SVDJacobi for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_jacobi(
+        &self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let a = self.to_owned();
+        a.svd_jacobi_into(calc_u, calc_vt)
+    }
+}
+
+impl<A, S> SVDJacobiInplace for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    type U = Array2<A>;
+    type VT = Array2<A>;
+    type Sigma = Array1<A::Real>;
+
+    fn svd_jacobi_inplace(
+        &mut self,
+        calc_u: bool,
+        calc_vt: bool,
+    ) -> Result<(Option<Self::U>, Self::Sigma, Option<Self::VT>)> {
+        let l = self.layout()?;
+        let svd_res = A::svd_jacobi(l, calc_u, calc_vt, self.as_allocated_mut()?)?;
+        let (m, n) = l.size();
+
+        let u = svd_res.u.map(|u| into_matrix(l.resized(m, m), u).unwrap());
+        let vt = svd_res
+            .vt
+            .map(|vt| into_matrix(l.resized(n, n), vt).unwrap());
+        let s = ArrayBase::from(svd_res.s);
+        Ok((u, s, vt))
+    }
+}
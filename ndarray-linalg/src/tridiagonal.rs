@@ -8,9 +8,9 @@ use super::layout::*;
 use cauchy::Scalar;
 use lax::*;
 use ndarray::*;
-use num_traits::One;
+use num_traits::{One, Zero};
 
-pub use lax::{LUFactorizedTridiagonal, Tridiagonal};
+pub use lax::{EigRange, LDLFactorizedTridiagonal, LUFactorizedTridiagonal, Tridiagonal};
 
 /// An interface for making a Tridiagonal struct.
 pub trait ExtractTridiagonal<A: Scalar> {
@@ -46,6 +46,139 @@ where
     }
 }
 
+/// Reduce symmetric/hermite matrix into real symmetric tridiagonal form
+///
+/// This computes `A = Q T Q^H` where `Q` is unitary (orthogonal for real matrices)
+/// and `T` is real symmetric tridiagonal.
+/// Only the triangle of `A` specified by `uplo` is referenced.
+pub trait Tridiagonalize<A: Scalar> {
+    type Q;
+    fn tridiagonalize(&self, uplo: UPLO) -> Result<(Self::Q, Tridiagonal<A>)>;
+}
+
+/// Reduce symmetric/hermite matrix into real symmetric tridiagonal form
+///
+/// The input matrix is overwritten by `Q`.
+pub trait TridiagonalizeInto<A: Scalar>: Sized {
+    fn tridiagonalize_into(self, uplo: UPLO) -> Result<(Self, Tridiagonal<A>)>;
+}
+
+impl<A, S> TridiagonalizeInto<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: DataMut<Elem = A>,
+{
+    fn tridiagonalize_into(mut self, uplo: UPLO) -> Result<(Self, Tridiagonal<A>)> {
+        let l = self.square_layout()?;
+        let (t, tau) = A::tridiagonalize(l, uplo, self.as_allocated_mut()?)?;
+        A::tridiagonalize_q(l, uplo, self.as_allocated_mut()?, &tau)?;
+        Ok((self, t))
+    }
+}
+
+impl<A, S> Tridiagonalize<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    type Q = Array2<A>;
+
+    fn tridiagonalize(&self, uplo: UPLO) -> Result<(Self::Q, Tridiagonal<A>)> {
+        let a = self.to_owned();
+        a.tridiagonalize_into(uplo)
+    }
+}
+
+/// Eigenvalue problem for symmetric/hermite tridiagonal matrix
+///
+/// Only `d` and `dl` of [Tridiagonal] are referenced,
+/// i.e. `du` is assumed to be the complex conjugate of `dl`.
+/// Eigenvalues are returned in ascending order,
+/// and eigenvectors are returned as the columns of the matrix.
+pub trait EighTridiagonal<A: Scalar> {
+    /// Compute all eigenvalues and eigenvectors by divide and conquer method
+    fn eigh_tridiagonal(&self) -> Result<(Array1<A::Real>, Array2<A>)>;
+
+    /// Compute all eigenvalues
+    fn eigvalsh_tridiagonal(&self) -> Result<Array1<A::Real>>;
+
+    /// Compute the eigenvalues selected by `range` and their eigenvectors
+    fn eigh_tridiagonal_range(
+        &self,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)>;
+
+    /// Compute the eigenvalues selected by `range` by bisection
+    fn eigvalsh_tridiagonal_range(&self, range: EigRange<A::Real>) -> Result<Array1<A::Real>>;
+}
+
+/// Split hermite tridiagonal matrix `T` into `D T_r D^H`,
+/// where `T_r` is real symmetric tridiagonal and `D` is diagonal with unit-modulus elements.
+///
+/// Returns the diagonal and off-diagonal elements of `T_r`, and the diagonal elements of `D`.
+fn real_symmetric_tridiagonal<A: Scalar>(
+    t: &Tridiagonal<A>,
+) -> (Vec<A::Real>, Vec<A::Real>, Vec<A>) {
+    let d = t.d.iter().map(|x| x.re()).collect();
+    let e: Vec<A::Real> = t.dl.iter().map(|x| x.abs()).collect();
+    let mut phase = Vec::with_capacity(t.d.len());
+    let mut p = A::one();
+    phase.push(p);
+    for (dl, e) in t.dl.iter().zip(e.iter()) {
+        if !e.is_zero() {
+            p *= *dl / A::from_real(*e);
+        }
+        phase.push(p);
+    }
+    (d, e, phase)
+}
+
+/// Transform eigenvectors of `T_r` into those of `T = D T_r D^H`
+fn restore_eigenvectors<A: Scalar>(phase: &[A], z: Vec<A>) -> Array2<A> {
+    let n = phase.len();
+    let m = z.len() / n.max(1);
+    let mut z = Array2::from_shape_vec((n, m).f(), z).unwrap();
+    for (mut row, p) in z.axis_iter_mut(Axis(0)).zip(phase.iter()) {
+        row.map_inplace(|x| *x *= *p);
+    }
+    z
+}
+
+impl<A> EighTridiagonal<A> for Tridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn eigh_tridiagonal(&self) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let (mut d, mut e, phase) = real_symmetric_tridiagonal(self);
+        let z = A::eigh_tridiagonal_dc(true, &mut d, &mut e)?;
+        Ok((ArrayBase::from(d), restore_eigenvectors(&phase, z.unwrap())))
+    }
+
+    fn eigvalsh_tridiagonal(&self) -> Result<Array1<A::Real>> {
+        let (mut d, mut e, _) = real_symmetric_tridiagonal(self);
+        A::eigh_tridiagonal(false, &mut d, &mut e)?;
+        Ok(ArrayBase::from(d))
+    }
+
+    fn eigh_tridiagonal_range(
+        &self,
+        range: EigRange<A::Real>,
+    ) -> Result<(Array1<A::Real>, Array2<A>)> {
+        let (mut d, mut e, phase) = real_symmetric_tridiagonal(self);
+        let res = A::eigh_tridiagonal_range(true, range, &mut d, &mut e)?;
+        Ok((
+            ArrayBase::from(res.eigs),
+            restore_eigenvectors(&phase, res.z.unwrap()),
+        ))
+    }
+
+    fn eigvalsh_tridiagonal_range(&self, range: EigRange<A::Real>) -> Result<Array1<A::Real>> {
+        let (d, e, _) = real_symmetric_tridiagonal(self);
+        let res = A::eigh_tridiagonal_bisect(false, range, &d, &e)?;
+        Ok(ArrayBase::from(res.eigs))
+    }
+}
+
 pub trait SolveTridiagonal<A: Scalar, D: Dimension> {
     /// Solves a system of linear equations `A * x = b` with tridiagonal
     /// matrix `A`, where `A` is `self`, `b` is the argument, and
@@ -585,6 +718,189 @@ where
     }
 }
 
+/// An interface for computing `L * D * L^H` factorizations of hermite (or real symmetric)
+/// positive definite tridiagonal matrix refs.
+///
+/// Only the diagonal and sub-diagonal elements are referenced.
+pub trait FactorizeLDLTridiagonal<A: Scalar> {
+    /// Computes the factorization `A = L * D * L^H`, where `L` is unit lower bidiagonal
+    /// and `D` is diagonal.
+    fn factorize_ldl_tridiagonal(&self) -> Result<LDLFactorizedTridiagonal<A>>;
+}
+
+/// An interface for computing `L * D * L^H` factorizations of hermite (or real symmetric)
+/// positive definite tridiagonal matrices.
+///
+/// Only the diagonal and sub-diagonal elements are referenced.
+pub trait FactorizeLDLTridiagonalInto<A: Scalar> {
+    /// Computes the factorization `A = L * D * L^H`, where `L` is unit lower bidiagonal
+    /// and `D` is diagonal.
+    fn factorize_ldl_tridiagonal_into(self) -> Result<LDLFactorizedTridiagonal<A>>;
+}
+
+impl<A> FactorizeLDLTridiagonalInto<A> for Tridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorize_ldl_tridiagonal_into(self) -> Result<LDLFactorizedTridiagonal<A>> {
+        self.factorize_ldl_tridiagonal()
+    }
+}
+
+impl<A> FactorizeLDLTridiagonal<A> for Tridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn factorize_ldl_tridiagonal(&self) -> Result<LDLFactorizedTridiagonal<A>> {
+        Ok(A::ldl_tridiagonal(self)?)
+    }
+}
+
+impl<A, S> FactorizeLDLTridiagonal<A> for ArrayBase<S, Ix2>
+where
+    A: Scalar + Lapack,
+    S: Data<Elem = A>,
+{
+    fn factorize_ldl_tridiagonal(&self) -> Result<LDLFactorizedTridiagonal<A>> {
+        let a = self.extract_tridiagonal()?;
+        Ok(A::ldl_tridiagonal(&a)?)
+    }
+}
+
+impl<A> SolveTridiagonal<A, Ix2> for LDLFactorizedTridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_tridiagonal<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix2>) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_tridiagonal_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_tridiagonal_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_tridiagonal_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_tridiagonal<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix2>,
+    ) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_t_tridiagonal_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_t_tridiagonal_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_t_tridiagonal_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_tridiagonal<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix2>,
+    ) -> Result<Array<A, Ix2>> {
+        let mut b = replicate(b);
+        self.solve_h_tridiagonal_inplace(&mut b)?;
+        Ok(b)
+    }
+    fn solve_h_tridiagonal_into<S: DataMut<Elem = A>>(
+        &self,
+        mut b: ArrayBase<S, Ix2>,
+    ) -> Result<ArrayBase<S, Ix2>> {
+        self.solve_h_tridiagonal_inplace(&mut b)?;
+        Ok(b)
+    }
+}
+
+impl<A> SolveTridiagonalInplace<A, Ix2> for LDLFactorizedTridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_tridiagonal_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        A::solve_ldl_tridiagonal(self, rhs.layout()?, rhs.as_slice_mut().unwrap())?;
+        Ok(rhs)
+    }
+    fn solve_t_tridiagonal_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        // A^T x = b is equivalent to A conj(x) = conj(b) since A is hermite
+        rhs.map_inplace(|x| *x = x.conj());
+        self.solve_tridiagonal_inplace(rhs)?;
+        rhs.map_inplace(|x| *x = x.conj());
+        Ok(rhs)
+    }
+    fn solve_h_tridiagonal_inplace<'a, Sb>(
+        &self,
+        rhs: &'a mut ArrayBase<Sb, Ix2>,
+    ) -> Result<&'a mut ArrayBase<Sb, Ix2>>
+    where
+        Sb: DataMut<Elem = A>,
+    {
+        self.solve_tridiagonal_inplace(rhs)
+    }
+}
+
+impl<A> SolveTridiagonal<A, Ix1> for LDLFactorizedTridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn solve_tridiagonal<S: Data<Elem = A>>(&self, b: &ArrayBase<S, Ix1>) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_tridiagonal_into(b)
+    }
+    fn solve_tridiagonal_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, Ix1>,
+    ) -> Result<ArrayBase<S, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_tridiagonal_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_t_tridiagonal<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_t_tridiagonal_into(b)
+    }
+    fn solve_t_tridiagonal_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, Ix1>,
+    ) -> Result<ArrayBase<S, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_t_tridiagonal_into(b)?;
+        Ok(flatten(b))
+    }
+    fn solve_h_tridiagonal<S: Data<Elem = A>>(
+        &self,
+        b: &ArrayBase<S, Ix1>,
+    ) -> Result<Array<A, Ix1>> {
+        let b = b.to_owned();
+        self.solve_h_tridiagonal_into(b)
+    }
+    fn solve_h_tridiagonal_into<S: DataMut<Elem = A>>(
+        &self,
+        b: ArrayBase<S, Ix1>,
+    ) -> Result<ArrayBase<S, Ix1>> {
+        let b = into_col(b);
+        let b = self.solve_h_tridiagonal_into(b)?;
+        Ok(flatten(b))
+    }
+}
+
 /// Calculates the recurrent relation,
 /// f_k = a_k * f_{k-1} - c_{k-1} * b_{k-1} * f_{k-2}
 /// where {a_1, a_2, ..., a_n} are diagonal elements,
@@ -638,6 +954,18 @@ where
     }
 }
 
+impl<A> DeterminantTridiagonal<A> for LDLFactorizedTridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn det_tridiagonal(&self) -> Result<A> {
+        // det(A) = det(D) since L is unit lower bidiagonal
+        Ok(A::from_real(
+            self.d.iter().fold(A::Real::one(), |acc, d| acc * *d),
+        ))
+    }
+}
+
 /// An interface for *estimating* the reciprocal condition number of tridiagonal matrix refs.
 pub trait ReciprocalConditionNumTridiagonal<A: Scalar> {
     /// *Estimates* the reciprocal of the condition number of the tridiagonal matrix in
@@ -693,3 +1021,21 @@ where
         self.factorize_tridiagonal()?.rcond_tridiagonal_into()
     }
 }
+
+impl<A> ReciprocalConditionNumTridiagonal<A> for LDLFactorizedTridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcond_tridiagonal(&self) -> Result<A::Real> {
+        Ok(A::rcond_ldl_tridiagonal(self)?)
+    }
+}
+
+impl<A> ReciprocalConditionNumTridiagonalInto<A> for LDLFactorizedTridiagonal<A>
+where
+    A: Scalar + Lapack,
+{
+    fn rcond_tridiagonal_into(self) -> Result<A::Real> {
+        self.rcond_tridiagonal()
+    }
+}
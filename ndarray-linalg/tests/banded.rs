@@ -0,0 +1,195 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Random matrix whose elements outside the band are zero
+fn random_banded<T: Scalar + Lapack>(n: usize, kl: usize, ku: usize) -> Array2<T> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<T> = random_using((n, n), &mut rng);
+    Zip::indexed(&mut a).for_each(|(i, j), elt| {
+        if i > j + kl || j > i + ku {
+            *elt = T::zero()
+        }
+    });
+    a
+}
+
+#[test]
+fn extract_banded() {
+    let a: Array2<f64> = arr2(&[
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]);
+    let b = a.extract_banded(2, 1).unwrap();
+    assert_eq!(b.ldab(), 4);
+    for i in 0..4 {
+        for j in 0..4 {
+            if i <= j + 2 && j <= i + 1 {
+                assert_eq!(b[(i as i32, j as i32)], a[(i, j)]);
+            }
+        }
+    }
+    let mut c = Banded::zeros(b.l, 2, 1);
+    c[[0, 0]] = 1.0;
+    c[[2, 0]] = 9.0;
+    c[[2, 3]] = 12.0;
+    assert_eq!(c[[2, 0]], b[[2, 0]]);
+    assert_eq!(c[[2, 3]], b[[2, 3]]);
+}
+
+macro_rules! impl_test_banded {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<solve_banded_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // pentadiagonal and asymmetric bands
+                for &(kl, ku) in &[(2, 2), (1, 3), (3, 0)] {
+                    let a: Array2<$scalar> = random_banded(8, kl, ku);
+                    let banded = a.extract_banded(kl, ku).unwrap();
+                    let x: Array1<$scalar> = random_using(8, &mut rng);
+
+                    let b = a.dot(&x);
+                    let y = banded.solve_banded(&b).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+
+                    let b = a.t().dot(&x);
+                    let y = banded.solve_t_banded(&b).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+
+                    let ah: Array2<$scalar> = conjugate(&a);
+                    let b = ah.dot(&x);
+                    let y = banded.solve_h_banded(&b).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<solve_banded_nrhs_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_banded(7, 3, 3);
+                let f = a.extract_banded(3, 3).unwrap().factorize_banded().unwrap();
+                let x: Array2<$scalar> = random_using((7, 3), &mut rng);
+                let b = a.dot(&x);
+                let y = f.solve_banded(&b).unwrap();
+                assert_close_l2!(&y, &x, $rtol);
+                let mut b_f = Array2::zeros((7, 3).f());
+                b_f.assign(&b);
+                let y = f.solve_banded_into(b_f).unwrap();
+                assert_close_l2!(&y, &x, $rtol);
+            }
+
+            #[test]
+            fn [<rcond_banded_ $scalar>]() {
+                let a: Array2<$scalar> = random_banded(6, 2, 1);
+                let rcond = a.extract_banded(2, 1).unwrap().rcond_banded().unwrap();
+                assert_aclose!(rcond, a.rcond().unwrap(), 1e-3);
+            }
+        }
+    };
+}
+
+impl_test_banded!(f64, 1e-7);
+impl_test_banded!(f32, 1e-3);
+impl_test_banded!(c64, 1e-7);
+impl_test_banded!(c32, 1e-3);
+
+// Hermite positive definite matrix whose elements outside the band are zero
+fn random_hpd_banded<T: Scalar + Lapack>(n: usize, kd: usize) -> Array2<T> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<T> = random_hermite_using(n, &mut rng);
+    Zip::indexed(&mut a).for_each(|(i, j), elt| {
+        if i > j + kd || j > i + kd {
+            *elt = T::zero()
+        } else if i == j {
+            *elt += T::from_real(T::real(4 * kd + 2))
+        }
+    });
+    a
+}
+
+#[test]
+fn extract_banded_hermite() {
+    let a: Array2<f64> = random_hpd_banded(5, 2);
+    let upper = a.extract_banded_hermite(UPLO::Upper, 2).unwrap();
+    let lower = a.extract_banded_hermite(UPLO::Lower, 2).unwrap();
+    assert_eq!(upper.ldab(), 3);
+    for i in 0..5 {
+        for j in 0..5 {
+            if i <= j && j <= i + 2 {
+                assert_eq!(upper[[i as i32, j as i32]], a[(i, j)]);
+                assert_eq!(lower[[j as i32, i as i32]], a[(j, i)]);
+            }
+        }
+    }
+}
+
+macro_rules! impl_test_banded_hermite {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<solvec_banded_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hpd_banded(9, 2);
+                let x: Array1<$scalar> = random_using(9, &mut rng);
+                let b = a.dot(&x);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let banded = a.extract_banded_hermite(uplo, 2).unwrap();
+                    let y = banded.solvec_banded(&b).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+                    let f = banded.factorizec_banded().unwrap();
+                    let y = f.solvec_banded_into(b.clone()).unwrap();
+                    assert_close_l2!(&y, &x, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<solvec_banded_nrhs_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hpd_banded(7, 3);
+                let f = a.extract_banded_hermite(UPLO::Upper, 3).unwrap().factorizec_banded().unwrap();
+                let x: Array2<$scalar> = random_using((7, 3), &mut rng);
+                let b = a.dot(&x);
+                let y = f.solvec_banded(&b).unwrap();
+                assert_close_l2!(&y, &x, $rtol);
+                let mut b_f = Array2::zeros((7, 3).f());
+                b_f.assign(&b);
+                let y = f.solvec_banded_into(b_f).unwrap();
+                assert_close_l2!(&y, &x, $rtol);
+            }
+
+            #[test]
+            fn [<rcondc_banded_ $scalar>]() {
+                let a: Array2<$scalar> = random_hpd_banded(6, 1);
+                let exact = 1.0 / (a.opnorm_one().unwrap() * a.inv().unwrap().opnorm_one().unwrap());
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let rcond = a.extract_banded_hermite(uplo, 1).unwrap().rcondc_banded().unwrap();
+                    // *pbcon estimates a lower bound of the 1-norm of the inverse
+                    assert!(rcond >= exact * (1.0 - 1e-3));
+                    assert!(rcond < exact * 3.0);
+                }
+            }
+
+            #[test]
+            fn [<eigh_banded_ $scalar>]() {
+                let a: Array2<$scalar> = random_hpd_banded(8, 3);
+                let expected = a.eigvalsh(UPLO::Upper).unwrap();
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let banded = a.extract_banded_hermite(uplo, 3).unwrap();
+                    let (w, v) = banded.eigh_banded().unwrap();
+                    assert_close_l2!(&w, &expected, $rtol);
+                    assert_close_l2!(&banded.eigvalsh_banded().unwrap(), &expected, $rtol);
+                    let av = a.dot(&v);
+                    let vw = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)] * <$scalar>::from_real(w[j]));
+                    assert_close_l2!(&av, &vw, $rtol);
+                }
+            }
+        }
+    };
+}
+
+impl_test_banded_hermite!(f64, 1e-7);
+impl_test_banded_hermite!(f32, 1e-3);
+impl_test_banded_hermite!(c64, 1e-7);
+impl_test_banded_hermite!(c32, 1e-3);
@@ -0,0 +1,62 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Test A = U B V^H, U^H U = I, and V^H V = I
+fn test<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let (u, d, e, vt) = a.bidiagonalize(true, true).unwrap();
+    let (u, vt) = (u.unwrap(), vt.unwrap());
+    println!("u = \n{:?}", &u);
+    println!("d = \n{:?}", &d);
+    println!("e = \n{:?}", &e);
+    println!("vt = \n{:?}", &vt);
+    assert_eq!(u.dim(), (m, k));
+    assert_eq!(vt.dim(), (k, n));
+    assert_eq!(d.len(), k);
+    assert_eq!(e.len(), k - 1);
+
+    let mut b: Array2<T> = Array2::zeros((k, k));
+    for i in 0..k {
+        b[(i, i)] = T::from_real(d[i]);
+    }
+    for i in 0..k - 1 {
+        if m >= n {
+            b[(i, i + 1)] = T::from_real(e[i]);
+        } else {
+            b[(i + 1, i)] = T::from_real(e[i]);
+        }
+    }
+    let uh: Array2<T> = conjugate(&u);
+    let v: Array2<T> = conjugate(&vt);
+    assert_close_l2!(&uh.dot(&u), &Array2::eye(k), T::real(1e-7));
+    assert_close_l2!(&vt.dot(&v), &Array2::eye(k), T::real(1e-7));
+    assert_close_l2!(&u.dot(&b).dot(&vt), a, T::real(1e-7));
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $m:expr, $n:expr) => {
+        paste::item! {
+            #[test]
+            fn [<bidiagonal_ $scalar _ $m x $n>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using(($m, $n), &mut rng);
+                test(&a);
+            }
+
+            #[test]
+            fn [<bidiagonal_ $scalar _ $m x $n _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using(($m, $n).f(), &mut rng);
+                test(&a);
+            }
+        }
+    };
+}
+
+impl_test!(f64, 3, 3);
+impl_test!(f64, 4, 3);
+impl_test!(f64, 3, 4);
+impl_test!(c64, 3, 3);
+impl_test!(c64, 4, 3);
+impl_test!(c64, 3, 4);
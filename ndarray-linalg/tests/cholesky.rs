@@ -231,3 +231,42 @@ cholesky_solve!(f64, 1e-9);
 cholesky_solve!(f32, 1e-3);
 cholesky_solve!(c64, 1e-9);
 cholesky_solve!(c32, 1e-3);
+
+macro_rules! cholesky_pivoted {
+    ($elem:ty, $tol:expr, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<cholesky_pivoted_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                // positive semi-definite matrix of rank 3
+                let b: Array2<$elem> = random_using((5, 3), &mut rng);
+                let a = b.dot(&b.t().mapv(|elem| elem.conj()));
+                assert!(a.cholesky(UPLO::Upper).is_err());
+
+                let (upper, p, rank) = a.cholesky_pivoted(UPLO::Upper, $tol).unwrap();
+                assert_eq!(rank, 3);
+                assert_eq!(upper.dim(), (3, 5));
+                assert_close_l2!(
+                    &upper.t().mapv(|elem| elem.conj()).dot(&upper),
+                    &a.select(Axis(0), p.as_slice().unwrap())
+                        .select(Axis(1), p.as_slice().unwrap()),
+                    $rtol
+                );
+
+                let (lower, p, rank) = a.clone().cholesky_pivoted_into(UPLO::Lower, $tol).unwrap();
+                assert_eq!(rank, 3);
+                assert_eq!(lower.dim(), (5, 3));
+                assert_close_l2!(
+                    &lower.dot(&lower.t().mapv(|elem| elem.conj())),
+                    &a.select(Axis(0), p.as_slice().unwrap())
+                        .select(Axis(1), p.as_slice().unwrap()),
+                    $rtol
+                );
+            }
+        }
+    };
+}
+cholesky_pivoted!(f64, 1e-9, 1e-9);
+cholesky_pivoted!(f32, 1e-4, 1e-3);
+cholesky_pivoted!(c64, 1e-9, 1e-9);
+cholesky_pivoted!(c32, 1e-4, 1e-3);
@@ -35,6 +35,28 @@ fn test_eig<T: Scalar>(
     }
 }
 
+// Test beta_i A v_i = alpha_i B v_i for i = 0..n
+fn test_eig_generalized<T: Scalar>(
+    a: ArrayView2<'_, T>,
+    b: ArrayView2<'_, T>,
+    alpha: ArrayView1<'_, T::Complex>,
+    beta: ArrayView1<'_, T>,
+    vecs: ArrayView2<'_, T::Complex>,
+) where
+    T::Complex: Lapack,
+{
+    println!("alpha\n{:+.4}", &alpha);
+    println!("beta\n{:+.4}", &beta);
+    println!("vec\n{:+.4}", &vecs);
+    let a: Array2<T::Complex> = a.map(|v| v.as_c());
+    let b: Array2<T::Complex> = b.map(|v| v.as_c());
+    for ((&al, &be), v) in alpha.iter().zip(beta).zip(vecs.axis_iter(Axis(1))) {
+        let bav = a.dot(&v).mapv(|val| val * be.as_c());
+        let abv = b.dot(&v).mapv(|val| val * al);
+        assert!((&bav - &abv).norm_l2() < T::real(1e-3));
+    }
+}
+
 // Test case for real Eigenvalue problem
 //
 //  -1.01   0.86  -4.60   3.31  -4.81
@@ -265,6 +287,29 @@ macro_rules! impl_test_real {
                 test_eig(a.view(), e2.view(), vecs.view());
             }
 
+            #[test]
+            fn [<$real _eig_generalized>]() {
+                let a = test_matrix_real::<$real>();
+                let b = Array2::<$real>::eye(5);
+                let ((alpha, beta), vecs) = (a.clone(), b.clone()).eig().unwrap();
+                let e = &alpha / &beta.mapv(|v| v.as_c());
+                assert_close_l2!(
+                    &sorted_eigvals(e.view()),
+                    &sorted_eigvals(answer_eig_real::<$real>().view()),
+                    1.0e-3
+                );
+                test_eig_generalized(a.view(), b.view(), alpha.view(), beta.view(), vecs.view());
+            }
+
+            #[test]
+            fn [<$real _eig_generalized_singular>]() {
+                let a = test_matrix_real_t::<$real>();
+                let b = Array2::from_diag(&array![1.0, 1.0, 1.0, 1.0, 0.0]);
+                let ((alpha, beta), vecs) = (a.clone(), b.clone()).eig().unwrap();
+                assert_eq!(beta.iter().filter(|v| v.abs() < 1e-5).count(), 1);
+                test_eig_generalized(a.view(), b.view(), alpha.view(), beta.view(), vecs.view());
+            }
+
         } // paste::item!
     };
 }
@@ -324,6 +369,20 @@ macro_rules! impl_test_complex {
                 test_eig(a.view(), e1.view(), vecs.view());
                 test_eig(a.view(), e2.view(), vecs.view());
             }
+
+            #[test]
+            fn [<$complex _eig_generalized>]() {
+                let a = test_matrix_complex_t::<$complex>();
+                let b = Array2::<$complex>::eye(4);
+                let ((alpha, beta), vecs) = (a.clone(), b.clone()).eig().unwrap();
+                let e = &alpha / &beta;
+                assert_close_l2!(
+                    &sorted_eigvals(e.view()),
+                    &sorted_eigvals(answer_eig_complex::<$complex>().view()),
+                    1.0e-3
+                );
+                test_eig_generalized(a.view(), b.view(), alpha.view(), beta.view(), vecs.view());
+            }
         } // paste::item!
     };
 }
@@ -132,3 +132,118 @@ fn ssqrt_t_lower() {
     println!("ss = {:?}", &ss);
     assert_close_l2!(&ss, &ans, 1e-7);
 }
+
+// Test selected eigenpairs agree with the full eigenvalue decomposition
+fn test_eigh_range<T: Scalar + Lapack>(a: &Array2<T>, uplo: UPLO) {
+    let e = a.eigvalsh(uplo).unwrap();
+    let expected = e.slice(s![1..4]).mapv(T::from_real);
+    let ranges = [
+        EigRange::Index(1..4),
+        EigRange::Value((e[0] + e[1]) / T::real(2.0), (e[3] + e[4]) / T::real(2.0)),
+    ];
+    for range in ranges.iter() {
+        let (w, v) = a.eigh_range(uplo, range.clone()).unwrap();
+        assert_close_l2!(&w.mapv(T::from_real), &expected, T::real(1e-7));
+        let vh: Array2<T> = conjugate(&v);
+        assert_close_l2!(&vh.dot(&v), &Array2::eye(3), T::real(1e-7));
+        let vw = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)] * T::from_real(w[j]));
+        assert_close_l2!(&a.dot(&v), &vw, T::real(1e-7));
+
+        let w = a.eigvalsh_range(uplo, range.clone()).unwrap();
+        assert_close_l2!(&w.mapv(T::from_real), &expected, T::real(1e-7));
+    }
+}
+
+macro_rules! impl_test_eigh_range {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<eigh_range_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng);
+                test_eigh_range(&a, UPLO::Upper);
+                test_eigh_range(&a, UPLO::Lower);
+            }
+
+            #[test]
+            fn [<eigh_range_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng);
+                let mut af = Array2::zeros((6, 6).f());
+                af.assign(&a);
+                test_eigh_range(&af, UPLO::Upper);
+                test_eigh_range(&af, UPLO::Lower);
+            }
+        }
+    };
+}
+
+impl_test_eigh_range!(f64);
+impl_test_eigh_range!(c64);
+
+// Test all drivers give the same eigenvalues and valid eigenvectors
+fn test_eigh_driver<T: Scalar + Lapack>(a: &Array2<T>, uplo: UPLO) {
+    let n = a.nrows();
+    let expected = a.eigvalsh(uplo).unwrap().mapv(T::from_real);
+    for driver in [
+        EighDriver::Qr,
+        EighDriver::DivideAndConquer,
+        EighDriver::Mrrr,
+    ]
+    .iter()
+    {
+        let (e, v) = a.eigh_with(uplo, *driver).unwrap();
+        assert_close_l2!(&e.mapv(T::from_real), &expected, T::real(1e-7));
+        let vh: Array2<T> = conjugate(&v);
+        assert_close_l2!(&vh.dot(&v), &Array2::eye(n), T::real(1e-7));
+        let ve = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)] * T::from_real(e[j]));
+        assert_close_l2!(&a.dot(&v), &ve, T::real(1e-7));
+
+        let e = a.eigvalsh_with(uplo, *driver).unwrap();
+        assert_close_l2!(&e.mapv(T::from_real), &expected, T::real(1e-7));
+    }
+}
+
+macro_rules! impl_test_eigh_driver {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<eigh_driver_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng);
+                test_eigh_driver(&a, UPLO::Upper);
+                test_eigh_driver(&a, UPLO::Lower);
+            }
+
+            #[test]
+            fn [<eigh_driver_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng);
+                let mut af = Array2::zeros((6, 6).f());
+                af.assign(&a);
+                test_eigh_driver(&af, UPLO::Upper);
+                test_eigh_driver(&af, UPLO::Lower);
+            }
+        }
+    };
+}
+
+impl_test_eigh_driver!(f64);
+impl_test_eigh_driver!(c64);
+
+// Eigenvectors of row-major complex input must not be conjugated
+#[test]
+fn eigh_c64_row_major() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(4, &mut rng);
+    assert!(a.is_standard_layout());
+    for uplo in [UPLO::Upper, UPLO::Lower] {
+        let (e, v) = a.eigh(uplo).unwrap();
+        let ve = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)] * c64::from_real(e[j]));
+        assert_close_l2!(&a.dot(&v), &ve, 1e-7);
+
+        let (e, v) = a.clone().eigh_into(uplo).unwrap();
+        let ve = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)] * c64::from_real(e[j]));
+        assert_close_l2!(&a.dot(&v), &ve, 1e-7);
+    }
+}
@@ -0,0 +1,65 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Test U^H A Q = D1 (0 R), V^H B Q = D2 (0 R), U, V and Q are unitary, and C^2 + S^2 = I
+fn test_gsvd<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>) {
+    let (m, n) = a.dim();
+    let p = b.nrows();
+    let gsvd = (a.clone(), b.clone()).gsvd().unwrap();
+    println!("u = \n{:?}", &gsvd.u);
+    println!("v = \n{:?}", &gsvd.v);
+    println!("q = \n{:?}", &gsvd.q);
+    println!("r = \n{:?}", &gsvd.r);
+    println!("alpha = \n{:?}", &gsvd.alpha);
+    println!("beta = \n{:?}", &gsvd.beta);
+    let r = gsvd.k + gsvd.l;
+    assert_eq!(gsvd.r.dim(), (r, r));
+
+    let uh: Array2<T> = conjugate(&gsvd.u);
+    let vh: Array2<T> = conjugate(&gsvd.v);
+    let qh: Array2<T> = conjugate(&gsvd.q);
+    assert_close_l2!(&uh.dot(&gsvd.u), &Array2::eye(m), T::real(1e-7));
+    assert_close_l2!(&vh.dot(&gsvd.v), &Array2::eye(p), T::real(1e-7));
+    assert_close_l2!(&qh.dot(&gsvd.q), &Array2::eye(n), T::real(1e-7));
+
+    let mut zr = Array2::<T>::zeros((r, n));
+    zr.slice_mut(s![.., n - r..]).assign(&gsvd.r);
+    let zrq = zr.dot(&qh);
+    assert_close_l2!(&gsvd.u.dot(&gsvd.d1()).dot(&zrq), a, T::real(1e-7));
+    assert_close_l2!(&gsvd.v.dot(&gsvd.d2()).dot(&zrq), b, T::real(1e-7));
+
+    for i in 0..r {
+        let c = gsvd.alpha[i];
+        let s = gsvd.beta[i];
+        assert_rclose!(T::from_real(c * c + s * s), T::one(), T::real(1e-7));
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $m:expr, $n:expr, $p:expr) => {
+        paste::item! {
+            #[test]
+            fn [<gsvd_ $scalar _ $m x $n x $p>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using(($m, $n), &mut rng);
+                let b: Array2<$scalar> = random_using(($p, $n), &mut rng);
+                test_gsvd(&a, &b);
+            }
+
+            #[test]
+            fn [<gsvd_ $scalar _ $m x $n x $p _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using(($m, $n).f(), &mut rng);
+                let b: Array2<$scalar> = random_using(($p, $n), &mut rng);
+                test_gsvd(&a, &b);
+            }
+        }
+    };
+}
+
+impl_test!(f64, 4, 3, 2);
+impl_test!(f64, 2, 3, 4);
+impl_test!(f64, 3, 3, 3);
+impl_test!(c64, 4, 3, 2);
+impl_test!(c64, 2, 3, 4);
+impl_test!(c64, 3, 3, 3);
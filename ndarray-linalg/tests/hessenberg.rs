@@ -0,0 +1,51 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Test A = Q H Q^H, Q^H Q = I, and H is upper Hessenberg
+fn test<T: Scalar + Lapack>(a: &Array2<T>) {
+    let n = a.nrows();
+    let (q, h) = a.hessenberg().unwrap();
+    println!("q = \n{:?}", &q);
+    println!("h = \n{:?}", &h);
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(n), T::real(1e-7));
+    assert_close_l2!(&q.dot(&h).dot(&qh), a, T::real(1e-7));
+    for ((i, j), val) in h.indexed_iter() {
+        if i > j + 1 {
+            assert_eq!(*val, T::zero());
+        }
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<hessenberg_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 5), &mut rng);
+                test(&a);
+            }
+
+            #[test]
+            fn [<hessenberg_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 5).f(), &mut rng);
+                test(&a);
+            }
+
+            #[test]
+            fn [<hessenberg_into_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 5), &mut rng);
+                let (q, h) = a.clone().hessenberg_into().unwrap();
+                let (q_, h_) = a.hessenberg().unwrap();
+                assert_close_l2!(&q, &q_, 1e-7);
+                assert_close_l2!(&h, &h_, 1e-7);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
@@ -0,0 +1,85 @@
+/// Solve generalized linear least squares problems
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Compare with the solution of the KKT system
+///
+/// ```text
+/// [ A^H A  B^H ] [ x ]   [ A^H c ]
+/// [ B      0   ] [ l ] = [ d     ]
+/// ```
+fn test_equality_constrained<T: Scalar + Lapack>(a: Array2<T>, b: Array2<T>) {
+    let (m, n) = a.dim();
+    let p = b.nrows();
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let c: Array1<T> = random_using(m, &mut rng);
+    let d: Array1<T> = random_using(p, &mut rng);
+    let x = a.least_squares_equality_constrained(&c, &b, &d).unwrap();
+    assert_close_l2!(&b.dot(&x), &d, T::real(1e-7));
+
+    let ah: Array2<T> = conjugate(&a);
+    let bh: Array2<T> = conjugate(&b);
+    let mut kkt = Array2::<T>::zeros((n + p, n + p));
+    kkt.slice_mut(s![..n, ..n]).assign(&ah.dot(&a));
+    kkt.slice_mut(s![..n, n..]).assign(&bh);
+    kkt.slice_mut(s![n.., ..n]).assign(&b);
+    let mut rhs = Array1::<T>::zeros(n + p);
+    rhs.slice_mut(s![..n]).assign(&ah.dot(&c));
+    rhs.slice_mut(s![n..]).assign(&d);
+    let ans = kkt.solve(&rhs).unwrap();
+    assert_close_l2!(&x, &ans.slice(s![..n]), T::real(1e-7));
+}
+
+/// For square non-singular `B`, this is the least squares problem `min |B^-1 (d - Ax)|`
+fn test_general_linear_model<T: Scalar + Lapack>(a: Array2<T>, b: Array2<T>) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let d: Array1<T> = random_using(a.nrows(), &mut rng);
+    let (x, y) = a.general_linear_model(&b, &d).unwrap();
+    assert_close_l2!(&(a.dot(&x) + b.dot(&y)), &d, T::real(1e-7));
+
+    let b_inv = b.inv().unwrap();
+    let ans = b_inv.dot(&a).least_squares(&b_inv.dot(&d)).unwrap();
+    assert_close_l2!(&x, &ans.solution, T::real(1e-7));
+    assert_close_l2!(&y, &b_inv.dot(&(&d - &a.dot(&x))), T::real(1e-7));
+}
+
+macro_rules! impl_test {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_equality_constrained_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 4), &mut rng);
+                let b: Array2<$scalar> = random_using((2, 4), &mut rng);
+                test_equality_constrained(a, b)
+            }
+
+            #[test]
+            fn [<least_squares_equality_constrained_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 4).f(), &mut rng);
+                let b: Array2<$scalar> = random_using((2, 4).f(), &mut rng);
+                test_equality_constrained(a, b)
+            }
+
+            #[test]
+            fn [<general_linear_model_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 3), &mut rng);
+                let b: Array2<$scalar> = random_using((5, 5), &mut rng);
+                test_general_linear_model(a, b)
+            }
+
+            #[test]
+            fn [<general_linear_model_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 3).f(), &mut rng);
+                let b: Array2<$scalar> = random_using((5, 5).f(), &mut rng);
+                test_general_linear_model(a, b)
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
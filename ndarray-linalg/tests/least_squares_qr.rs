@@ -0,0 +1,121 @@
+/// Solve least square problem `|b - Ax|` by QR or LQ decomposition
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Compare with the SVD-based solver for full rank `A`
+fn test_compare_svd<T: Scalar + Lapack>(a: Array2<T>) {
+    let (m, n) = a.dim();
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array1<T> = random_using(m, &mut rng);
+    let result = a.least_squares_qr(&b).unwrap();
+    let expected = a.least_squares(&b).unwrap();
+    assert_eq!(result.rank, expected.rank);
+    assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-4));
+    if m >= n {
+        let r = result.residual_sum_of_squares.unwrap()[()];
+        let e = expected.residual_sum_of_squares.unwrap()[()];
+        assert!((r - e).abs() < T::real(1.0e-4));
+    } else {
+        assert!(result.residual_sum_of_squares.is_none());
+    }
+
+    // both C- and F-continuous RHS
+    for bs in [
+        random_using((m, 2), &mut rng),
+        random_using((m, 2).f(), &mut rng),
+    ] {
+        let bs: Array2<T> = bs;
+        let result = a.least_squares_qr(&bs).unwrap();
+        let expected = a.least_squares(&bs).unwrap();
+        assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-4));
+        if m >= n {
+            let r = result.residual_sum_of_squares.unwrap();
+            let e = expected.residual_sum_of_squares.unwrap();
+            assert_close_l2!(
+                &r.mapv(T::from_real),
+                &e.mapv(T::from_real),
+                T::real(1.0e-4)
+            );
+        }
+    }
+}
+
+/// Compare `op(A)` variants with the SVD-based solver for explicitly transposed `A`
+fn test_compare_svd_op<T: Scalar + Lapack>(a: Array2<T>) {
+    let (m, n) = a.dim();
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let at: Array2<T> = a.t().to_owned();
+    let ah: Array2<T> = a.t().mapv(|x| x.conj());
+
+    let b: Array1<T> = random_using(n, &mut rng);
+    let result = a.least_squares_qr_t(&b).unwrap();
+    let expected = at.least_squares(&b).unwrap();
+    assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-4));
+    let result = a.least_squares_qr_h(&b).unwrap();
+    let expected = ah.least_squares(&b).unwrap();
+    assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-4));
+    if n >= m {
+        let r = result.residual_sum_of_squares.unwrap()[()];
+        let e = expected.residual_sum_of_squares.unwrap()[()];
+        assert!((r - e).abs() < T::real(1.0e-4));
+    } else {
+        assert!(result.residual_sum_of_squares.is_none());
+    }
+
+    // both C- and F-continuous RHS
+    for bs in [
+        random_using((n, 2), &mut rng),
+        random_using((n, 2).f(), &mut rng),
+    ] {
+        let bs: Array2<T> = bs;
+        let result = a.least_squares_qr_t(&bs).unwrap();
+        let expected = at.least_squares(&bs).unwrap();
+        assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-4));
+        let result = a.least_squares_qr_h(&bs).unwrap();
+        let expected = ah.least_squares(&bs).unwrap();
+        assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-4));
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty, $name:ident, $m:expr, $n:expr) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_qr_ $scalar _ $name>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using(($m, $n), &mut rng);
+                test_compare_svd(a)
+            }
+
+            #[test]
+            fn [<least_squares_qr_ $scalar _ $name _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using(($m, $n).f(), &mut rng);
+                test_compare_svd(a)
+            }
+
+            #[test]
+            fn [<least_squares_qr_op_ $scalar _ $name>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using(($m, $n), &mut rng);
+                test_compare_svd_op(a)
+            }
+
+            #[test]
+            fn [<least_squares_qr_op_ $scalar _ $name _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using(($m, $n).f(), &mut rng);
+                test_compare_svd_op(a)
+            }
+        }
+    };
+}
+
+impl_test!(f32, exact, 3, 3);
+impl_test!(f64, exact, 3, 3);
+impl_test!(c32, exact, 3, 3);
+impl_test!(c64, exact, 3, 3);
+impl_test!(f64, overdetermined, 4, 3);
+impl_test!(c64, overdetermined, 4, 3);
+impl_test!(f64, underdetermined, 3, 4);
+impl_test!(c64, underdetermined, 3, 4);
@@ -0,0 +1,99 @@
+/// Solve least square problem `|b - Ax|` by complete orthogonal decomposition
+use ndarray::*;
+use ndarray_linalg::*;
+
+/// Compare with the SVD-based solver, which also returns the minimum norm solution
+fn test_compare_svd<T: Scalar + Lapack>(a: Array2<T>, rank: i32) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array1<T> = random_using(a.nrows(), &mut rng);
+    let result = a.least_squares_qr_pivoted(&b).unwrap();
+    let expected = a.least_squares(&b).unwrap();
+    assert_eq!(result.rank, rank);
+    assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-7));
+    match (
+        result.residual_sum_of_squares,
+        expected.residual_sum_of_squares,
+    ) {
+        (Some(r), Some(e)) => assert!((r[()] - e[()]).abs() < T::real(1.0e-7)),
+        (None, None) => {}
+        _ => panic!("residual_sum_of_squares mismatch"),
+    }
+
+    let bs: Array2<T> = random_using((a.nrows(), 2), &mut rng);
+    let result = a.least_squares_qr_pivoted(&bs).unwrap();
+    let expected = a.least_squares(&bs).unwrap();
+    assert_eq!(result.rank, rank);
+    assert_close_l2!(&result.solution, &expected.solution, T::real(1.0e-7));
+}
+
+/// `A` has 3 columns, and the last one is the sum of the first two columns.
+/// The solution must be the minimum norm one, i.e. orthogonal to the null space of `A`.
+fn test_rank_deficient<T: Scalar + Lapack>(mut a: Array2<T>) {
+    let c = &a.column(0) + &a.column(1);
+    a.column_mut(2).assign(&c);
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array1<T> = random_using(a.nrows(), &mut rng);
+    let result = a.least_squares_qr_pivoted(&b).unwrap();
+    assert_eq!(result.rank, 2);
+    assert!(result.residual_sum_of_squares.is_none());
+    let x = result.solution;
+
+    // normal equation `A^H (b - Ax) = 0`
+    let ah: Array2<T> = conjugate(&a);
+    let r = ah.dot(&(&b - &a.dot(&x)));
+    assert!(r.norm_l2() < T::real(1.0e-7));
+
+    // orthogonal to the null vector `(1, 1, -1)`
+    assert!((x[0] + x[1] - x[2]).abs() < T::real(1.0e-7));
+}
+
+macro_rules! impl_test {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<least_squares_qr_pivoted_ $scalar _overdetermined>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((4, 3), &mut rng);
+                test_compare_svd(a, 3)
+            }
+
+            #[test]
+            fn [<least_squares_qr_pivoted_ $scalar _overdetermined_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((4, 3).f(), &mut rng);
+                test_compare_svd(a, 3)
+            }
+
+            #[test]
+            fn [<least_squares_qr_pivoted_ $scalar _underdetermined>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((3, 4), &mut rng);
+                test_compare_svd(a, 3)
+            }
+
+            #[test]
+            fn [<least_squares_qr_pivoted_ $scalar _underdetermined_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((3, 4).f(), &mut rng);
+                test_compare_svd(a, 3)
+            }
+
+            #[test]
+            fn [<least_squares_qr_pivoted_ $scalar _rank_deficient>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 3), &mut rng);
+                test_rank_deficient(a)
+            }
+
+            #[test]
+            fn [<least_squares_qr_pivoted_ $scalar _rank_deficient_t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 3).f(), &mut rng);
+                test_rank_deficient(a)
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
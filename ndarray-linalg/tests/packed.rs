@@ -0,0 +1,132 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+#[test]
+fn packed_index() {
+    let a: Array2<f64> = arr2(&[[1.0, 2.0, 4.0], [2.0, 3.0, 5.0], [4.0, 5.0, 6.0]]);
+    let a_f = a.t();
+    let upper = to_packed(&a_f, UPLO::Upper).unwrap();
+    assert_eq!(upper.uplo, UPLO::Upper);
+    assert_eq!(upper.ap, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let lower = to_packed(&a_f, UPLO::Lower).unwrap();
+    assert_eq!(lower.uplo, UPLO::Lower);
+    assert_eq!(lower.ap, vec![1.0, 2.0, 4.0, 3.0, 5.0, 6.0]);
+    for i in 0..3 {
+        for j in i..3 {
+            assert_eq!(upper[[i as i32, j as i32]], a[(i, j)]);
+            assert_eq!(lower[[j as i32, i as i32]], a[(j, i)]);
+        }
+    }
+
+    // The opposite triangle is stored for row-major matrix
+    assert_eq!(to_packed(&a, UPLO::Upper).unwrap().ap, lower.ap);
+    assert_eq!(to_packed(&a, UPLO::Lower).unwrap().ap, upper.ap);
+}
+
+#[test]
+fn packed_row_major_triangle() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<c64> = random_hermite_using(4, &mut rng);
+    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+        // Only the triangle specified by `uplo` is read
+        let mut b = a.clone();
+        Zip::indexed(&mut b).for_each(|(i, j), elt| {
+            if (uplo == UPLO::Upper && i > j) || (uplo == UPLO::Lower && i < j) {
+                *elt = c64::new(f64::NAN, f64::NAN);
+            }
+        });
+        let packed = to_packed(&b, uplo).unwrap();
+        assert_eq!(packed.uplo, uplo.t());
+        assert_close_l2!(&from_packed(&packed).unwrap(), &a, 1e-12);
+        let rfp = to_rfp(&b, uplo).unwrap();
+        assert_eq!(rfp.uplo, uplo.t());
+        assert_close_l2!(&from_rfp(&rfp).unwrap(), &a, 1e-12);
+    }
+}
+
+macro_rules! impl_test_packed {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<packed_roundtrip_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(5, &mut rng);
+                let mut a_f = Array2::zeros((5, 5).f());
+                a_f.assign(&a);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    assert_close_l2!(&from_packed(&to_packed(&a, uplo).unwrap()).unwrap(), &a, $rtol);
+                    assert_close_l2!(&from_rfp(&to_rfp(&a, uplo).unwrap()).unwrap(), &a, $rtol);
+                    assert_close_l2!(&from_packed(&to_packed(&a_f, uplo).unwrap()).unwrap(), &a, $rtol);
+                    assert_close_l2!(&from_rfp(&to_rfp(&a_f, uplo).unwrap()).unwrap(), &a, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<packed_rfp_odd_even_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                for n in 1..7 {
+                    let a: Array2<$scalar> = random_hermite_using(n, &mut rng);
+                    for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                        let rfp = to_rfp(&a, uplo).unwrap();
+                        assert_eq!(rfp.arf.len(), n * (n + 1) / 2);
+                        assert_close_l2!(&from_rfp(&rfp).unwrap(), &a, $rtol);
+                    }
+                }
+            }
+
+            #[test]
+            fn [<solvec_packed_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hpd_using(6, &mut rng);
+                let x: Array1<$scalar> = random_using(6, &mut rng);
+                let b = a.dot(&x);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let packed = to_packed(&a, uplo).unwrap();
+                    assert_close_l2!(&packed.solvec(&b).unwrap(), &x, $rtol);
+                    let f = packed.factorizec_packed().unwrap();
+                    assert_close_l2!(&f.solvec_into(b.clone()).unwrap(), &x, $rtol);
+
+                    let rfp = to_rfp(&a, uplo).unwrap();
+                    assert_close_l2!(&rfp.solvec(&b).unwrap(), &x, $rtol);
+                    let f = rfp.factorizec_rfp().unwrap();
+                    assert_close_l2!(&f.solvec_into(b.clone()).unwrap(), &x, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<solveh_packed_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng);
+                let x: Array1<$scalar> = random_using(6, &mut rng);
+                let b = a.dot(&x);
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let packed = to_packed(&a, uplo).unwrap();
+                    assert_close_l2!(&packed.solveh(&b).unwrap(), &x, $rtol);
+                    let f = packed.factorizeh_packed().unwrap();
+                    assert_close_l2!(&f.solveh_into(b.clone()).unwrap(), &x, $rtol);
+                }
+            }
+
+            #[test]
+            fn [<eigh_packed_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(6, &mut rng);
+                let expected = a.eigvalsh(UPLO::Upper).unwrap();
+                for &uplo in &[UPLO::Upper, UPLO::Lower] {
+                    let packed = to_packed(&a, uplo).unwrap();
+                    let (w, v) = packed.eigh_packed().unwrap();
+                    assert_close_l2!(&w, &expected, $rtol);
+                    assert_close_l2!(&packed.eigvalsh_packed().unwrap(), &expected, $rtol);
+                    let av = a.dot(&v);
+                    let vw = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)] * <$scalar>::from_real(w[j]));
+                    assert_close_l2!(&av, &vw, $rtol);
+                }
+            }
+        }
+    };
+}
+
+impl_test_packed!(f64, 1e-7);
+impl_test_packed!(f32, 1e-3);
+impl_test_packed!(c64, 1e-7);
+impl_test_packed!(c32, 1e-3);
@@ -79,3 +79,222 @@ fn qr_4x3_t() {
     let a = random_using((4, 3).f(), &mut rng);
     test(&a, 4, 3);
 }
+
+fn test_pivoted<T: Scalar + Lapack>(a: &Array2<T>, n: usize, m: usize) {
+    println!("a = \n{:?}", a);
+    let (q, r, p): (Array2<_>, Array2<_>, Array1<usize>) = a.qr_pivoted().unwrap();
+    println!("q = \n{:?}", &q);
+    println!("r = \n{:?}", &r);
+    println!("p = \n{:?}", &p);
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array::eye(min(n, m)), T::real(1e-7));
+    assert_close_l2!(
+        &q.dot(&r),
+        &a.select(Axis(1), p.as_slice().unwrap()),
+        T::real(1e-7)
+    );
+    assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, T::real(1e-7));
+    for i in 1..min(n, m) {
+        assert!(r[(i - 1, i - 1)].abs() >= r[(i, i)].abs());
+    }
+    assert_eq!(numerical_rank(&r, T::real(1e-7)), min(n, m));
+}
+
+fn test_pivoted_rank_deficient<T: Scalar + Lapack>(a: &Array2<T>, n: usize, m: usize) {
+    // Replace the last column by a linear combination of the first two columns
+    let mut a = a.clone();
+    let c = &a.column(0) + &a.column(1);
+    a.column_mut(m - 1).assign(&c);
+    let (q, r, p): (Array2<_>, Array2<_>, Array1<usize>) = a.qr_pivoted().unwrap();
+    assert_close_l2!(
+        &q.dot(&r),
+        &a.select(Axis(1), p.as_slice().unwrap()),
+        T::real(1e-7)
+    );
+    assert_eq!(numerical_rank(&r, T::real(1e-7)), min(n, m - 1));
+}
+
+macro_rules! test_qr_pivoted_impl {
+    ($type:ty, $test:ident, $n:expr, $m:expr) => {
+        paste::item! {
+            #[test]
+            fn [<qr_ $type _ $test _ $n x $m>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a = random_using(($n, $m), &mut rng);
+                $test::<$type>(&a, $n, $m);
+            }
+
+            #[test]
+            fn [<qr_ $type _ $test _ $n x $m _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a = random_using(($n, $m).f(), &mut rng);
+                $test::<$type>(&a, $n, $m);
+            }
+        }
+    };
+}
+
+test_qr_pivoted_impl!(f64, test_pivoted, 3, 3);
+test_qr_pivoted_impl!(f64, test_pivoted, 3, 4);
+test_qr_pivoted_impl!(f64, test_pivoted, 4, 3);
+test_qr_pivoted_impl!(f64, test_pivoted_rank_deficient, 4, 3);
+test_qr_pivoted_impl!(c64, test_pivoted, 3, 3);
+test_qr_pivoted_impl!(c64, test_pivoted, 3, 4);
+test_qr_pivoted_impl!(c64, test_pivoted, 4, 3);
+test_qr_pivoted_impl!(c64, test_pivoted_rank_deficient, 4, 3);
+
+fn test_lq<T: Scalar + Lapack>(a: &Array2<T>, n: usize, m: usize) {
+    println!("a = \n{:?}", a);
+    let (l, q): (Array2<_>, Array2<_>) = a.lq().unwrap();
+    println!("l = \n{:?}", &l);
+    println!("q = \n{:?}", &q);
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&q.dot(&qh), &Array::eye(min(n, m)), T::real(1e-7));
+    assert_close_l2!(&l.dot(&q), a, T::real(1e-7));
+    assert_close_l2!(&l.clone().into_triangular(UPLO::Lower), &l, T::real(1e-7));
+}
+
+fn test_ql<T: Scalar + Lapack>(a: &Array2<T>, n: usize, m: usize) {
+    println!("a = \n{:?}", a);
+    let (q, l): (Array2<_>, Array2<_>) = a.ql().unwrap();
+    println!("q = \n{:?}", &q);
+    println!("l = \n{:?}", &l);
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array::eye(min(n, m)), T::real(1e-7));
+    assert_close_l2!(&q.dot(&l), a, T::real(1e-7));
+    // L is lower trapezoidal aligned to the bottom-right corner
+    for ((i, j), x) in l.indexed_iter() {
+        if j + l.nrows() > i + l.ncols() {
+            assert!(x.abs() < T::real(1e-9));
+        }
+    }
+}
+
+fn test_rq<T: Scalar + Lapack>(a: &Array2<T>, n: usize, m: usize) {
+    println!("a = \n{:?}", a);
+    let (r, q): (Array2<_>, Array2<_>) = a.rq().unwrap();
+    println!("r = \n{:?}", &r);
+    println!("q = \n{:?}", &q);
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&q.dot(&qh), &Array::eye(min(n, m)), T::real(1e-7));
+    assert_close_l2!(&r.dot(&q), a, T::real(1e-7));
+    // R is upper trapezoidal aligned to the bottom-right corner
+    for ((i, j), x) in r.indexed_iter() {
+        if i + r.ncols() > j + r.nrows() {
+            assert!(x.abs() < T::real(1e-9));
+        }
+    }
+}
+
+fn test_square_lq_ql_rq<T: Scalar + Lapack>(a: &Array2<T>, _n: usize, _m: usize) {
+    let (l, q): (Array2<_>, Array2<_>) = a.lq_square().unwrap();
+    assert_close_l2!(&l.dot(&q), a, T::real(1e-7));
+    assert_close_l2!(&l.clone().into_triangular(UPLO::Lower), &l, T::real(1e-7));
+    let (q, l): (Array2<_>, Array2<_>) = a.ql_square().unwrap();
+    assert_close_l2!(&q.dot(&l), a, T::real(1e-7));
+    assert_close_l2!(&l.clone().into_triangular(UPLO::Lower), &l, T::real(1e-7));
+    let (r, q): (Array2<_>, Array2<_>) = a.rq_square().unwrap();
+    assert_close_l2!(&r.dot(&q), a, T::real(1e-7));
+    assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, T::real(1e-7));
+}
+
+test_qr_pivoted_impl!(f64, test_lq, 3, 3);
+test_qr_pivoted_impl!(f64, test_lq, 3, 4);
+test_qr_pivoted_impl!(f64, test_lq, 4, 3);
+test_qr_pivoted_impl!(f64, test_ql, 3, 3);
+test_qr_pivoted_impl!(f64, test_ql, 3, 4);
+test_qr_pivoted_impl!(f64, test_ql, 4, 3);
+test_qr_pivoted_impl!(f64, test_rq, 3, 3);
+test_qr_pivoted_impl!(f64, test_rq, 3, 4);
+test_qr_pivoted_impl!(f64, test_rq, 4, 3);
+test_qr_pivoted_impl!(f64, test_square_lq_ql_rq, 3, 3);
+test_qr_pivoted_impl!(c64, test_lq, 3, 3);
+test_qr_pivoted_impl!(c64, test_lq, 3, 4);
+test_qr_pivoted_impl!(c64, test_lq, 4, 3);
+test_qr_pivoted_impl!(c64, test_ql, 3, 3);
+test_qr_pivoted_impl!(c64, test_ql, 3, 4);
+test_qr_pivoted_impl!(c64, test_ql, 4, 3);
+test_qr_pivoted_impl!(c64, test_rq, 3, 3);
+test_qr_pivoted_impl!(c64, test_rq, 3, 4);
+test_qr_pivoted_impl!(c64, test_rq, 4, 3);
+test_qr_pivoted_impl!(c64, test_square_lq_ql_rq, 3, 3);
+
+fn test_factorized<T: Scalar + Lapack>(a: &Array2<T>, n: usize, m: usize) {
+    let f = a.factorize_qr().unwrap();
+    let r = f.r();
+    assert_eq!(r.dim(), (min(n, m), m));
+    assert_close_l2!(&r.clone().into_triangular(UPLO::Upper), &r, T::real(1e-7));
+    // Q^H A = [R; 0]
+    for j in 0..m {
+        let x = f.apply_qh(&a.column(j)).unwrap();
+        for i in 0..n {
+            let expected = if i < r.nrows() { r[(i, j)] } else { T::zero() };
+            assert!((x[i] - expected).abs() < T::real(1e-7));
+        }
+    }
+    // Q Q^H b = b
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array1<T> = random_using(n, &mut rng);
+    let x = f.apply_q(&f.apply_qh(&b).unwrap()).unwrap();
+    assert_close_l2!(&x, &b, T::real(1e-7));
+}
+
+fn test_factorized_solve<T: Scalar + Lapack>(a: &Array2<T>, n: usize, _m: usize) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let x: Array1<T> = random_using(n, &mut rng);
+    let f = a.factorize_qr().unwrap();
+    let b = a.dot(&x);
+    assert_close_l2!(&f.solve(&b).unwrap(), &x, T::real(1e-7));
+    let b = a.t().dot(&x);
+    assert_close_l2!(&f.solve_t(&b).unwrap(), &x, T::real(1e-7));
+    let ah: Array2<T> = conjugate(a);
+    let b = ah.dot(&x);
+    assert_close_l2!(&f.solve_h(&b).unwrap(), &x, T::real(1e-7));
+}
+
+fn test_factorized_least_squares<T: Scalar + Lapack>(a: &Array2<T>, n: usize, _m: usize) {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let b: Array1<T> = random_using(n, &mut rng);
+    let x = a.factorize_qr().unwrap().least_squares(&b).unwrap();
+    let ans = a.least_squares(&b).unwrap();
+    assert_close_l2!(&x, &ans.solution, T::real(1e-7));
+}
+
+test_qr_pivoted_impl!(f64, test_factorized, 3, 3);
+test_qr_pivoted_impl!(f64, test_factorized, 3, 4);
+test_qr_pivoted_impl!(f64, test_factorized, 4, 3);
+test_qr_pivoted_impl!(f64, test_factorized_solve, 3, 3);
+test_qr_pivoted_impl!(f64, test_factorized_least_squares, 6, 3);
+test_qr_pivoted_impl!(c64, test_factorized, 3, 3);
+test_qr_pivoted_impl!(c64, test_factorized, 3, 4);
+test_qr_pivoted_impl!(c64, test_factorized, 4, 3);
+test_qr_pivoted_impl!(c64, test_factorized_solve, 3, 3);
+test_qr_pivoted_impl!(c64, test_factorized_least_squares, 6, 3);
+
+fn test_complete_orthogonal<T: Scalar + Lapack>(a: &Array2<T>, n: usize, m: usize) {
+    // Replace the last column by a linear combination of the first two columns
+    let mut a = a.clone();
+    let c = &a.column(0) + &a.column(1);
+    a.column_mut(m - 1).assign(&c);
+    let rank = min(n, m - 1);
+    let (q, t, z, p): (Array2<_>, Array2<_>, Array2<_>, Array1<usize>) =
+        a.complete_orthogonal(T::real(1e-7)).unwrap();
+    assert_eq!(q.dim(), (n, rank));
+    assert_eq!(t.dim(), (rank, rank));
+    assert_eq!(z.dim(), (rank, m));
+    let qh: Array2<T> = conjugate(&q);
+    let zh: Array2<T> = conjugate(&z);
+    assert_close_l2!(&qh.dot(&q), &Array::eye(rank), T::real(1e-7));
+    assert_close_l2!(&z.dot(&zh), &Array::eye(rank), T::real(1e-7));
+    assert_close_l2!(&t.clone().into_triangular(UPLO::Upper), &t, T::real(1e-7));
+    assert_close_l2!(
+        &q.dot(&t).dot(&z),
+        &a.select(Axis(1), p.as_slice().unwrap()),
+        T::real(1e-7)
+    );
+}
+
+test_qr_pivoted_impl!(f64, test_complete_orthogonal, 4, 3);
+test_qr_pivoted_impl!(f64, test_complete_orthogonal, 3, 4);
+test_qr_pivoted_impl!(c64, test_complete_orthogonal, 4, 3);
+test_qr_pivoted_impl!(c64, test_complete_orthogonal, 3, 4);
@@ -0,0 +1,64 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Test A = Q S Z^H, B = Q T Z^H, Q and Z are unitary, and T is upper triangular
+fn test_qz<T: Scalar + Lapack>(a: &Array2<T>, b: &Array2<T>, qz: &QZDecomposition<T>) {
+    let n = a.nrows();
+    println!("q = \n{:?}", &qz.q);
+    println!("z = \n{:?}", &qz.z);
+    println!("s = \n{:?}", &qz.s);
+    println!("t = \n{:?}", &qz.t);
+    let qh: Array2<T> = conjugate(&qz.q);
+    let zh: Array2<T> = conjugate(&qz.z);
+    assert_close_l2!(&qh.dot(&qz.q), &Array2::eye(n), T::real(1e-7));
+    assert_close_l2!(&zh.dot(&qz.z), &Array2::eye(n), T::real(1e-7));
+    assert_close_l2!(&qz.q.dot(&qz.s).dot(&zh), a, T::real(1e-7));
+    assert_close_l2!(&qz.q.dot(&qz.t).dot(&zh), b, T::real(1e-7));
+    assert_close_l2!(
+        &qz.t.clone().into_triangular(UPLO::Upper),
+        &qz.t,
+        T::real(1e-7)
+    );
+}
+
+macro_rules! impl_test {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<qz_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((4, 4), &mut rng);
+                let b: Array2<$scalar> = random_using((4, 4), &mut rng);
+                let qz = (a.clone(), b.clone()).qz().unwrap();
+                test_qz(&a, &b, &qz);
+            }
+
+            #[test]
+            fn [<qz_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((4, 4).f(), &mut rng);
+                let b: Array2<$scalar> = random_using((4, 4), &mut rng);
+                let qz = (a.clone(), b.clone()).qz().unwrap();
+                test_qz(&a, &b, &qz);
+            }
+
+            #[test]
+            fn [<qz_sorted_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((4, 4), &mut rng);
+                let mut b: Array2<$scalar> = random_using((4, 4), &mut rng);
+                // singular B has an infinite eigenvalue
+                b.row_mut(3).fill(<$scalar>::from_real(0.0));
+                let qz = (a.clone(), b.clone())
+                    .qz_sorted(|_alpha, beta| beta.abs() > 1e-10)
+                    .unwrap();
+                test_qz(&a, &b, &qz);
+                assert_eq!(qz.sdim, 3);
+                assert!(qz.beta[3].abs() < 1e-10);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
@@ -0,0 +1,88 @@
+use ndarray::*;
+use ndarray_linalg::*;
+
+// Test A = Z T Z^H, Z^H Z = I, and T is (quasi-)upper triangular
+fn test_schur<T: Scalar + Lapack>(a: &Array2<T>, z: &Array2<T>, t: &Array2<T>) {
+    let n = a.nrows();
+    println!("a = \n{:?}", a);
+    println!("z = \n{:?}", z);
+    println!("t = \n{:?}", t);
+    let zh: Array2<T> = conjugate(z);
+    assert_close_l2!(&zh.dot(z), &Array2::eye(n), T::real(1e-7));
+    assert_close_l2!(&z.dot(t).dot(&zh), a, T::real(1e-7));
+    for ((i, j), val) in t.indexed_iter() {
+        if i > j + 1 {
+            assert!(val.abs() < T::real(1e-7), "t[{}, {}] = {:?}", i, j, val);
+        }
+    }
+    // 2x2 diagonal blocks must not overlap
+    for i in 2..n {
+        assert!(t[(i - 1, i - 2)].abs() < T::real(1e-7) || t[(i, i - 1)].abs() < T::real(1e-7));
+    }
+}
+
+// Test the selected eigenvalues are in the leading block of T
+fn test_sorted<T: Scalar + Lapack>(t: &Array2<T>, sdim: usize) {
+    let n = t.nrows();
+    if sdim > 0 {
+        let leading = t.slice(s![..sdim, ..sdim]).eigvals().unwrap();
+        assert!(leading.iter().all(|e| e.re() < T::real(0.0)));
+    }
+    if sdim < n {
+        let trailing = t.slice(s![sdim.., sdim..]).eigvals().unwrap();
+        assert!(trailing.iter().all(|e| e.re() >= T::real(0.0)));
+    }
+}
+
+macro_rules! impl_test {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<schur_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 5), &mut rng);
+                let (z, t) = a.schur().unwrap();
+                test_schur(&a, &z, &t);
+            }
+
+            #[test]
+            fn [<schur_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 5).f(), &mut rng);
+                let (z, t) = a.schur().unwrap();
+                test_schur(&a, &z, &t);
+            }
+
+            #[test]
+            fn [<schur_into_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 5), &mut rng);
+                let (z, t) = a.clone().schur_into().unwrap();
+                test_schur(&a, &z, &t);
+            }
+
+            #[test]
+            fn [<schur_sorted_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 5), &mut rng);
+                let a = &a - &Array2::from_diag_elem(5, <$scalar>::from_real(0.5));
+                let (z, t, sdim) = a.schur_sorted(|e| e.re() < 0.0).unwrap();
+                test_schur(&a, &z, &t);
+                test_sorted(&t, sdim);
+            }
+
+            #[test]
+            fn [<schur_sorted_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_using((5, 5).f(), &mut rng);
+                let a = &a - &Array2::from_diag_elem(5, <$scalar>::from_real(0.5));
+                let (z, t, sdim) = a.schur_sorted(|e| e.re() < 0.0).unwrap();
+                test_schur(&a, &z, &t);
+                test_sorted(&t, sdim);
+            }
+        }
+    };
+}
+
+impl_test!(f64);
+impl_test!(c64);
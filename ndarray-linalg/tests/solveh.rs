@@ -1,5 +1,6 @@
 use ndarray::*;
 use ndarray_linalg::*;
+use num_traits::{One, Zero};
 
 #[should_panic]
 #[test]
@@ -68,3 +69,121 @@ fn solveh_random_t() {
     let y = f.solveh_into(b).unwrap();
     assert_close_l2!(&x, &y, 1e-7);
 }
+
+#[test]
+fn solveh_rook_random() {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let a: Array2<f64> = random_hermite_using(5, &mut rng);
+    let x: Array1<f64> = random_using(5, &mut rng);
+    let b = a.dot(&x);
+    let f = a.factorizeh_rook().unwrap();
+    let y = f.solveh_into(b).unwrap();
+    assert_close_l2!(&x, &y, 1e-7);
+
+    let a = a.reversed_axes();
+    let b = a.dot(&x);
+    let f = a.factorizeh_rook_into().unwrap();
+    let y = f.solveh_into(b).unwrap();
+    assert_close_l2!(&x, &y, 1e-7);
+}
+
+macro_rules! factorizeh_factors {
+    ($elem:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<factorizeh_factors_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(6, &mut rng);
+                let mut a_t = Array2::zeros((6, 6).f());
+                a_t.assign(&a);
+                for a in [a, a_t] {
+                    let f = a.factorizeh().unwrap();
+                    let (u, d, p) = f.factors();
+                    let f_rook = a.factorizeh_rook().unwrap();
+                    let (u_rook, d_rook, p_rook) = f_rook.factors();
+                    for (u, d, p, inv) in [
+                        (u, d, p, f.invh().unwrap()),
+                        (u_rook, d_rook, p_rook, f_rook.invh().unwrap()),
+                    ] {
+                        for i in 0..6 {
+                            assert_eq!(u[(i, i)], <$elem>::one());
+                            for j in 0..i {
+                                assert_eq!(u[(i, j)], <$elem>::zero());
+                            }
+                        }
+                        assert_close_l2!(
+                            &u.dot(&d).dot(&u.t().mapv(|elem| elem.conj())),
+                            &a.select(Axis(0), p.as_slice().unwrap())
+                                .select(Axis(1), p.as_slice().unwrap()),
+                            $rtol
+                        );
+                        assert_close_l2!(&inv.dot(&a), &Array2::eye(6), $rtol);
+                    }
+                }
+            }
+        }
+    };
+}
+factorizeh_factors!(f64, 1e-7);
+factorizeh_factors!(f32, 1e-3);
+factorizeh_factors!(c64, 1e-7);
+factorizeh_factors!(c32, 1e-3);
+
+macro_rules! inertia {
+    ($elem:ty) => {
+        paste::item! {
+            #[test]
+            fn [<inertia_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$elem> = random_hermite_using(6, &mut rng);
+                let e = a.eigvalsh(UPLO::Upper).unwrap();
+
+                let inertia = a.inertia().unwrap();
+                assert_eq!(inertia.positive, e.iter().filter(|&&x| x > 0.0).count());
+                assert_eq!(inertia.negative, e.iter().filter(|&&x| x < 0.0).count());
+                assert_eq!(inertia.zero, 0);
+                assert_eq!(a.factorizeh().unwrap().inertia(), inertia);
+                assert_eq!(a.factorizeh_rook().unwrap().inertia(), inertia);
+
+                // Eigenvalues are sorted in ascending order
+                for i in 0..5 {
+                    let sigma = (e[i] + e[i + 1]) / 2.0;
+                    assert_eq!(a.count_eigenvalues_below(sigma).unwrap(), i + 1);
+                }
+                assert_eq!(a.count_eigenvalues_below(e[0] - 1.0).unwrap(), 0);
+                assert_eq!(a.count_eigenvalues_below(e[5] + 1.0).unwrap(), 6);
+            }
+
+            #[test]
+            fn [<inertia_singular_ $elem>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let c: Array2<$elem> = random_hermite_using(4, &mut rng);
+                let e = c.eigvalsh(UPLO::Upper).unwrap();
+                // Embed `c` with two zero rows and columns so that `D` has
+                // exactly zero 1x1 blocks, which rounding errors would
+                // otherwise hide (e.g. for rank-deficient `B * B^H`).
+                let idx = [0, 2, 3, 5];
+                let mut a = Array2::<$elem>::zeros((6, 6));
+                for (i, &p) in idx.iter().enumerate() {
+                    for (j, &q) in idx.iter().enumerate() {
+                        a[(p, q)] = c[(i, j)];
+                    }
+                }
+                assert!(a.factorizeh().is_err());
+
+                let inertia = a.inertia().unwrap();
+                let negative = e.iter().filter(|&&x| x < 0.0).count();
+                assert_eq!(inertia.positive, e.iter().filter(|&&x| x > 0.0).count());
+                assert_eq!(inertia.negative, negative);
+                assert_eq!(inertia.zero, 2);
+                // `sigma` is exactly an eigenvalue
+                assert_eq!(a.count_eigenvalues_below(0.0).unwrap(), negative);
+                assert_eq!(a.t().inertia().unwrap(), inertia);
+            }
+        }
+    };
+}
+inertia!(f64);
+inertia!(f32);
+inertia!(c64);
+inertia!(c32);
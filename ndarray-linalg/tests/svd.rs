@@ -92,3 +92,48 @@ test_svd_impl!(c64, test, 3, 4);
 test_svd_impl!(c64, test_no_vt, 3, 4);
 test_svd_impl!(c64, test_no_u, 3, 4);
 test_svd_impl!(c64, test_diag_only, 3, 4);
+
+fn test_range<T: Scalar + Lapack>(a: &Array2<T>) {
+    let (n, m) = a.dim();
+    let k = min(n, m);
+    println!("a = \n{:?}", a);
+    let (_, s_all, _) = a.svd(false, false).unwrap();
+
+    // two largest singular triplets
+    let (u, s, vt) = a.svd_range(true, true, EigRange::Index(0..2)).unwrap();
+    let u: Array2<T> = u.unwrap();
+    let vt: Array2<T> = vt.unwrap();
+    assert_eq!(u.dim(), (n, 2));
+    assert_eq!(vt.dim(), (2, m));
+    assert_close_l2!(
+        &s.mapv(T::from_real),
+        &s_all.slice(s![..2]).mapv(T::from_real),
+        T::real(1e-7)
+    );
+    // A v_i = s_i u_i
+    let sm = Array2::from_diag(&s.mapv(T::from_real));
+    assert_close_l2!(
+        &a.dot(&vt.t().mapv(|x| x.conj())),
+        &u.dot(&sm),
+        T::real(1e-7)
+    );
+
+    // singular values in (s_k / 2, 2 s_1], i.e. all of them
+    let (u, s, vt) = a
+        .svd_range(
+            false,
+            false,
+            EigRange::Value(s_all[k - 1] / T::real(2.0), s_all[0] * T::real(2.0)),
+        )
+        .unwrap();
+    assert!(u.is_none());
+    assert!(vt.is_none());
+    assert_eq!(s.len(), k);
+}
+
+test_svd_impl!(f64, test_range, 3, 3);
+test_svd_impl!(f64, test_range, 4, 3);
+test_svd_impl!(f64, test_range, 3, 4);
+test_svd_impl!(c64, test_range, 3, 3);
+test_svd_impl!(c64, test_range, 4, 3);
+test_svd_impl!(c64, test_range, 3, 4);
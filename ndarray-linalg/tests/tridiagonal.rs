@@ -265,3 +265,183 @@ fn rcond_tridiagonal_identity() {
         rcond_identity!(c32, rows, 1e-3);
     }
 }
+
+// Test A = Q T Q^H, Q is unitary, and T is real symmetric tridiagonal
+fn test_tridiagonalize<T: Scalar + Lapack>(a: &Array2<T>, uplo: UPLO) {
+    let n = a.nrows();
+    let (q, t) = a.tridiagonalize(uplo).unwrap();
+    assert_eq!(t.dl, t.du);
+    for x in t.d.iter().chain(t.dl.iter()) {
+        assert!(x.im().abs() < T::real(1e-9));
+    }
+    let mut td = Array2::<T>::zeros((n, n));
+    for i in 0..n {
+        td[(i, i)] = t.d[i];
+    }
+    for i in 0..n - 1 {
+        td[(i + 1, i)] = t.dl[i];
+        td[(i, i + 1)] = t.du[i];
+    }
+    let qh: Array2<T> = conjugate(&q);
+    assert_close_l2!(&qh.dot(&q), &Array2::eye(n), T::real(1e-7));
+    assert_close_l2!(&q.dot(&td).dot(&qh), a, T::real(1e-7));
+}
+
+macro_rules! impl_test_tridiagonalize {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<tridiagonalize_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(5, &mut rng);
+                test_tridiagonalize(&a, UPLO::Upper);
+                test_tridiagonalize(&a, UPLO::Lower);
+            }
+
+            #[test]
+            fn [<tridiagonalize_ $scalar _t>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hermite_using(5, &mut rng);
+                let mut af = Array2::zeros((5, 5).f());
+                af.assign(&a);
+                test_tridiagonalize(&af, UPLO::Upper);
+                test_tridiagonalize(&af, UPLO::Lower);
+            }
+        }
+    };
+}
+
+impl_test_tridiagonalize!(f64);
+impl_test_tridiagonalize!(c64);
+
+// Hermite tridiagonal matrix with random elements in the band
+fn random_hermite_tridiagonal<T: Scalar + Lapack>(n: usize) -> Array2<T> {
+    let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+    let mut a: Array2<T> = random_hermite_using(n, &mut rng);
+    Zip::indexed(&mut a).for_each(|(i, j), elt| {
+        if i > j + 1 || j > i + 1 {
+            *elt = T::zero()
+        }
+    });
+    a
+}
+
+// Test A V = V diag(w), and eigenvalues agree with those of the dense matrix
+fn test_eigh_tridiagonal<T: Scalar + Lapack>(a: &Array2<T>, w: &Array1<T::Real>, v: &Array2<T>) {
+    let av = a.dot(v);
+    let vw = Array2::from_shape_fn(v.dim(), |(i, j)| v[(i, j)] * T::from_real(w[j]));
+    assert_close_l2!(&av, &vw, T::real(1e-7));
+    let vh: Array2<T> = conjugate(v);
+    assert_close_l2!(&vh.dot(v), &Array2::eye(w.len()), T::real(1e-7));
+}
+
+macro_rules! impl_test_eigh_tridiagonal {
+    ($scalar:ty) => {
+        paste::item! {
+            #[test]
+            fn [<eigh_tridiagonal_ $scalar>]() {
+                let a: Array2<$scalar> = random_hermite_tridiagonal(6);
+                let t = a.extract_tridiagonal().unwrap();
+                let (w, v) = t.eigh_tridiagonal().unwrap();
+                test_eigh_tridiagonal(&a, &w, &v);
+                let expected = a.eigvalsh(UPLO::Upper).unwrap();
+                assert_close_l2!(&w, &expected, 1e-7);
+                assert_close_l2!(&t.eigvalsh_tridiagonal().unwrap(), &expected, 1e-7);
+            }
+
+            #[test]
+            fn [<eigh_tridiagonal_index_ $scalar>]() {
+                let a: Array2<$scalar> = random_hermite_tridiagonal(6);
+                let t = a.extract_tridiagonal().unwrap();
+                let expected = a.eigvalsh(UPLO::Upper).unwrap();
+                let (w, v) = t.eigh_tridiagonal_range(EigRange::Index(1..4)).unwrap();
+                test_eigh_tridiagonal(&a, &w, &v);
+                assert_close_l2!(&w, &expected.slice(s![1..4]), 1e-7);
+                let w = t.eigvalsh_tridiagonal_range(EigRange::Index(1..4)).unwrap();
+                assert_close_l2!(&w, &expected.slice(s![1..4]), 1e-7);
+            }
+
+            #[test]
+            fn [<eigh_tridiagonal_value_ $scalar>]() {
+                let a: Array2<$scalar> = random_hermite_tridiagonal(6);
+                let t = a.extract_tridiagonal().unwrap();
+                let expected = a.eigvalsh(UPLO::Upper).unwrap();
+                let range = EigRange::Value(
+                    (expected[0] + expected[1]) / 2.0,
+                    (expected[3] + expected[4]) / 2.0,
+                );
+                let (w, v) = t.eigh_tridiagonal_range(range.clone()).unwrap();
+                test_eigh_tridiagonal(&a, &w, &v);
+                assert_close_l2!(&w, &expected.slice(s![1..4]), 1e-7);
+                let w = t.eigvalsh_tridiagonal_range(range).unwrap();
+                assert_close_l2!(&w, &expected.slice(s![1..4]), 1e-7);
+            }
+        }
+    };
+}
+
+impl_test_eigh_tridiagonal!(f64);
+impl_test_eigh_tridiagonal!(c64);
+
+// Hermite positive definite tridiagonal matrix
+fn random_hpd_tridiagonal<T: Scalar + Lapack>(n: usize) -> Array2<T> {
+    let mut a: Array2<T> = random_hermite_tridiagonal(n);
+    for i in 0..n {
+        a[(i, i)] += T::from_real(T::real(5.0));
+    }
+    a
+}
+
+macro_rules! impl_test_ldl_tridiagonal {
+    ($scalar:ty, $rtol:expr) => {
+        paste::item! {
+            #[test]
+            fn [<solve_ldl_tridiagonal_ $scalar>]() {
+                let mut rng = rand_pcg::Mcg128Xsl64::new(0xcafef00dd15ea5e5);
+                let a: Array2<$scalar> = random_hpd_tridiagonal(6);
+                let f = a.factorize_ldl_tridiagonal().unwrap();
+                let x: Array1<$scalar> = random_using(6, &mut rng);
+                assert_close_l2!(&f.solve_tridiagonal(&a.dot(&x)).unwrap(), &x, $rtol);
+                assert_close_l2!(&f.solve_t_tridiagonal(&a.t().dot(&x)).unwrap(), &x, $rtol);
+                let ah: Array2<$scalar> = conjugate(&a);
+                assert_close_l2!(&f.solve_h_tridiagonal(&ah.dot(&x)).unwrap(), &x, $rtol);
+
+                let x: Array2<$scalar> = random_using((6, 2), &mut rng);
+                assert_close_l2!(&f.solve_tridiagonal(&a.dot(&x)).unwrap(), &x, $rtol);
+                let mut b = Array2::zeros((6, 2).f());
+                b.assign(&a.dot(&x));
+                assert_close_l2!(&f.solve_tridiagonal_into(b).unwrap(), &x, $rtol);
+            }
+
+            #[test]
+            fn [<det_ldl_tridiagonal_ $scalar>]() {
+                let a: Array2<$scalar> = random_hpd_tridiagonal(5);
+                let f = a.extract_tridiagonal().unwrap().factorize_ldl_tridiagonal_into().unwrap();
+                let det = f.det_tridiagonal().unwrap();
+                assert_aclose!(det.re(), a.det().unwrap().re(), $rtol * det.abs());
+                assert_aclose!(det.im(), 0.0, $rtol);
+            }
+
+            #[test]
+            fn [<rcond_ldl_tridiagonal_ $scalar>]() {
+                let a: Array2<$scalar> = random_hpd_tridiagonal(5);
+                let f = a.factorize_ldl_tridiagonal().unwrap();
+                // *ptcon computes the exact 1-norm of the inverse
+                let exact = 1.0 / (a.opnorm_one().unwrap() * a.inv().unwrap().opnorm_one().unwrap());
+                assert_aclose!(f.rcond_tridiagonal().unwrap(), exact, $rtol);
+            }
+
+            #[test]
+            fn [<ldl_tridiagonal_not_positive_definite_ $scalar>]() {
+                let mut a: Array2<$scalar> = random_hpd_tridiagonal(5);
+                a[(2, 2)] = -a[(2, 2)];
+                assert!(a.factorize_ldl_tridiagonal().is_err());
+            }
+        }
+    };
+}
+
+impl_test_ldl_tridiagonal!(f64, 1e-7);
+impl_test_ldl_tridiagonal!(f32, 1e-3);
+impl_test_ldl_tridiagonal!(c64, 1e-7);
+impl_test_ldl_tridiagonal!(c32, 1e-3);